@@ -13,12 +13,12 @@ use frame_system::RawOrigin;
 #[benchmarks]
 mod benchmarks {
 	use super::*;
-	use frame_support::traits::Get;
+	use frame_support::{traits::Get, BoundedVec};
 	use frame_system::pallet_prelude::BlockNumberFor;
 	use sp_runtime::traits::Saturating;
 
 	#[benchmark]
-	fn award_points() {
+	fn award_points(n: Linear<0, { T::MaxPointBatches::get() }>) {
 		// Setup: Create an admin and authorized issuer
 		let admin: T::AccountId = whitelisted_caller();
 		Admin::<T>::put(&admin);
@@ -26,7 +26,23 @@ mod benchmarks {
 		let issuer: T::AccountId = account("issuer", 0, 0);
 		AuthorizedIssuers::<T>::insert(&issuer, true);
 
+		// Give the recipient `n` batches that have expired by the award: every held batch is
+		// walked and, in this worst case, also removed
 		let recipient: T::AccountId = account("recipient", 0, 0);
+		let expired: Vec<PointBatch<BlockNumberFor<T>>> = (0..n)
+			.map(|_| PointBatch {
+				earned_at_block: 0u32.into(),
+				expires_at_block: 1u32.into(),
+				remaining_points: 100,
+				travel_type: TravelType::Airline,
+				last_demurrage_block: 0u32.into(),
+				expiring_per_step: 0,
+			})
+			.collect();
+		UserPoints::<T>::insert(&recipient, BoundedVec::truncate_from(expired));
+		TotalPoints::<T>::insert(&recipient, 100 * n as u128);
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+
 		let amount: u128 = 1000;
 
 		#[extrinsic_call]
@@ -42,6 +58,133 @@ mod benchmarks {
 		assert_eq!(TotalPoints::<T>::get(&recipient), amount);
 	}
 
+	#[benchmark]
+	fn award_points_pending() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		award_points_pending(
+			RawOrigin::Signed(issuer),
+			recipient.clone(),
+			1000,
+			TravelType::Airline,
+			None,
+		);
+
+		assert!(PendingAwards::<T>::contains_key(0));
+		assert_eq!(TotalPoints::<T>::get(&recipient), 0);
+	}
+
+	#[benchmark]
+	fn confirm_award(n: Linear<0, { T::MaxPointBatches::get() }>) {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		// Give the recipient `n` batches that have expired by the confirmation, the worst
+		// case for `n` held batches
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let expired: Vec<PointBatch<BlockNumberFor<T>>> = (0..n)
+			.map(|_| PointBatch {
+				earned_at_block: 0u32.into(),
+				expires_at_block: 1u32.into(),
+				remaining_points: 100,
+				travel_type: TravelType::Airline,
+				last_demurrage_block: 0u32.into(),
+				expiring_per_step: 0,
+			})
+			.collect();
+		UserPoints::<T>::insert(&recipient, BoundedVec::truncate_from(expired));
+		TotalPoints::<T>::insert(&recipient, 100 * n as u128);
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+
+		PendingAwards::<T>::insert(
+			0,
+			PendingAward {
+				issuer: issuer.clone(),
+				recipient: recipient.clone(),
+				amount: 1000,
+				travel_type: TravelType::Airline,
+				custom_expiration: None,
+				created_at: 0u32.into(),
+			},
+		);
+
+		#[extrinsic_call]
+		confirm_award(RawOrigin::Signed(issuer), 0);
+
+		assert!(!PendingAwards::<T>::contains_key(0));
+		assert_eq!(TotalPoints::<T>::get(&recipient), 1000);
+	}
+
+	#[benchmark]
+	fn cancel_pending_award() {
+		// The admin cancels, so the admin check is exercised as well
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		PendingAwards::<T>::insert(
+			0,
+			PendingAward {
+				issuer,
+				recipient: account("recipient", 0, 0),
+				amount: 1000,
+				travel_type: TravelType::Airline,
+				custom_expiration: None,
+				created_at: 0u32.into(),
+			},
+		);
+
+		#[extrinsic_call]
+		cancel_pending_award(RawOrigin::Signed(admin), 0);
+
+		assert!(!PendingAwards::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn retroactive_bonus(n: Linear<0, { T::MaxRetroactiveRecipients::get() }>) {
+		let issuer: T::AccountId = whitelisted_caller();
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		// `n` users spent at the issuer in period 0
+		let period: BlockNumberFor<T> = 0u32.into();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			UserIssuerSpend::<T>::insert((period, &user, &issuer), 1000u128);
+			IssuerPeriodRedeemers::<T>::insert((period, &issuer, &user), ());
+		}
+		frame_system::Pallet::<T>::set_block_number(T::BlocksPerRewardPeriod::get());
+
+		#[extrinsic_call]
+		retroactive_bonus(RawOrigin::Signed(issuer.clone()), period, 1000);
+
+		assert_eq!(IssuerPeriodRedeemers::<T>::iter_prefix((period, issuer)).count(), 0);
+	}
+
+	#[benchmark]
+	fn prune_period_redeemers(n: Linear<0, { T::MaxRetroactiveRecipients::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let issuer: T::AccountId = account("issuer", 0, 0);
+
+		// `n` redeemers left over in period 0, which is past its bonus window
+		let period: BlockNumberFor<T> = 0u32.into();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			IssuerPeriodRedeemers::<T>::insert((period, &issuer, &user), ());
+		}
+		let periods = T::RetroactiveBonusWindow::get().saturating_add(1u32.into());
+		frame_system::Pallet::<T>::set_block_number(
+			T::BlocksPerRewardPeriod::get().saturating_mul(periods),
+		);
+
+		#[extrinsic_call]
+		prune_period_redeemers(RawOrigin::Signed(caller), issuer.clone(), period);
+
+		assert_eq!(IssuerPeriodRedeemers::<T>::iter_prefix((period, issuer)).count(), 0);
+	}
+
 	#[benchmark]
 	fn spend_points() {
 		// Setup: Create a user with points
@@ -62,6 +205,10 @@ mod benchmarks {
 			None,
 		);
 
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
 		let spend_amount: u128 = 500;
 
 		#[extrinsic_call]
@@ -72,7 +219,140 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn cleanup_expired() {
+	fn spend_points_of_type() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+		TravelTypeRedemptionBonus::<T>::insert(TravelType::Train, 2000);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer.clone()).into(),
+			user.clone(),
+			2000,
+			TravelType::Train,
+			None,
+		);
+
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
+		#[extrinsic_call]
+		spend_points_of_type(
+			RawOrigin::Signed(user.clone()),
+			500,
+			issuer.clone(),
+			TravelType::Train,
+		);
+
+		// 20% off: 400 points deducted
+		assert_eq!(TotalPoints::<T>::get(&user), 1600);
+	}
+
+	#[benchmark]
+	fn teleport_points() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
+		// Longest beneficiary the call accepts
+		let beneficiary = alloc::vec![1u8; MAX_BENEFICIARY_LEN as usize];
+
+		#[extrinsic_call]
+		teleport_points(RawOrigin::Signed(user.clone()), 2000, beneficiary, 500);
+
+		assert_eq!(TotalPoints::<T>::get(&user), 1500);
+	}
+
+	#[benchmark]
+	fn lock_points() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
+		#[extrinsic_call]
+		lock_points(RawOrigin::Signed(user.clone()), 500, 1);
+
+		assert_eq!(TotalPoints::<T>::get(&user), 1500);
+		assert!(LockedPoints::<T>::contains_key(&user, 1));
+	}
+
+	#[benchmark]
+	fn commit_lock() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer.clone()).into(),
+			user.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+		let _ = TravelPoints::<T>::lock_points(RawOrigin::Signed(user.clone()).into(), 500, 1);
+
+		#[extrinsic_call]
+		commit_lock(RawOrigin::Signed(user.clone()), 1, issuer);
+
+		assert!(!LockedPoints::<T>::contains_key(&user, 1));
+		assert_eq!(TotalPoints::<T>::get(&user), 1500);
+	}
+
+	#[benchmark]
+	fn release_lock() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+		let _ = TravelPoints::<T>::lock_points(RawOrigin::Signed(user.clone()).into(), 500, 1);
+
+		#[extrinsic_call]
+		release_lock(RawOrigin::Signed(user.clone()), 1);
+
+		assert!(!LockedPoints::<T>::contains_key(&user, 1));
+		assert_eq!(TotalPoints::<T>::get(&user), 2000);
+	}
+
+	#[benchmark]
+	fn cleanup_expired(n: Linear<0, { T::MaxPointLocks::get() }>) {
 		// Setup: Create a user with some points
 		let admin: T::AccountId = whitelisted_caller();
 		Admin::<T>::put(&admin);
@@ -84,17 +364,95 @@ mod benchmarks {
 
 		// Award points with very short expiration
 		let _ = TravelPoints::<T>::award_points(
-			RawOrigin::Signed(issuer).into(),
+			RawOrigin::Signed(issuer.clone()).into(),
 			user.clone(),
 			1000,
 			TravelType::Train,
 			Some(1u32.into()),
 		);
 
+		// Give the user `n` point locks that time out before the cleanup
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			100_000,
+			TravelType::Airline,
+			None,
+		);
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+		for lock_id in 0..n {
+			let _ = TravelPoints::<T>::lock_points(
+				RawOrigin::Signed(user.clone()).into(),
+				100,
+				lock_id.into(),
+			);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::LockTimeout::get()));
+
 		let caller: T::AccountId = account("caller", 0, 0);
 
 		#[extrinsic_call]
 		cleanup_expired(RawOrigin::Signed(caller), user.clone());
+
+		assert_eq!(PointLockCount::<T>::get(&user), 0);
+	}
+
+	#[benchmark]
+	fn apply_demurrage() {
+		// Setup: Give a user points and let them age for a demurrage period
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let period: BlockNumberFor<T> = T::DemurragePeriod::get();
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			1_000_000,
+			TravelType::Airline,
+			Some(period.saturating_add(1000u32.into())),
+		);
+
+		let target_block = frame_system::Pallet::<T>::block_number().saturating_add(period);
+		frame_system::Pallet::<T>::set_block_number(target_block);
+
+		let caller: T::AccountId = account("caller", 0, 0);
+
+		#[extrinsic_call]
+		apply_demurrage(RawOrigin::Signed(caller), user.clone());
+	}
+
+	#[benchmark]
+	fn refresh_balance_cache() {
+		// Setup: Create a user with points
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			1000,
+			TravelType::Airline,
+			None,
+		);
+
+		let caller: T::AccountId = account("caller", 0, 0);
+
+		#[extrinsic_call]
+		refresh_balance_cache(RawOrigin::Signed(caller), user.clone());
+
+		// Verify the snapshot was taken
+		let current_block = frame_system::Pallet::<T>::block_number();
+		assert_eq!(AvailableCache::<T>::get(&user), Some((current_block, 1000)));
 	}
 
 	#[benchmark]
@@ -113,19 +471,230 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn revoke_issuer() {
-		// Setup: Create an admin and an authorized issuer
-		let admin: T::AccountId = whitelisted_caller();
+	fn revoke_issuer() {
+		// Setup: Create an admin and an authorized issuer
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		#[extrinsic_call]
+		revoke_issuer(RawOrigin::Signed(admin), issuer.clone());
+
+		// Verify the result
+		assert!(!AuthorizedIssuers::<T>::get(&issuer));
+	}
+
+	#[benchmark]
+	fn renounce_issuer() {
+		// Setup: An authorized issuer
+		let issuer: T::AccountId = whitelisted_caller();
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		#[extrinsic_call]
+		renounce_issuer(RawOrigin::Signed(issuer.clone()));
+
+		// Verify the result
+		assert!(!AuthorizedIssuers::<T>::get(&issuer));
+	}
+
+	#[benchmark]
+	fn prune_inactive_issuer() {
+		// Setup: An issuer last active in period 0, inactive for the whole window since
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+		let last_active: BlockNumberFor<T> = 0u32.into();
+		IssuerLastActivePeriod::<T>::insert(&issuer, last_active);
+		let inactive_until =
+			TravelPoints::<T>::period_start_block(T::IssuerInactivityPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(inactive_until);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		prune_inactive_issuer(RawOrigin::Signed(caller), issuer.clone());
+
+		// Verify the result
+		assert!(!AuthorizedIssuers::<T>::get(&issuer));
+	}
+
+	#[benchmark]
+	fn reset_user_issuer_spend() {
+		// Setup: Create an admin and an existing spend record
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		let period: BlockNumberFor<T> = 0u32.into();
+		UserIssuerSpend::<T>::insert((period, &user, &issuer), 500u128);
+
+		#[extrinsic_call]
+		reset_user_issuer_spend(RawOrigin::Signed(admin), user.clone(), issuer.clone(), period);
+
+		// Verify the result
+		assert_eq!(UserIssuerSpend::<T>::get((period, &user, &issuer)), 0);
+	}
+
+	#[benchmark]
+	fn prune_user_period_spend(n: Linear<0, { T::MaxPeriodEntriesPruned::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// `n` users' totals left over in period 0, which has ended
+		let period: BlockNumberFor<T> = 0u32.into();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			UserPeriodSpend::<T>::insert(period, &user, 100);
+		}
+		frame_system::Pallet::<T>::set_block_number(T::BlocksPerRewardPeriod::get());
+
+		#[extrinsic_call]
+		prune_user_period_spend(RawOrigin::Signed(caller), period);
+
+		assert_eq!(UserPeriodSpend::<T>::iter_prefix(period).count(), 0);
+	}
+
+	#[benchmark]
+	fn prune_issuer_ticket_counts(n: Linear<0, { T::MaxPeriodEntriesPruned::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// `n` issuers' counts left over in period 0, which has ended
+		let period: BlockNumberFor<T> = 0u32.into();
+		for i in 0..n {
+			let issuer: T::AccountId = account("issuer", i, 0);
+			IssuerPeriodTicketCount::<T>::insert(period, &issuer, 1);
+		}
+		frame_system::Pallet::<T>::set_block_number(T::BlocksPerRewardPeriod::get());
+
+		#[extrinsic_call]
+		prune_issuer_ticket_counts(RawOrigin::Signed(caller), period);
+
+		assert_eq!(IssuerPeriodTicketCount::<T>::iter_prefix(period).count(), 0);
+	}
+
+	#[benchmark]
+	fn escheat_dormant(n: Linear<0, { T::MaxPointLocks::get() }>) {
+		// Setup: Create an admin and a user whose points outlive the dormancy period
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let dormancy: BlockNumberFor<T> = T::DormancyPeriod::get();
+		let holding: BlockNumberFor<T> = T::MinHoldingPeriod::get();
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			user.clone(),
+			2000,
+			TravelType::Airline,
+			Some(dormancy.saturating_add(holding).saturating_add(1000u32.into())),
+		);
+
+		// Give the user `n` point locks, which are escheated along with its batches
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(holding));
+		for lock_id in 0..n {
+			let _ = TravelPoints::<T>::lock_points(
+				RawOrigin::Signed(user.clone()).into(),
+				100,
+				lock_id.into(),
+			);
+		}
+
+		// Move past the dormancy period
+		let target_block = frame_system::Pallet::<T>::block_number().saturating_add(dormancy);
+		frame_system::Pallet::<T>::set_block_number(target_block);
+
+		#[extrinsic_call]
+		escheat_dormant(RawOrigin::Signed(admin), user.clone());
+
+		// Verify the points moved to the escheatment account
+		assert_eq!(TotalPoints::<T>::get(&user), 0);
+		assert_eq!(PointLockCount::<T>::get(&user), 0);
+		assert_eq!(TotalPoints::<T>::get(&T::EscheatmentAccount::get()), 2000);
+	}
+
+	#[benchmark]
+	fn migrate_account_points() {
+		// Setup: Create an admin and a user with points
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let from: T::AccountId = account("from", 0, 0);
+		let to: T::AccountId = account("to", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer).into(),
+			from.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		#[extrinsic_call]
+		migrate_account_points(RawOrigin::Signed(admin), from.clone(), to.clone());
+
+		// Verify the points moved
+		assert_eq!(TotalPoints::<T>::get(&from), 0);
+		assert_eq!(TotalPoints::<T>::get(&to), 2000);
+	}
+
+	#[benchmark]
+	fn reclassify_batch() {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let user: T::AccountId = account("user", 0, 0);
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer.clone()).into(),
+			user.clone(),
+			2000,
+			TravelType::Bus,
+			None,
+		);
+
+		#[extrinsic_call]
+		reclassify_batch(RawOrigin::Signed(admin), user.clone(), 0, TravelType::Train);
+
+		assert_eq!(UserPoints::<T>::get(&user)[0].travel_type, TravelType::Train);
+	}
+
+	#[benchmark]
+	fn extend_all_batches() {
+		// Worst case: the caller is an issuer and the user holds a full list of batches
+		let admin: T::AccountId = account("admin", 0, 0);
 		Admin::<T>::put(&admin);
 
-		let issuer: T::AccountId = account("issuer", 0, 0);
+		let issuer: T::AccountId = whitelisted_caller();
 		AuthorizedIssuers::<T>::insert(&issuer, true);
 
+		let user: T::AccountId = account("user", 0, 0);
+		let batch_count = T::MaxPointBatches::get();
+		let batches: Vec<PointBatch<BlockNumberFor<T>>> = (0..batch_count)
+			.map(|i| PointBatch {
+				earned_at_block: 0u32.into(),
+				expires_at_block: (1000u32 + i).into(),
+				remaining_points: 100,
+				travel_type: TravelType::Airline,
+				last_demurrage_block: 0u32.into(),
+				expiring_per_step: 0,
+			})
+			.collect();
+		UserPoints::<T>::insert(&user, BoundedVec::truncate_from(batches));
+		TotalPoints::<T>::insert(&user, 100 * batch_count as u128);
+
 		#[extrinsic_call]
-		revoke_issuer(RawOrigin::Signed(admin), issuer.clone());
+		extend_all_batches(RawOrigin::Signed(issuer), user.clone(), 500u32.into());
 
-		// Verify the result
-		assert!(!AuthorizedIssuers::<T>::get(&issuer));
+		assert_eq!(UserPoints::<T>::get(&user)[0].expires_at_block, 1500u32.into());
 	}
 
 	#[benchmark]
@@ -163,6 +732,10 @@ mod benchmarks {
 			None,
 		);
 
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
 		let points_cost: u128 = 500;
 
 		#[extrinsic_call]
@@ -180,6 +753,10 @@ mod benchmarks {
 			b"Los Angeles".to_vec(),
 			b"2024-03-15 10:00".to_vec(),
 			b"Business Class".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		// Verify the result - ticket was created
@@ -188,6 +765,52 @@ mod benchmarks {
 		assert_eq!(TotalPoints::<T>::get(&owner), 1500);
 	}
 
+	#[benchmark]
+	fn gift_ticket() {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let payer: T::AccountId = account("payer", 0, 0);
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		let _ = TravelPoints::<T>::award_points(
+			RawOrigin::Signed(issuer.clone()).into(),
+			payer.clone(),
+			2000,
+			TravelType::Airline,
+			None,
+		);
+
+		// Let the awarded points outlast the minimum holding period
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinHoldingPeriod::get()));
+
+		#[extrinsic_call]
+		gift_ticket(
+			RawOrigin::Signed(issuer.clone()),
+			payer.clone(),
+			recipient.clone(),
+			TicketType::PlaneTicket,
+			500,
+			None,
+			b"John Doe".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"Business Class".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		);
+
+		assert_eq!(TotalPoints::<T>::get(&payer), 1500);
+		assert_eq!(UserTickets::<T>::get(&recipient).len(), 1);
+	}
+
 	#[benchmark]
 	fn redeem_ticket() {
 		// Setup: Create a ticket first
@@ -214,6 +837,10 @@ mod benchmarks {
 			b"Detroit".to_vec(),
 			b"2024-04-01 14:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		let ticket_id = 0u128;
@@ -253,16 +880,96 @@ mod benchmarks {
 			b"City B".to_vec(),
 			b"2024-05-01 09:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		let ticket_id = 0u128;
 
+		// Worst case: the sender holds a full ticket list with the transferred ticket last,
+		// and the receiver is one ticket short of `MaxTicketsPerUser`
+		let max_tickets = T::MaxTicketsPerUser::get() as u128;
+		let mut sender_tickets: Vec<u128> = (1..max_tickets).map(|i| 1000 + i).collect();
+		sender_tickets.push(ticket_id);
+		UserTickets::<T>::insert(&from, BoundedVec::truncate_from(sender_tickets));
+		let receiver_tickets: Vec<u128> = (1..max_tickets).map(|i| 2000 + i).collect();
+		UserTickets::<T>::insert(&to, BoundedVec::truncate_from(receiver_tickets));
+
+		// Give the sender points to cover a non-zero `TicketTransferFee`
+		let batch = PointBatch {
+			earned_at_block: 0u32.into(),
+			expires_at_block: 1000u32.into(),
+			remaining_points: 1000,
+			travel_type: TravelType::Airline,
+			last_demurrage_block: 0u32.into(),
+			expiring_per_step: 0,
+		};
+		UserPoints::<T>::insert(&from, BoundedVec::truncate_from(alloc::vec![batch]));
+		TotalPoints::<T>::insert(&from, 1000);
+
 		#[extrinsic_call]
 		transfer_ticket(RawOrigin::Signed(from.clone()), ticket_id, to.clone());
 
 		// Verify the ticket ownership changed
 		let ticket = Tickets::<T>::get(ticket_id).unwrap();
 		assert_eq!(ticket.owner, to);
+		assert_eq!(UserTickets::<T>::get(&to).len() as u128, max_tickets);
+	}
+
+	#[benchmark]
+	fn transfer_tickets_batch(n: Linear<1, { T::MaxTicketsPerBatchTransfer::get() }>) {
+		// Setup: Mint `n` tickets for the sender
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+		AuthorizedIssuers::<T>::insert(&issuer, true);
+
+		let from: T::AccountId = account("from", 0, 0);
+		let to: T::AccountId = account("to", 0, 0);
+
+		let mut ticket_ids = Vec::new();
+		for i in 0..n {
+			let _ = TravelPoints::<T>::mint_ticket(
+				RawOrigin::Signed(issuer.clone()).into(),
+				from.clone(),
+				TicketType::BusTicket,
+				0,
+				None,
+				b"Original Owner".to_vec(),
+				b"BUS001".to_vec(),
+				b"".to_vec(),
+				b"5".to_vec(),
+				b"City A".to_vec(),
+				b"City B".to_vec(),
+				b"2024-05-01 09:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			);
+			ticket_ids.push(i as u128);
+		}
+
+		#[extrinsic_call]
+		transfer_tickets_batch(RawOrigin::Signed(from.clone()), ticket_ids, to.clone());
+
+		// Verify every ticket moved to the recipient
+		assert_eq!(UserTickets::<T>::get(&to).len() as u32, n);
+		assert!(UserTickets::<T>::get(&from).is_empty());
+	}
+
+	#[benchmark]
+	fn set_accepts_gifts() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		set_accepts_gifts(RawOrigin::Signed(caller.clone()), false);
+
+		assert!(!AcceptsGifts::<T>::get(&caller));
 	}
 
 	#[benchmark]
@@ -291,6 +998,10 @@ mod benchmarks {
 			b"City B".to_vec(),
 			b"2024-05-01 09:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		let ticket_id = 0u128;
@@ -328,6 +1039,10 @@ mod benchmarks {
 			b"City B".to_vec(),
 			b"2024-05-01 09:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		let ticket_id = 0u128;
@@ -365,6 +1080,10 @@ mod benchmarks {
 			b"City B".to_vec(),
 			b"2024-05-01 09:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		);
 
 		// Move to block 10 so the ticket is expired
@@ -388,7 +1107,7 @@ mod benchmarks {
 		stake(RawOrigin::Signed(staker.clone()), amount);
 
 		// Verify stake was created
-		assert!(Stakes::<T>::get(&staker).is_some());
+		assert!(EnhancedStakes::<T>::get(&staker).is_some());
 		assert_eq!(TotalStaked::<T>::get(), amount);
 	}
 
@@ -404,7 +1123,7 @@ mod benchmarks {
 		unstake(RawOrigin::Signed(staker.clone()));
 
 		// Verify stake was removed
-		assert!(Stakes::<T>::get(&staker).is_none());
+		assert!(EnhancedStakes::<T>::get(&staker).is_none());
 		assert_eq!(TotalStaked::<T>::get(), 0);
 	}
 
@@ -420,6 +1139,17 @@ mod benchmarks {
 		assert_eq!(RewardPool::<T>::get(), amount);
 	}
 
+	#[benchmark]
+	fn set_reward_pool() {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		#[extrinsic_call]
+		set_reward_pool(RawOrigin::Signed(admin), 5000);
+
+		assert_eq!(RewardPool::<T>::get(), 5000);
+	}
+
 	// ============================================================================
 	// ADVANCED STAKING BENCHMARKS
 	// ============================================================================
@@ -438,8 +1168,8 @@ mod benchmarks {
 		request_unbond(RawOrigin::Signed(staker.clone()), unbond_amount);
 
 		// Verify stake was reduced
-		let stake_info = Stakes::<T>::get(&staker).unwrap();
-		assert_eq!(stake_info.amount, 1000);
+		let stake_info = EnhancedStakes::<T>::get(&staker).unwrap();
+		assert_eq!(stake_info.active, 1000);
 	}
 
 	#[benchmark]
@@ -468,6 +1198,84 @@ mod benchmarks {
 		assert!(requests.is_empty());
 	}
 
+	#[benchmark]
+	fn emergency_withdraw() {
+		// Worst case: a full queue of unbonding requests holding the staker's whole stake,
+		// all used up by the withdrawal, which then closes the stake
+		let staker: T::AccountId = whitelisted_caller();
+		let _ = TravelPoints::<T>::stake(
+			RawOrigin::Signed(staker.clone()).into(),
+			T::MinStakeAmount::get(),
+		);
+		EnhancedStakes::<T>::mutate(&staker, |info| {
+			if let Some(info) = info {
+				info.active = 0;
+			}
+		});
+
+		let max_requests = T::MaxUnbondingRequests::get();
+		let unlocks_at = frame_system::Pallet::<T>::block_number() + T::UnbondingPeriod::get();
+		let requests: Vec<UnbondingInfo<BlockNumberFor<T>>> = (0..max_requests)
+			.map(|_| UnbondingInfo { amount: 1, requested_at: 0u32.into(), unlocks_at })
+			.collect();
+		UnbondingRequests::<T>::insert(&staker, BoundedVec::truncate_from(requests));
+
+		#[extrinsic_call]
+		emergency_withdraw(RawOrigin::Signed(staker.clone()), max_requests as u128);
+
+		// The unbonding stake was released and the empty stake closed
+		assert!(UnbondingRequests::<T>::get(&staker).is_empty());
+		assert!(EnhancedStakes::<T>::get(&staker).is_none());
+	}
+
+	#[benchmark]
+	fn set_staking_recovery() {
+		// Setup: Create a stake
+		let staker: T::AccountId = whitelisted_caller();
+		let _ = TravelPoints::<T>::stake(
+			RawOrigin::Signed(staker.clone()).into(),
+			T::MinStakeAmount::get(),
+		);
+
+		let recovery: T::AccountId = account("recovery", 0, 0);
+
+		#[extrinsic_call]
+		set_staking_recovery(RawOrigin::Signed(staker.clone()), recovery.clone());
+
+		// Verify recovery account was stored
+		assert_eq!(RecoveryAccount::<T>::get(&staker), Some(recovery));
+	}
+
+	#[benchmark]
+	fn recover_unbonded() {
+		// Setup: Create a stake, designate a recovery account and request unbonding
+		let staker: T::AccountId = whitelisted_caller();
+		let recovery: T::AccountId = account("recovery", 0, 0);
+		let stake_amount: u128 = T::MinStakeAmount::get().saturating_mul(2);
+
+		let _ = TravelPoints::<T>::stake(RawOrigin::Signed(staker.clone()).into(), stake_amount);
+		let _ = TravelPoints::<T>::set_staking_recovery(
+			RawOrigin::Signed(staker.clone()).into(),
+			recovery.clone(),
+		);
+		let _ = TravelPoints::<T>::request_unbond(
+			RawOrigin::Signed(staker.clone()).into(),
+			T::MinStakeAmount::get(),
+		);
+
+		// Move blocks forward past unbonding period
+		let unbonding_period: BlockNumberFor<T> = T::UnbondingPeriod::get();
+		let target_block = unbonding_period.saturating_add(100u32.into());
+		frame_system::Pallet::<T>::set_block_number(target_block);
+
+		#[extrinsic_call]
+		recover_unbonded(RawOrigin::Signed(recovery), staker.clone());
+
+		// Verify unbonding requests are cleared
+		let requests = UnbondingRequests::<T>::get(&staker);
+		assert!(requests.is_empty());
+	}
+
 	#[benchmark]
 	fn cancel_unbonding() {
 		// Setup: Create a stake and request unbonding
@@ -481,8 +1289,8 @@ mod benchmarks {
 		cancel_unbonding(RawOrigin::Signed(staker.clone()));
 
 		// Verify stake was restored
-		let stake_info = Stakes::<T>::get(&staker).unwrap();
-		assert_eq!(stake_info.amount, 2000);
+		let stake_info = EnhancedStakes::<T>::get(&staker).unwrap();
+		assert_eq!(stake_info.active, 2000);
 	}
 
 	#[benchmark]
@@ -496,11 +1304,37 @@ mod benchmarks {
 		let _ = TravelPoints::<T>::stake(RawOrigin::Signed(staker.clone()).into(), stake_amount);
 
 		#[extrinsic_call]
-		slash_staker(RawOrigin::Signed(admin), staker.clone(), SlashReason::Offline);
+		slash_staker(RawOrigin::Signed(admin), staker.clone(), SlashReason::Offline, None);
 
 		// Verify stake was reduced
-		let stake_info = Stakes::<T>::get(&staker).unwrap();
-		assert!(stake_info.amount < stake_amount);
+		let stake_info = EnhancedStakes::<T>::get(&staker).unwrap();
+		assert!(stake_info.active < stake_amount);
+	}
+
+	#[benchmark]
+	fn rebuild_staker_list(n: Linear<0, { T::MaxStakers::get() + T::MaxStakerWaitlist::get() }>) {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		// Stakes missing from `StakerList`
+		for i in 0..n {
+			let staker: T::AccountId = account("staker", i, 0);
+			EnhancedStakes::<T>::insert(
+				&staker,
+				EnhancedStakeInfo {
+					active: 1000 + i as u128,
+					staked_at: 0u32.into(),
+					is_verifier: false,
+					total_slashed: 0,
+				},
+			);
+		}
+
+		#[extrinsic_call]
+		rebuild_staker_list(RawOrigin::Signed(admin), n);
+
+		assert_eq!(StakerList::<T>::get().len() as u32, n.min(T::MaxStakers::get()));
+		assert_eq!(StakerWaitlist::<T>::get().len() as u32, n.saturating_sub(T::MaxStakers::get()));
 	}
 
 	#[benchmark]
@@ -594,6 +1428,10 @@ mod benchmarks {
 			1000, // 10% commission in basis points
 		);
 
+		// Let the pool reach its minimum lifetime
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinPoolLifetime::get()));
+
 		#[extrinsic_call]
 		close_pool(RawOrigin::Signed(operator.clone()), 0);
 
@@ -602,13 +1440,151 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn rotate_era() {
-		// Setup: Create some stakers with proper minimum stake
-		let staker: T::AccountId = whitelisted_caller();
-		let _ = TravelPoints::<T>::stake(
-			RawOrigin::Signed(staker.clone()).into(),
-			T::MinStakeAmount::get(),
-		);
+	fn cleanup_pool_index() {
+		// Setup: Leave a delegator index behind for a pool that does not exist
+		let caller: T::AccountId = whitelisted_caller();
+		let delegator: T::AccountId = account("delegator", 0, 0);
+		PoolDelegators::<T>::mutate(0, |delegators| {
+			let _ = delegators.try_push(delegator);
+		});
+
+		#[extrinsic_call]
+		cleanup_pool_index(RawOrigin::Signed(caller), 0);
+
+		// Verify index was removed
+		assert!(!PoolDelegators::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn sweep_closed_pool_residue(n: Linear<0, { T::MaxDelegatorsPerPool::get() }>) {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		// Setup: `n` delegations left behind by a pool that no longer exists
+		for i in 0..n {
+			let delegator: T::AccountId = account("delegator", i, 0);
+			Delegations::<T>::insert(
+				&delegator,
+				DelegationInfo { pool_id: 0, amount: 1000, delegated_at: 0u32.into() },
+			);
+			PoolDelegators::<T>::mutate(0, |delegators| {
+				let _ = delegators.try_push(delegator);
+			});
+		}
+		TotalStaked::<T>::put(1000 * n as u128);
+
+		#[extrinsic_call]
+		sweep_closed_pool_residue(RawOrigin::Signed(admin), 0);
+
+		assert_eq!(TotalStaked::<T>::get(), 0);
+		assert!(!PoolDelegators::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn set_pool_creation_restricted() {
+		// Setup: Set admin
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		#[extrinsic_call]
+		set_pool_creation_restricted(RawOrigin::Signed(admin), true);
+
+		// Verify restriction was enabled
+		assert!(PoolCreationRestricted::<T>::get());
+	}
+
+	#[benchmark]
+	fn set_pool_creator() {
+		// Setup: Set admin
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let creator: T::AccountId = account("creator", 0, 0);
+
+		#[extrinsic_call]
+		set_pool_creator(RawOrigin::Signed(admin), creator.clone(), true);
+
+		// Verify creator was allowed
+		assert!(PoolCreators::<T>::get(&creator));
+	}
+
+	#[benchmark]
+	fn set_suppress_events() {
+		// Setup: Set admin
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+
+		#[extrinsic_call]
+		set_suppress_events(RawOrigin::Signed(admin), issuer.clone(), true);
+
+		// Verify issuer events are suppressed
+		assert!(SuppressEvents::<T>::get(&issuer));
+	}
+
+	#[benchmark]
+	fn set_spend_tracking() {
+		// Setup: Set admin
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let issuer: T::AccountId = account("issuer", 0, 0);
+
+		#[extrinsic_call]
+		set_spend_tracking(RawOrigin::Signed(admin), issuer.clone(), false);
+
+		// Verify the issuer's spends are untracked
+		assert!(UntrackedSpends::<T>::get(&issuer));
+	}
+
+	#[benchmark]
+	fn set_redemption_bonus() {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		#[extrinsic_call]
+		set_redemption_bonus(RawOrigin::Signed(admin), TravelType::Train, 2000);
+
+		assert_eq!(TravelTypeRedemptionBonus::<T>::get(TravelType::Train), 2000);
+	}
+
+	#[benchmark]
+	fn configure_travel_type() {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		let config = TravelTypeConfig {
+			multiplier: Some(20_000),
+			default_expiry: Some(1000u32.into()),
+			redemption_bonus: Some(2000),
+		};
+
+		#[extrinsic_call]
+		configure_travel_type(RawOrigin::Signed(admin), TravelType::Train, config);
+
+		assert_eq!(TravelTypeMultiplier::<T>::get(TravelType::Train), Some(20_000));
+		assert_eq!(TravelTypeRedemptionBonus::<T>::get(TravelType::Train), 2000);
+	}
+
+	#[benchmark]
+	fn rotate_era(n: Linear<0, { T::MaxStakers::get() }>) {
+		// Setup: `n` eligible stakers with equal stakes, so selection also compares accounts
+		for i in 0..n {
+			let staker: T::AccountId = account("staker", i, 0);
+			EnhancedStakes::<T>::insert(
+				&staker,
+				EnhancedStakeInfo {
+					active: T::MinStakeAmount::get(),
+					staked_at: 0u32.into(),
+					is_verifier: false,
+					total_slashed: 0,
+				},
+			);
+			StakerList::<T>::mutate(|list| {
+				let _ = list.try_push(staker);
+			});
+		}
 
 		// Move blocks forward past era using BlocksPerEra from config
 		// Add extra buffer to ensure we're past the era boundary
@@ -616,11 +1592,53 @@ mod benchmarks {
 		let target_block = blocks_per_era.saturating_add(100u32.into());
 		frame_system::Pallet::<T>::set_block_number(target_block);
 
+		let caller: T::AccountId = whitelisted_caller();
+
 		#[extrinsic_call]
-		rotate_era(RawOrigin::Signed(staker.clone()));
+		rotate_era(RawOrigin::Signed(caller));
 
 		// Verify era was rotated
 		assert_eq!(CurrentEra::<T>::get(), 1);
+		assert_eq!(EraVerifiers::<T>::get(1).len() as u32, n.min(T::VerifiersPerEra::get()));
+	}
+
+	#[benchmark]
+	fn report_verification() {
+		// Setup: Make the caller a verifier of the current era
+		let verifier: T::AccountId = whitelisted_caller();
+		EraVerifiers::<T>::mutate(CurrentEra::<T>::get(), |verifiers| {
+			let _ = verifiers.try_push(verifier.clone());
+		});
+
+		let period: BlockNumberFor<T> = 0u32.into();
+
+		#[extrinsic_call]
+		report_verification(RawOrigin::Signed(verifier), period);
+
+		// Verify the report was counted
+		assert_eq!(VerificationReportCount::<T>::get(period), 1);
+	}
+
+	#[benchmark]
+	fn prune_verification_reports(n: Linear<0, { T::VerifiersPerEra::get() }>) {
+		let admin: T::AccountId = whitelisted_caller();
+		Admin::<T>::put(&admin);
+
+		// `n` verifiers reported for period 0, which has ended
+		let period: BlockNumberFor<T> = 0u32.into();
+		for i in 0..n {
+			let verifier: T::AccountId = account("verifier", i, 0);
+			VerificationReports::<T>::insert(period, &verifier, ());
+		}
+		VerificationReportCount::<T>::insert(period, n);
+		PeriodVerificationEra::<T>::insert(period, 0);
+		frame_system::Pallet::<T>::set_block_number(T::BlocksPerRewardPeriod::get());
+
+		#[extrinsic_call]
+		prune_verification_reports(RawOrigin::Signed(admin), period);
+
+		assert_eq!(VerificationReports::<T>::iter_prefix(period).count(), 0);
+		assert_eq!(VerificationReportCount::<T>::get(period), 0);
 	}
 
 	#[benchmark]
@@ -632,6 +1650,10 @@ mod benchmarks {
 		let staker: T::AccountId = account("staker", 0, 0);
 		let _ = TravelPoints::<T>::stake(RawOrigin::Signed(staker.clone()).into(), 1000);
 
+		// Age the stake so it is eligible for rewards
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::StakeRewardDelay::get()));
+
 		RewardPool::<T>::put(10000u128);
 
 		let period: BlockNumberFor<T> = 0u32.into();
@@ -641,6 +1663,7 @@ mod benchmarks {
 
 		// Verify reward pool was emptied
 		assert_eq!(RewardPool::<T>::get(), 0);
+		assert!(PendingStakerRewards::<T>::get(&staker) > 0);
 	}
 
 	#[benchmark]
@@ -656,6 +1679,50 @@ mod benchmarks {
 		assert_eq!(PendingStakerRewards::<T>::get(&caller), 0);
 	}
 
+	#[benchmark]
+	fn claim_rewards_for(n: Linear<1, { T::MaxClaimsPerBatch::get() }>) {
+		// Setup: Create pending rewards for `n` accounts
+		let caller: T::AccountId = whitelisted_caller();
+
+		let mut accounts = Vec::new();
+		for i in 0..n {
+			let who: T::AccountId = account("claimant", i, 0);
+			PendingStakerRewards::<T>::insert(&who, 5000u128);
+			PendingIssuerRewards::<T>::insert(&who, 1000u128);
+			accounts.push(who);
+		}
+
+		#[extrinsic_call]
+		claim_rewards_for(RawOrigin::Signed(caller), accounts.clone());
+
+		// Verify all rewards were claimed
+		for who in accounts.iter() {
+			assert_eq!(PendingStakerRewards::<T>::get(who), 0);
+		}
+	}
+
+	#[benchmark]
+	fn withdraw_vested_reward() {
+		// Setup: A schedule that is half vested, so it is updated rather than removed
+		let caller: T::AccountId = whitelisted_caller();
+		RewardVestingSchedules::<T>::insert(
+			&caller,
+			RewardVesting {
+				total: 5000u128,
+				withdrawn: 0,
+				starts_at: 0u32.into(),
+				ends_at: 100u32.into(),
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+
+		#[extrinsic_call]
+		withdraw_vested_reward(RawOrigin::Signed(caller.clone()));
+
+		// Verify the vested half was withdrawn
+		assert_eq!(RewardVestingSchedules::<T>::get(&caller).map(|s| s.withdrawn), Some(2500));
+	}
+
 	#[benchmark]
 	fn increase_stake() {
 		// Setup: Create an initial stake
@@ -668,8 +1735,8 @@ mod benchmarks {
 		increase_stake(RawOrigin::Signed(staker.clone()), increase_amount);
 
 		// Verify stake was increased
-		let stake_info = Stakes::<T>::get(&staker).unwrap();
-		assert_eq!(stake_info.amount, 1500);
+		let stake_info = EnhancedStakes::<T>::get(&staker).unwrap();
+		assert_eq!(stake_info.active, 1500);
 	}
 
 	impl_benchmark_test_suite!(TravelPoints, crate::mock::new_test_ext(), crate::mock::Test);
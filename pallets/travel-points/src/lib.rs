@@ -39,6 +39,11 @@
 //! - Verifiers/stakers stake tokens and receive rewards based on contribution
 //! - Issuers receive rewards proportional to how much users spend points with them
 //! - Daily tracking of point spending per issuer for fair reward distribution
+//!
+//! ### Weights
+//! Calls whose cost grows with a bounded collection (a user's batches or point locks, a
+//! period's recipients, a pool's delegators, the staker list) are charged up front for the
+//! configured maximum; the unused part is refunded based on the entries actually processed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -61,10 +66,32 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+// Storage migrations
+pub mod migrations;
+
 // Weights module - placeholder for now
 pub mod weights;
 pub use weights::*;
 
+/// Divide `numerator` by `denominator`, rounding to the nearest integer (halves round up).
+///
+/// Use this on user-favorable paths such as refunds and conversions so users do not lose a
+/// point to truncation. Protocol-favorable paths (fees, pro-rata payouts from a fixed pool)
+/// keep rounding down with `saturating_div`. Returns 0 if `denominator` is 0.
+pub fn div_round_nearest(numerator: u128, denominator: u128) -> u128 {
+	if denominator == 0 {
+		return 0;
+	}
+	let quotient = numerator / denominator;
+	let remainder = numerator % denominator;
+	// remainder >= denominator / 2, written so it cannot overflow
+	if remainder >= denominator - remainder {
+		quotient.saturating_add(1)
+	} else {
+		quotient
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -73,7 +100,10 @@ pub mod pallet {
 	use codec::DecodeWithMemTracking;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Saturating, Zero};
+	use sp_runtime::{
+		traits::{Bounded, Saturating, Zero},
+		SaturatedConversion,
+	};
 
 	// ============================================================================
 	// TYPES AND STRUCTS
@@ -99,6 +129,21 @@ pub mod pallet {
 		}
 	}
 
+	/// Settings of a travel type applied together by `configure_travel_type`.
+	/// Fields left as `None` keep their current setting.
+	#[derive(
+		Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, PartialEq, Eq, Debug, Default,
+	)]
+	pub struct TravelTypeConfig<BlockNumber> {
+		/// Award multiplier in basis points (10000 = 1x, must be non-zero)
+		pub multiplier: Option<u32>,
+		/// Expiry of awards made without a custom expiration (0 restores
+		/// `DefaultExpirationPeriod`)
+		pub default_expiry: Option<BlockNumber>,
+		/// Redemption bonus in basis points, below 10000 (0 disables it)
+		pub redemption_bonus: Option<u32>,
+	}
+
 	/// Represents the type of ticket/bonus NFT
 	#[derive(
 		Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug,
@@ -122,6 +167,22 @@ pub mod pallet {
 		}
 	}
 
+	/// Usability of a ticket, returned per ticket by the `ticket_statuses` view function
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug)]
+	pub enum TicketStatus {
+		/// The ticket exists and can still be redeemed
+		Valid,
+		/// The ticket has been fully redeemed
+		Redeemed,
+		/// The ticket passed its expiration block without being fully redeemed
+		Expired,
+		/// No ticket exists with this ID, e.g. because it was burned or cleaned up
+		NotFound,
+		/// The ID came after the first `MaxTicketStatusQueries` of the query and was not
+		/// looked up
+		NotChecked,
+	}
+
 	/// A single batch of points awarded to a user.
 	/// Each batch tracks when points were earned, when they expire, and how many remain.
 	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
@@ -135,11 +196,61 @@ pub mod pallet {
 		pub remaining_points: u128,
 		/// The type of travel that earned these points
 		pub travel_type: TravelType,
+		/// The block up to which demurrage has been applied to this batch
+		pub last_demurrage_block: BlockNumber,
+		/// Points removed at each partial expiry step, fixed when the batch first expires
+		/// under `PartialExpiryPercent` (0 until then)
+		pub expiring_per_step: u128,
+	}
+
+	/// An award held back until its issuer confirms it, e.g. once a flight has been flown.
+	/// Pending awards are not part of the recipient's balance.
+	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+	pub struct PendingAward<AccountId, BlockNumber> {
+		/// The issuer that created the award and may confirm it
+		pub issuer: AccountId,
+		/// The account that receives the points once confirmed
+		pub recipient: AccountId,
+		/// The number of points to award
+		pub amount: u128,
+		/// The type of travel the points are for
+		pub travel_type: TravelType,
+		/// Custom expiration period, counted from confirmation
+		pub custom_expiration: Option<BlockNumber>,
+		/// Block at which the award was created
+		pub created_at: BlockNumber,
+	}
+
+	/// Points held back from a user's balance for a pending off-chain payment, until the lock
+	/// is committed as a spend or released.
+	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+	pub struct PointLock<BlockNumber, Batches> {
+		/// The number of points locked
+		pub amount: u128,
+		/// Block from which the lock can no longer be committed and is released
+		pub expires_at: BlockNumber,
+		/// The parts of the user's batches taken into the lock, returned on release
+		pub batches: Batches,
 	}
 
 	/// Maximum length for string fields in tickets
 	pub const MAX_STRING_LEN: u32 = 128;
 
+	/// Maximum length for a ticket's free-form metadata, which may hold longer structured notes
+	pub const MAX_METADATA_LEN: u32 = 512;
+
+	/// Maximum length for coded locations (e.g. 3-letter IATA codes, station codes)
+	pub const MAX_LOCATION_CODE_LEN: u32 = 8;
+
+	/// Maximum length for an encoded cross-chain beneficiary of teleported points
+	pub const MAX_BENEFICIARY_LEN: u32 = 128;
+
+	/// Blocks in a year at a 6 second block time, used to annualize reward estimates
+	pub const BLOCKS_PER_YEAR: u32 = 5_256_000;
+
+	/// Short alphanumeric location code used for route indexing
+	pub type LocationCode = BoundedVec<u8, ConstU32<MAX_LOCATION_CODE_LEN>>;
+
 	/// NFT Ticket structure storing all relevant ticket information
 	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
 	#[scale_info(skip_type_params(T))]
@@ -174,11 +285,35 @@ pub mod pallet {
 		pub arrival: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
 		/// Departure time as encoded string (e.g., "2024-03-15 10:00", ISO 8601, or custom format)
 		pub departure_time: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
-		/// Additional metadata/notes
-		pub metadata: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		/// Additional metadata/notes (up to `MAX_METADATA_LEN` bytes)
+		pub metadata: BoundedVec<u8, ConstU32<MAX_METADATA_LEN>>,
+		/// Coded departure location (e.g. "JFK"), used for route indexing
+		pub departure_code: Option<LocationCode>,
+		/// Coded arrival location (e.g. "LAX"), used for route indexing
+		pub arrival_code: Option<LocationCode>,
+		/// Number of segments (legs) covered by the ticket, e.g. 2 for a round trip
+		pub segments: u8,
+		/// Number of segments redeemed so far
+		pub segments_redeemed: u8,
+		/// Number of travelers covered by the ticket, e.g. 4 for a family pass
+		pub capacity: u32,
+		/// Seats used on the current segment
+		pub used: u32,
+	}
+
+	/// Handler notified whenever a ticket is redeemed.
+	/// Lets runtimes wire redemptions into other pallets (bookings, access control, ...).
+	pub trait OnTicketRedeemedHandler<AccountId> {
+		/// Called after `ticket_id` owned by `owner` has been marked as redeemed
+		fn on_redeemed(ticket_id: u128, owner: &AccountId, ticket_type: &TicketType);
+	}
+
+	impl<AccountId> OnTicketRedeemedHandler<AccountId> for () {
+		fn on_redeemed(_ticket_id: u128, _owner: &AccountId, _ticket_type: &TicketType) {}
 	}
 
-	/// Staking info for a staker
+	/// Staking info as stored in the legacy `Stakes` map, before `migrations::v1` moved it
+	/// to `EnhancedStakes`
 	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
 	#[scale_info(skip_type_params(T))]
 	pub struct StakeInfo<BlockNumber> {
@@ -199,6 +334,100 @@ pub mod pallet {
 		pub transaction_count: u32,
 	}
 
+	/// Result of a successful award, returned by `contract_award_points_detailed`
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug, Default)]
+	pub struct AwardOutcome<BlockNumber> {
+		/// Block at which the awarded batch expires
+		pub expires_at_block: BlockNumber,
+		/// The recipient's total points balance after the award
+		pub new_total_balance: u128,
+		/// Number of batches the recipient holds after the award
+		pub batch_count: u32,
+		/// Points credited, after any travel type multiplier
+		pub amount: u128,
+	}
+
+	/// Aggregated status of an issuer, returned by the `issuer_dashboard` view function
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug, Default)]
+	pub struct IssuerDashboard<BlockNumber> {
+		/// Whether the issuer is currently authorized to issue points
+		pub authorized: bool,
+		/// Current reward period number
+		pub period: BlockNumber,
+		/// Points spent through this issuer in the current period
+		pub points_spent: u128,
+		/// Number of transactions through this issuer in the current period
+		pub transaction_count: u32,
+		/// Issuer rewards awaiting claim
+		pub pending_reward: u128,
+	}
+
+	/// Why an issuer's authorization was revoked
+	#[derive(
+		Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug,
+	)]
+	pub enum IssuerRevocationReason {
+		/// Revoked by the admin
+		Admin,
+		/// Given up by the issuer itself
+		Renounced,
+		/// Pruned after `IssuerInactivityPeriod` periods without activity
+		Inactive,
+	}
+
+	/// Destructive admin operation recorded by `AdminActionPerformed`
+	#[derive(
+		Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug,
+	)]
+	pub enum AdminAction {
+		/// A staker was slashed with `slash_staker`
+		SlashStaker,
+		/// A ticket was burned with `force_unmint_ticket`
+		ForceUnmintTicket,
+		/// The admin was replaced with `set_admin`
+		SetAdmin,
+		/// A dormant account's points were escheated with `escheat_dormant`
+		EscheatDormant,
+		/// A user's points were moved with `migrate_account_points`
+		MigrateAccountPoints,
+		/// A point batch was reclassified with `reclassify_batch`
+		ReclassifyBatch,
+		/// The reward pool was overwritten with `set_reward_pool`
+		SetRewardPool,
+		/// A closed pool's delegations were released with `sweep_closed_pool_residue`
+		SweepClosedPoolResidue,
+		/// The staker sets were rebuilt with `rebuild_staker_list`
+		RebuildStakerList,
+	}
+
+	/// How claimed rewards are paid out
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+	pub enum RewardKind {
+		/// Rewards are paid as currency
+		Currency,
+		/// Rewards are minted as travel points that expire after `DefaultExpirationPeriod`
+		Points,
+	}
+
+	impl Default for RewardKind {
+		fn default() -> Self {
+			RewardKind::Currency
+		}
+	}
+
+	/// Claimed currency rewards vesting linearly between `starts_at` and `ends_at`
+	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
+	pub struct RewardVesting<BlockNumber> {
+		/// Total amount covered by the schedule
+		pub total: u128,
+		/// Amount already withdrawn
+		pub withdrawn: u128,
+		/// Block vesting started
+		pub starts_at: BlockNumber,
+		/// Block from which the whole amount is vested
+		pub ends_at: BlockNumber,
+	}
+
 	// ============================================================================
 	// ADVANCED STAKING TYPES (Slashing, Unbonding, Delegation, Eras)
 	// ============================================================================
@@ -224,6 +453,16 @@ pub mod pallet {
 		}
 	}
 
+	impl SlashReason {
+		/// Every reason, in declaration order. Must be extended when a reason is added.
+		pub const ALL: [SlashReason; 4] = [
+			SlashReason::Offline,
+			SlashReason::InvalidVerification,
+			SlashReason::Malicious,
+			SlashReason::Other,
+		];
+	}
+
 	/// Record of a slash event
 	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
 	#[scale_info(skip_type_params(T))]
@@ -282,6 +521,37 @@ pub mod pallet {
 		}
 	}
 
+	/// Risk metrics of a staking pool, returned by the `pool_health` view function
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug, Default)]
+	pub struct PoolHealth {
+		/// Commission rate in basis points
+		pub commission: u32,
+		/// Operator's share of the pool's total stake in basis points
+		pub operator_stake_ratio: u32,
+		/// Number of accounts delegating to the pool
+		pub delegator_count: u32,
+		/// Total stake in the pool (operator + delegators)
+		pub total_stake: u128,
+		/// Whether the pool is accepting delegations
+		pub is_active: bool,
+		/// Whether the operator has ever been slashed
+		pub operator_slashed: bool,
+	}
+
+	/// An account's combined staking position, returned by the `full_staking_position` view
+	/// function
+	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug, Default)]
+	pub struct StakingPosition {
+		/// Active flat stake (0 if the account is not a staker)
+		pub active: u128,
+		/// (pool_id, operator_stake) of every pool the account operates
+		pub pools_operated: Vec<(u32, u128)>,
+		/// (pool_id, amount) of the account's delegation, if any
+		pub delegation: Option<(u32, u128)>,
+		/// Total stake waiting in unbonding requests, matured or not
+		pub unbonding: u128,
+	}
+
 	/// Delegation info for a delegator in a pool
 	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Default)]
 	#[scale_info(skip_type_params(T))]
@@ -312,8 +582,12 @@ pub mod pallet {
 	// PALLET CONFIGURATION
 	// ============================================================================
 
+	/// The in-code storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	/// The pallet struct - placeholder for implementing traits and dispatchables
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configuration trait for the pallet.
@@ -333,19 +607,60 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxPointBatches: Get<u32>;
 
+		/// Batch utilization (basis points of `MaxPointBatches`, e.g. 9000 = 90%) at which an
+		/// award first merges the user's batches sharing a travel type and expiry block.
+		#[pallet::constant]
+		type BatchCompactionThreshold: Get<u32>;
+
+		/// Minimum amount of points a single award may grant.
+		/// Raises the cost of fragmenting a user's batches with tiny awards.
+		#[pallet::constant]
+		type MinAwardAmount: Get<u128>;
+
+		/// Maximum number of distinct travel types a user can hold point batches for.
+		/// Set at or above the number of `TravelType` variants to disable the limit.
+		#[pallet::constant]
+		type MaxTravelTypesPerUser: Get<u32>;
+
 		/// Default expiration period for points in blocks.
 		/// For example, if blocks are 6 seconds, 365 days ≈ 5,256,000 blocks
 		#[pallet::constant]
 		type DefaultExpirationPeriod: Get<BlockNumberFor<Self>>;
 
+		/// Share of a batch (basis points of its points when it first expires) that expires
+		/// at each partial expiry step. An expired batch loses this share and its expiry moves
+		/// `PartialExpiryInterval` blocks out, until nothing is left. Set to 0 to expire whole
+		/// batches at once.
+		#[pallet::constant]
+		type PartialExpiryPercent: Get<u32>;
+
+		/// Number of blocks between partial expiry steps. Set to 0 to expire whole batches at
+		/// once.
+		#[pallet::constant]
+		type PartialExpiryInterval: Get<BlockNumberFor<Self>>;
+
 		/// Maximum number of tickets a user can own
 		#[pallet::constant]
 		type MaxTicketsPerUser: Get<u32>;
 
+		/// Maximum number of (owner, since-block) entries kept in a ticket's provenance.
+		/// Once full, the oldest entry is dropped for each new owner.
+		#[pallet::constant]
+		type MaxProvenanceEntries: Get<u32>;
+
+		/// Maximum number of tickets an issuer may mint in one reward period. Further mints
+		/// fail with `IssuerTicketCapExceeded` until the next period.
+		#[pallet::constant]
+		type MaxTicketsPerIssuerPerPeriod: Get<u32>;
+
 		/// Maximum number of stakers
 		#[pallet::constant]
 		type MaxStakers: Get<u32>;
 
+		/// Maximum number of stakers waiting for a slot in the active staker set
+		#[pallet::constant]
+		type MaxStakerWaitlist: Get<u32>;
+
 		/// Minimum stake amount required
 		#[pallet::constant]
 		type MinStakeAmount: Get<u128>;
@@ -380,6 +695,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaliciousSlashPercent: Get<u32>;
 
+		/// Share of every slash (basis points) added to the reward pool for honest stakers.
+		/// The rest of the slash is burned. Set to 0 to burn slashes entirely.
+		#[pallet::constant]
+		type SlashToRewardPoolBps: Get<u32>;
+
 		/// Maximum number of staking pools
 		#[pallet::constant]
 		type MaxPools: Get<u32>;
@@ -388,10 +708,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDelegatorsPerPool: Get<u32>;
 
+		/// Maximum amount a single account may delegate to one pool (absolute cap).
+		/// Keeps any one delegator from dominating a pool. Set to 0 to disable.
+		#[pallet::constant]
+		type MaxDelegationPerPoolPerAccount: Get<u128>;
+
 		/// Minimum pool operator stake
 		#[pallet::constant]
 		type MinPoolOperatorStake: Get<u128>;
 
+		/// Number of blocks a pool must exist before its operator can close it, so pools
+		/// are not spun up and torn down in quick succession. Set to 0 to allow closing
+		/// right away.
+		#[pallet::constant]
+		type MinPoolLifetime: Get<BlockNumberFor<Self>>;
+
 		/// Maximum commission a pool operator can charge (basis points)
 		#[pallet::constant]
 		type MaxPoolCommission: Get<u32>;
@@ -400,6 +731,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type VerifiersPerEra: Get<u32>;
 
+		/// Minimum active stake for a staker to be considered in verifier selection. Stakers
+		/// below it can still stake and earn, but are never selected as verifiers.
+		#[pallet::constant]
+		type MinVerifierStake: Get<u128>;
+
+		/// Share of a period's verifiers (basis points, e.g., 6667 = 66.67%) that must call
+		/// `report_verification` for it before its rewards can be distributed. A period is
+		/// attested by the verifiers of the era its first report was made in.
+		/// Set to 0 to disable the quorum.
+		#[pallet::constant]
+		type VerifierQuorumPercent: Get<u32>;
+
 		/// Blocks per era for verifier rotation
 		#[pallet::constant]
 		type BlocksPerEra: Get<BlockNumberFor<Self>>;
@@ -409,9 +752,198 @@ pub mod pallet {
 		#[pallet::constant]
 		type IssuerRewardPercent: Get<u32>;
 
+		/// Weight of an issuer's points spent in its reward score. The issuer share of a
+		/// period is split in proportion to `points_spent * IssuerRewardSpendWeight +
+		/// transaction_count * IssuerRewardTransactionScale * IssuerRewardTransactionWeight`.
+		#[pallet::constant]
+		type IssuerRewardSpendWeight: Get<u32>;
+
+		/// Weight of an issuer's transaction count in its reward score. Set to 0 to split
+		/// issuer rewards by points spent alone.
+		#[pallet::constant]
+		type IssuerRewardTransactionWeight: Get<u32>;
+
+		/// Points each transaction counts as in the reward score, before weighting
+		#[pallet::constant]
+		type IssuerRewardTransactionScale: Get<u128>;
+
 		/// Maximum unbonding requests per account
 		#[pallet::constant]
 		type MaxUnbondingRequests: Get<u32>;
+
+		/// Number of active stake changes remembered per staker, used to slash against the
+		/// stake held at an offense block. Older changes are forgotten first.
+		#[pallet::constant]
+		type MaxStakeHistory: Get<u32>;
+
+		/// Unbonding requests strictly below this amount are returned immediately instead of
+		/// waiting for `UnbondingPeriod`. Set to 0 to disable instant unbonding.
+		#[pallet::constant]
+		type InstantUnbondThreshold: Get<u128>;
+
+		/// Maximum total amount an account may unbond instantly per reward period.
+		/// Requests beyond this allowance fall back to the regular unbonding queue.
+		#[pallet::constant]
+		type MaxInstantUnbondPerPeriod: Get<u128>;
+
+		/// Penalty (basis points) withheld from an `emergency_withdraw`, which skips the
+		/// unbonding period. The penalty is added to the reward pool.
+		#[pallet::constant]
+		type EmergencyWithdrawPenaltyBps: Get<u32>;
+
+		/// Minimum balance that must remain after a spend unless the balance is spent to zero.
+		/// Prevents leaving dust balances behind. Set to 0 to disable.
+		#[pallet::constant]
+		type MinRetainedPoints: Get<u128>;
+
+		/// Number of blocks a point batch must be held after it was earned before it can be
+		/// spent. Set to 0 to make awarded points spendable right away.
+		#[pallet::constant]
+		type MinHoldingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// How many blocks after expiring a point batch can still be reinstated by
+		/// `extend_all_batches`. Batches that expired longer ago stay expired.
+		#[pallet::constant]
+		type ReinstatementWindow: Get<BlockNumberFor<Self>>;
+
+		/// Whether every award to and spend by a user pushes the expiry of all of the user's
+		/// live batches out to `DefaultExpirationPeriod` from that block, as airlines do on
+		/// account activity. Batches already expiring later keep their expiry.
+		#[pallet::constant]
+		type ResetExpiryOnActivity: Get<bool>;
+
+		/// Number of blocks a point lock may stay open. An expired lock can no longer be
+		/// committed; its points return to the user on `release_lock` or `cleanup_expired`.
+		#[pallet::constant]
+		type LockTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of open point locks per user
+		#[pallet::constant]
+		type MaxPointLocks: Get<u32>;
+
+		/// Maximum number of consumed batches reported in a `SpendBreakdown`. Spends that
+		/// touch more batches only emit the aggregate `PointsSpent` event.
+		#[pallet::constant]
+		type MaxSpendBreakdownEntries: Get<u32>;
+
+		/// Maximum number of distinct issuers a user may spend with in one reward period.
+		/// A spend at a further issuer fails with `TooManyIssuersThisPeriod`.
+		#[pallet::constant]
+		type MaxIssuersPerUserPerPeriod: Get<u32>;
+
+		/// Maximum number of points a user may spend in one reward period, across all
+		/// issuers. A spend past it fails with `UserSpendCapExceeded`. Set to 0 to disable.
+		#[pallet::constant]
+		type MaxUserSpendPerPeriod: Get<u128>;
+
+		/// Maximum number of entries of a past period removed by one pruning call, such as
+		/// `prune_user_period_spend` or `prune_issuer_ticket_counts`. Call again to continue
+		/// with the rest.
+		#[pallet::constant]
+		type MaxPeriodEntriesPruned: Get<u32>;
+
+		/// Maximum number of redeemers credited by one `retroactive_bonus` call. Issuers with
+		/// more redeemers in a period call it again to continue where the last call stopped.
+		#[pallet::constant]
+		type MaxRetroactiveRecipients: Get<u32>;
+
+		/// Number of reward periods after a period during which it can still receive a
+		/// `retroactive_bonus`. The redeemers of older periods can be pruned by anyone with
+		/// `prune_period_redeemers`.
+		#[pallet::constant]
+		type RetroactiveBonusWindow: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks without award, spend or ticket transfer activity after which an
+		/// account's points may be escheated to `EscheatmentAccount`
+		#[pallet::constant]
+		type DormancyPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of reward periods without an award by, or a spend at, an issuer after which
+		/// anyone may revoke its authorization with `prune_inactive_issuer`. Set to 0 to
+		/// disable pruning.
+		#[pallet::constant]
+		type IssuerInactivityPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Account that receives the points of escheated dormant accounts. Its own points can
+		/// never be escheated.
+		type EscheatmentAccount: Get<Self::AccountId>;
+
+		/// Demurrage rate in basis points of a batch's remaining points per
+		/// `DemurragePeriod` held. Set to 0 to disable demurrage.
+		#[pallet::constant]
+		type DemurrageRate: Get<u32>;
+
+		/// Number of blocks over which `DemurrageRate` applies in full
+		#[pallet::constant]
+		type DemurragePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum share of a batch, in basis points, a single `apply_demurrage` call may remove
+		#[pallet::constant]
+		type MaxDemurragePerCall: Get<u32>;
+
+		/// Number of blocks after the latest reward accrual or partial claim during which
+		/// pending rewards can be claimed. Rewards older than this are recycled into the reward
+		/// pool on claim or on the next accrual. Set to 0 to disable reward expiry.
+		#[pallet::constant]
+		type RewardClaimWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum reward paid out by a single claim. Larger pending rewards are paid up to
+		/// this amount and the rest stays pending for a later claim, bounding the payout work
+		/// of one call. Set to 0 to pay out the full reward.
+		#[pallet::constant]
+		type MaxClaimPerCall: Get<u128>;
+
+		/// Number of blocks a stake must have been held to share in `distribute_rewards`.
+		/// Stakers who staked more recently are left out of the distribution, so stake added
+		/// just before a distribution earns nothing. Set to 0 to reward every active stake.
+		#[pallet::constant]
+		type StakeRewardDelay: Get<BlockNumberFor<Self>>;
+
+		/// Whether stakers must actively verify to share in `distribute_rewards`. When set,
+		/// only stakers in the current or previous era's verifier set who reported for the
+		/// distributed period earn; passive stakers earn nothing.
+		#[pallet::constant]
+		type RequireVerificationForReward: Get<bool>;
+
+		/// Maximum number of tickets that can be moved in a single `transfer_tickets_batch` call
+		#[pallet::constant]
+		type MaxTicketsPerBatchTransfer: Get<u32>;
+
+		/// Points burned from the sender for every ticket transferred, to discourage
+		/// scalping. Deducted oldest batch first. Set to 0 to make transfers free.
+		#[pallet::constant]
+		type TicketTransferFee: Get<u128>;
+
+		/// Maximum number of accounts a keeper can claim rewards for in one
+		/// `claim_rewards_for` call
+		#[pallet::constant]
+		type MaxClaimsPerBatch: Get<u32>;
+
+		/// Maximum number of (ticket, owner) pairs checked by one `verify_ticket_ownership`
+		/// query. Pairs beyond this are reported as not owned.
+		#[pallet::constant]
+		type MaxOwnershipChecks: Get<u32>;
+
+		/// Maximum number of tickets looked up by one `ticket_statuses` query. Tickets
+		/// beyond this are reported as `TicketStatus::NotChecked`.
+		#[pallet::constant]
+		type MaxTicketStatusQueries: Get<u32>;
+
+		/// How staker rewards are paid out when claimed
+		#[pallet::constant]
+		type StakerRewardKind: Get<RewardKind>;
+
+		/// How issuer rewards are paid out when claimed
+		#[pallet::constant]
+		type IssuerRewardKind: Get<RewardKind>;
+
+		/// Number of blocks over which claimed currency rewards vest linearly before they can
+		/// be withdrawn with `withdraw_vested_reward`. Set to 0 to pay them out on claim.
+		#[pallet::constant]
+		type RewardVestingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Hook invoked on every ticket redemption. Use `()` if nothing needs to react.
+		type OnTicketRedeemed: OnTicketRedeemedHandler<Self::AccountId>;
 	}
 
 	// ============================================================================
@@ -438,6 +970,64 @@ pub mod pallet {
 	pub type TotalPoints<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
 
+	/// Snapshot of a user's available (unexpired) points as (block, value), taken only by
+	/// `refresh_balance_cache`. Awards and spends leave it untouched, so it is only served
+	/// in the block it was taken and reflects the balance at the time of the refresh.
+	#[pallet::storage]
+	#[pallet::getter(fn available_cache)]
+	pub type AvailableCache<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u128), OptionQuery>;
+
+	/// Block of each user's last award, spend or ticket transfer.
+	/// Accounts without a record are never considered dormant.
+	#[pallet::storage]
+	#[pallet::getter(fn last_activity)]
+	pub type LastActivity<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Reward period of each authorized issuer's last award or spend, or of its authorization
+	/// if it has not been active since. Issuers without a record count as last active in
+	/// period 0.
+	#[pallet::storage]
+	#[pallet::getter(fn issuer_last_active_period)]
+	pub type IssuerLastActivePeriod<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
+	/// Next available pending award ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_pending_award_id)]
+	pub type NextPendingAwardId<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// Awards waiting for their issuer's confirmation, by award ID
+	#[pallet::storage]
+	#[pallet::getter(fn pending_awards)]
+	pub type PendingAwards<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u128,
+		PendingAward<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Points locked by a user for a pending payment, by user and lock ID
+	#[pallet::storage]
+	#[pallet::getter(fn locked_points)]
+	pub type LockedPoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		u64,
+		PointLock<BlockNumberFor<T>, BoundedVec<PointBatch<BlockNumberFor<T>>, T::MaxPointBatches>>,
+		OptionQuery,
+	>;
+
+	/// Number of open point locks per user
+	#[pallet::storage]
+	#[pallet::getter(fn point_lock_count)]
+	pub type PointLockCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	/// Stores which accounts are authorized to issue points.
 	/// These could be smart contracts or admin accounts.
 	#[pallet::storage]
@@ -445,12 +1035,67 @@ pub mod pallet {
 	pub type AuthorizedIssuers<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+	/// Default for `AcceptsGifts`: every account accepts gifts until it opts out
+	#[pallet::type_value]
+	pub fn DefaultAcceptsGifts() -> bool {
+		true
+	}
+
+	/// Whether each account accepts tickets gifted or transferred to it by others. Only
+	/// accounts that opted out are stored.
+	#[pallet::storage]
+	#[pallet::getter(fn accepts_gifts)]
+	pub type AcceptsGifts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery, DefaultAcceptsGifts>;
+
+	/// Issuers whose awards and spends skip the routine `PointsEarned`, `SpendBreakdown`
+	/// and `PointsSpent` events. Storage is still updated as usual.
+	#[pallet::storage]
+	#[pallet::getter(fn suppress_events)]
+	pub type SuppressEvents<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Issuers whose spends are left out of issuer reward accounting (`IssuerDailyRecords`
+	/// and `PeriodTotalSpent`), e.g. accounts used for internal transfers or promotional
+	/// burns. Set by the admin.
+	#[pallet::storage]
+	#[pallet::getter(fn untracked_spends)]
+	pub type UntrackedSpends<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Redemption bonus per travel type (basis points, below 10000). A type-scoped spend of
+	/// `amount` points of value only deducts `amount - amount * bonus / 10000` points, with
+	/// the discount rounded to nearest.
+	#[pallet::storage]
+	#[pallet::getter(fn travel_type_redemption_bonus)]
+	pub type TravelTypeRedemptionBonus<T: Config> =
+		StorageMap<_, Blake2_128Concat, TravelType, u32, ValueQuery>;
+
+	/// Award multiplier per travel type (basis points, 10000 = 1x). Issuer awards of the type
+	/// credit `amount * multiplier / 10000` points, rounded to nearest; types without an entry
+	/// are awarded 1x.
+	#[pallet::storage]
+	#[pallet::getter(fn travel_type_multiplier)]
+	pub type TravelTypeMultiplier<T: Config> =
+		StorageMap<_, Blake2_128Concat, TravelType, u32, OptionQuery>;
+
+	/// Expiry period per travel type, used instead of `DefaultExpirationPeriod` for awards
+	/// of the type made without a custom expiration
+	#[pallet::storage]
+	#[pallet::getter(fn travel_type_expiry)]
+	pub type TravelTypeExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, TravelType, BlockNumberFor<T>, OptionQuery>;
+
 	/// Stores the admin/root account that can manage authorized issuers.
 	/// This is set during genesis or by sudo.
 	#[pallet::storage]
 	#[pallet::getter(fn admin)]
 	pub type Admin<T: Config> = StorageValue<_, T::AccountId>;
 
+	/// Set while an award or spend is in progress, so nested calls can be rejected
+	#[pallet::storage]
+	pub type ReentrancyLock<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	// ============================================================================
 	// NFT TICKET STORAGE
 	// ============================================================================
@@ -477,16 +1122,48 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Ownership history of each ticket as (owner, since-block), oldest first. Holds at
+	/// most `MaxProvenanceEntries` entries, so heavily traded tickets lose their oldest owners.
+	#[pallet::storage]
+	pub type TicketProvenance<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u128, // Ticket ID
+		BoundedVec<(T::AccountId, BlockNumberFor<T>), T::MaxProvenanceEntries>,
+		ValueQuery,
+	>;
+
+	/// Number of tickets each issuer has minted in a period
+	/// (keyed by period number and issuer). Bounded by `MaxTicketsPerIssuerPerPeriod`.
+	/// Past periods are removed with `prune_issuer_ticket_counts`.
+	#[pallet::storage]
+	pub type IssuerPeriodTicketCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>, // Period number
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Ticket IDs indexed by coded (departure, arrival) route
+	#[pallet::storage]
+	#[pallet::getter(fn tickets_by_route)]
+	pub type TicketsByRoute<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(LocationCode, LocationCode),
+		Blake2_128Concat,
+		u128, // Ticket ID
+		(),
+		OptionQuery,
+	>;
+
 	// ============================================================================
 	// STAKING STORAGE
 	// ============================================================================
 
-	/// Staking information for each staker
-	#[pallet::storage]
-	#[pallet::getter(fn stakes)]
-	pub type Stakes<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, StakeInfo<BlockNumberFor<T>>, OptionQuery>;
-
 	/// Total amount staked in the system
 	#[pallet::storage]
 	#[pallet::getter(fn total_staked)]
@@ -498,6 +1175,13 @@ pub mod pallet {
 	pub type StakerList<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxStakers>, ValueQuery>;
 
+	/// Stakers waiting for a slot in the active set once `StakerList` is full.
+	/// The highest stake is promoted whenever an active slot frees up.
+	#[pallet::storage]
+	#[pallet::getter(fn staker_waitlist)]
+	pub type StakerWaitlist<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxStakerWaitlist>, ValueQuery>;
+
 	// ============================================================================
 	// ISSUER REWARD TRACKING STORAGE
 	// ============================================================================
@@ -527,27 +1211,100 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Accumulated rewards pool for distribution
+	/// Points spent by a user at a given issuer in a period
+	/// (keyed by period number, user and issuer). A non-zero entry takes one of the user's
+	/// `MaxIssuersPerUserPerPeriod` issuer slots, and `retroactive_bonus` credits in
+	/// proportion to it.
 	#[pallet::storage]
-	#[pallet::getter(fn reward_pool)]
-	pub type RewardPool<T: Config> = StorageValue<_, u128, ValueQuery>;
-
-	// ============================================================================
-	// ADVANCED STAKING STORAGE (Slashing, Unbonding, Pools, Eras)
-	// ============================================================================
+	#[pallet::getter(fn user_issuer_spend)]
+	pub type UserIssuerSpend<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, BlockNumberFor<T>>, // Period number
+			NMapKey<Blake2_128Concat, T::AccountId>,      // User account
+			NMapKey<Blake2_128Concat, T::AccountId>,      // Issuer account
+		),
+		u128,
+		ValueQuery,
+	>;
 
-	/// Enhanced staking information using StorageMap for scalability (replaces Vec-based list)
+	/// Points spent by each user in a period across all issuers
+	/// (keyed by period number and user). Bounded by `MaxUserSpendPerPeriod`, and only
+	/// tracked while that cap is enabled. Past periods are removed with
+	/// `prune_user_period_spend`.
 	#[pallet::storage]
-	#[pallet::getter(fn enhanced_stakes)]
-	pub type EnhancedStakes<T: Config> = StorageMap<
+	pub type UserPeriodSpend<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
-		T::AccountId,
+		BlockNumberFor<T>, // Period number
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Users that spent at an issuer in a period and have not yet received a
+	/// `retroactive_bonus` for it (keyed by period number, issuer and user). Indexes
+	/// `UserIssuerSpend` by issuer.
+	#[pallet::storage]
+	pub type IssuerPeriodRedeemers<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, BlockNumberFor<T>>, // Period number
+			NMapKey<Blake2_128Concat, T::AccountId>,      // Issuer account
+			NMapKey<Blake2_128Concat, T::AccountId>,      // User account
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of distinct issuers each user has spent with in a period
+	/// (keyed by period number and user). Bounded by `MaxIssuersPerUserPerPeriod`.
+	#[pallet::storage]
+	pub type UserPeriodIssuerCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>, // Period number
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Batches consumed by each user's most recent spend, as (expires_at_block, amount_taken)
+	/// in consumption order. Absent if the spend touched more than `MaxSpendBreakdownEntries`.
+	#[pallet::storage]
+	#[pallet::getter(fn last_spend_breakdown)]
+	pub type LastSpendBreakdown<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(BlockNumberFor<T>, u128), T::MaxSpendBreakdownEntries>,
+		OptionQuery,
+	>;
+
+	/// Accumulated rewards pool for distribution
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pool)]
+	pub type RewardPool<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	// ============================================================================
+	// ADVANCED STAKING STORAGE (Slashing, Unbonding, Pools, Eras)
+	// ============================================================================
+
+	/// Staking information for each staker. Replaces the legacy `Stakes` map, which
+	/// `migrations::v1` moves over.
+	#[pallet::storage]
+	#[pallet::getter(fn enhanced_stakes)]
+	pub type EnhancedStakes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
 		EnhancedStakeInfo<BlockNumberFor<T>>,
 		OptionQuery,
 	>;
 
-	/// Count of stakers for efficient iteration
+	/// Number of accounts with an entry in `EnhancedStakes`
 	#[pallet::storage]
 	#[pallet::getter(fn staker_count)]
 	pub type StakerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
@@ -563,6 +1320,25 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Stake returned instantly to each staker per period, capped by `MaxInstantUnbondPerPeriod`
+	#[pallet::storage]
+	#[pallet::getter(fn instant_unbonded)]
+	pub type InstantUnbonded<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>, // Period number
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Recovery account allowed to withdraw a staker's matured unbonding on their behalf
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_account)]
+	pub type RecoveryAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	/// Slash records for each staker
 	#[pallet::storage]
 	#[pallet::getter(fn slash_records)]
@@ -574,11 +1350,36 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Recent active stake of each staker, as (block, amount after the change) in block
+	/// order. Bounded by `MaxStakeHistory`; the oldest entry is dropped when full.
+	#[pallet::storage]
+	#[pallet::getter(fn stake_history)]
+	pub type StakeHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(BlockNumberFor<T>, u128), T::MaxStakeHistory>,
+		ValueQuery,
+	>;
+
+	/// Amount each staker has bonded and not yet been paid back: active stake plus pending
+	/// unbonding. Used to check the two never drift above what was bonded. Stakers that
+	/// bonded before this was tracked have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn bonded_stake)]
+	pub type BondedStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u128, OptionQuery>;
+
 	/// Total amount slashed (for statistics)
 	#[pallet::storage]
 	#[pallet::getter(fn total_slashed)]
 	pub type TotalSlashed<T: Config> = StorageValue<_, u128, ValueQuery>;
 
+	/// Number of slashes and total amount slashed per reason (for statistics)
+	#[pallet::storage]
+	pub type SlashStatsByReason<T: Config> =
+		StorageMap<_, Blake2_128Concat, SlashReason, (u32, u128), ValueQuery>;
+
 	/// Staking pools - keyed by pool ID
 	#[pallet::storage]
 	#[pallet::getter(fn pools)]
@@ -595,6 +1396,17 @@ pub mod pallet {
 	#[pallet::getter(fn next_pool_id)]
 	pub type NextPoolId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// Whether pool creation is limited to accounts in `PoolCreators`
+	#[pallet::storage]
+	#[pallet::getter(fn pool_creation_restricted)]
+	pub type PoolCreationRestricted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Accounts allowed to create pools while pool creation is restricted
+	#[pallet::storage]
+	#[pallet::getter(fn pool_creators)]
+	pub type PoolCreators<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
 	/// Delegations by delegator account
 	#[pallet::storage]
 	#[pallet::getter(fn delegations)]
@@ -633,6 +1445,31 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Verifiers that attested to a period's activity
+	#[pallet::storage]
+	pub type VerificationReports<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>, // Period number
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of verification reports per period
+	#[pallet::storage]
+	#[pallet::getter(fn verification_report_count)]
+	pub type VerificationReportCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, u32, ValueQuery>;
+
+	/// Era whose verifiers attest to a period, fixed by the first report for it. The
+	/// period's quorum is measured against this era's verifier set.
+	#[pallet::storage]
+	#[pallet::getter(fn period_verification_era)]
+	pub type PeriodVerificationEra<T: Config> =
+		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, u32, OptionQuery>;
+
 	/// Last era rotation block
 	#[pallet::storage]
 	#[pallet::getter(fn last_era_block)]
@@ -650,6 +1487,24 @@ pub mod pallet {
 	pub type PendingIssuerRewards<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
 
+	/// Claimed currency rewards still vesting per account
+	#[pallet::storage]
+	#[pallet::getter(fn reward_vesting)]
+	pub type RewardVestingSchedules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		RewardVesting<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Block of each account's latest reward accrual or partial claim, used by
+	/// `RewardClaimWindow`
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rewards_since)]
+	pub type PendingRewardsSince<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
 	// ============================================================================
 	// GENESIS CONFIGURATION
 	// ============================================================================
@@ -698,6 +1553,99 @@ pub mod pallet {
 			travel_type: TravelType,
 		},
 
+		/// An award was created that credits points only once its issuer confirms it
+		/// [award_id, issuer, recipient, amount]
+		AwardPending {
+			/// Pending award ID
+			award_id: u128,
+			/// The issuer that created the award
+			issuer: T::AccountId,
+			/// The account that receives the points once confirmed
+			recipient: T::AccountId,
+			/// The number of points to award
+			amount: u128,
+		},
+
+		/// A pending award was confirmed and its points credited
+		/// [award_id, recipient, amount]
+		AwardConfirmed {
+			/// Pending award ID
+			award_id: u128,
+			/// The account that received the points
+			recipient: T::AccountId,
+			/// The number of points credited
+			amount: u128,
+		},
+
+		/// A pending award was voided without crediting any points
+		/// [award_id]
+		AwardCancelled {
+			/// Pending award ID
+			award_id: u128,
+		},
+
+		/// An issuer credited bonus points to users that spent with it in a past period
+		/// [issuer, period, recipients, total]
+		RetroactiveBonusAwarded {
+			/// The issuer paying the bonus
+			issuer: T::AccountId,
+			/// The period whose spending earned the bonus
+			period: BlockNumberFor<T>,
+			/// Number of users credited by this call
+			recipients: u32,
+			/// Total bonus points credited by this call
+			total: u128,
+		},
+
+		/// Redeemers of a period past its `RetroactiveBonusWindow` were removed
+		PeriodRedeemersPruned {
+			/// The issuer the redeemers spent with
+			issuer: T::AccountId,
+			/// The pruned period
+			period: BlockNumberFor<T>,
+			/// Number of redeemers removed by this call
+			removed: u32,
+		},
+
+		/// Points were locked for a pending payment
+		/// [user, lock_id, amount, expires_at]
+		PointsLocked {
+			/// The account whose points were locked
+			user: T::AccountId,
+			/// The user's lock ID
+			lock_id: u64,
+			/// The number of points locked
+			amount: u128,
+			/// Block from which the lock can no longer be committed
+			expires_at: BlockNumberFor<T>,
+		},
+
+		/// A point lock was committed as a spend at an issuer
+		/// [user, lock_id, issuer, amount]
+		PointLockCommitted {
+			/// The account whose locked points were spent
+			user: T::AccountId,
+			/// The user's lock ID
+			lock_id: u64,
+			/// The issuer the points were spent at
+			issuer: T::AccountId,
+			/// The number of points spent
+			amount: u128,
+		},
+
+		/// The points of a point lock were returned to the user
+		/// [user, lock_id, amount, timed_out]
+		PointLockReleased {
+			/// The account the points were returned to
+			user: T::AccountId,
+			/// The user's lock ID
+			lock_id: u64,
+			/// The number of points returned
+			amount: u128,
+			/// Whether the lock was released because it passed `LockTimeout`
+			timed_out: bool,
+		},
+
 		/// Points were spent/used by a user (with issuer tracking)
 		/// [user, amount_spent, remaining_balance, issuer]
 		PointsSpent {
@@ -711,6 +1659,37 @@ pub mod pallet {
 			issuer: T::AccountId,
 		},
 
+		/// The batches that funded a spend, emitted just before its `PointsSpent` event
+		/// [user, issuer, consumed]
+		SpendBreakdown {
+			/// The account that spent the points
+			user: T::AccountId,
+			/// The issuer where points were spent
+			issuer: T::AccountId,
+			/// (expires_at_block, amount_taken) for each consumed batch, oldest first
+			consumed: Vec<(BlockNumberFor<T>, u128)>,
+		},
+
+		/// A user's point batches sharing a travel type and expiry block were merged
+		/// [user, batches_before, batches_after]
+		PointBatchesCompacted {
+			/// The account whose batches were compacted
+			user: T::AccountId,
+			/// Number of batches before compaction
+			batches_before: u32,
+			/// Number of batches after compaction
+			batches_after: u32,
+		},
+
+		/// A user's total balance dropped from positive to zero through a spend, expiry,
+		/// demurrage, escheatment or account migration. Not emitted again until the balance
+		/// is refilled and emptied again.
+		/// [user]
+		BalanceEmptied {
+			/// The account whose balance is now empty
+			user: T::AccountId,
+		},
+
 		/// Points expired for a user (removed from their balance)
 		/// [user, amount_expired, batches_removed]
 		PointsExpired {
@@ -730,10 +1709,121 @@ pub mod pallet {
 		},
 
 		/// An account's authorization to issue points was revoked
-		/// [issuer]
+		/// [issuer, reason]
 		IssuerRevoked {
 			/// The account whose authorization was revoked
 			issuer: T::AccountId,
+			/// Why the authorization was revoked
+			reason: IssuerRevocationReason,
+		},
+
+		/// A type-scoped spend was discounted by the travel type's redemption bonus
+		/// [user, travel_type, value, points_deducted]
+		RedemptionBonusApplied {
+			/// The spending account
+			user: T::AccountId,
+			/// The travel type the points were spent from
+			travel_type: TravelType,
+			/// The value redeemed, in points
+			value: u128,
+			/// The points actually deducted after the bonus
+			points_deducted: u128,
+		},
+
+		/// The redemption bonus of a travel type was set by the admin
+		/// [travel_type, bonus]
+		RedemptionBonusSet {
+			/// The travel type
+			travel_type: TravelType,
+			/// The new bonus in basis points
+			bonus: u32,
+		},
+
+		/// Several settings of a travel type were changed by `configure_travel_type`
+		TravelTypeConfigured {
+			/// The travel type
+			travel_type: TravelType,
+		},
+
+		/// Routine award/spend events were suppressed or re-enabled for an issuer
+		/// [issuer, suppressed]
+		IssuerEventsSuppressionSet {
+			/// The issuer
+			issuer: T::AccountId,
+			/// Whether the issuer's routine events are now suppressed
+			suppressed: bool,
+		},
+
+		/// An issuer's spends were left out of, or counted again towards, issuer rewards
+		IssuerSpendTrackingSet {
+			/// The issuer
+			issuer: T::AccountId,
+			/// Whether the issuer's spends now count towards issuer rewards
+			tracked: bool,
+		},
+
+		/// A user's recorded spend at an issuer was reset by the admin
+		/// [user, issuer, period, previous_amount]
+		UserIssuerSpendReset {
+			/// The user whose spend record was cleared
+			user: T::AccountId,
+			/// The issuer the spend was recorded against
+			issuer: T::AccountId,
+			/// The period that was reset
+			period: BlockNumberFor<T>,
+			/// The amount recorded before the reset
+			previous_amount: u128,
+		},
+
+		/// Demurrage reduced a user's point balance
+		/// [user, amount_reduced]
+		DemurrageApplied {
+			/// The account whose points lost value
+			user: T::AccountId,
+			/// The total amount of points removed
+			amount_reduced: u128,
+		},
+
+		/// A dormant account's points were moved to the escheatment account
+		/// [user, amount]
+		PointsEscheated {
+			/// The dormant account
+			user: T::AccountId,
+			/// The amount of unexpired points escheated
+			amount: u128,
+		},
+
+		/// An account's points were moved to another account by the admin
+		/// [from, to, amount]
+		AccountPointsMigrated {
+			/// The old account
+			from: T::AccountId,
+			/// The new account
+			to: T::AccountId,
+			/// The amount of unexpired points moved
+			amount: u128,
+		},
+
+		/// A point batch's travel type was corrected
+		/// [user, batch_index, old_type, new_type]
+		BatchReclassified {
+			/// The account holding the batch
+			user: T::AccountId,
+			/// Index of the batch in the user's `UserPoints`
+			batch_index: u32,
+			/// The previous travel type
+			old_type: TravelType,
+			/// The new travel type
+			new_type: TravelType,
+		},
+
+		/// All of a user's live or recently expired point batches were extended
+		/// [user, batch_count]
+		AllBatchesExtended {
+			/// The account holding the batches
+			user: T::AccountId,
+			/// The number of batches extended
+			batch_count: u32,
 		},
 
 		/// Admin account was changed
@@ -745,6 +1835,16 @@ pub mod pallet {
 			new_admin: T::AccountId,
 		},
 
+		/// Audit record emitted alongside the specific event of every destructive admin call
+		AdminActionPerformed {
+			/// The admin that performed the action (`None` for root)
+			admin: Option<T::AccountId>,
+			/// The operation performed
+			action: AdminAction,
+			/// The account affected, if any
+			target: Option<T::AccountId>,
+		},
+
 		/// A new ticket was minted
 		TicketMinted {
 			/// Ticket ID
@@ -759,6 +1859,16 @@ pub mod pallet {
 			points_cost: u128,
 		},
 
+		/// A ticket was minted to one account and paid for by another
+		TicketGifted {
+			/// Ticket ID
+			ticket_id: u128,
+			/// Account whose points paid for the ticket
+			payer: T::AccountId,
+			/// Owner of the ticket
+			recipient: T::AccountId,
+		},
+
 		/// A ticket was redeemed/used
 		TicketRedeemed {
 			/// Ticket ID
@@ -767,6 +1877,33 @@ pub mod pallet {
 			owner: T::AccountId,
 		},
 
+		/// One segment of a multi-segment ticket was redeemed
+		TicketSegmentRedeemed {
+			/// Ticket ID
+			ticket_id: u128,
+			/// Number of the redeemed segment, starting at 1
+			segment: u8,
+		},
+
+		/// One seat of a group ticket was used
+		TicketSeatUsed {
+			/// Ticket ID
+			ticket_id: u128,
+			/// Seats used on the current segment, including this one
+			used: u32,
+			/// Number of travelers covered by the ticket
+			capacity: u32,
+		},
+
+		/// An account opted out of, or back into, receiving gifted tickets
+		/// [account, accepts]
+		GiftAcceptanceSet {
+			/// The account
+			account: T::AccountId,
+			/// Whether the account now accepts gifts
+			accepts: bool,
+		},
+
 		/// A ticket was transferred to a new owner
 		TicketTransferred {
 			/// Ticket ID
@@ -777,6 +1914,27 @@ pub mod pallet {
 			to: T::AccountId,
 		},
 
+		/// The ticket transfer fee was burned from the sender's points
+		TicketTransferFeeBurned {
+			/// The sender of the transferred tickets
+			account: T::AccountId,
+			/// Points burned
+			fee: u128,
+		},
+
+		/// Points were burned locally to be credited on another parachain. The runtime's XCM
+		/// adapter turns this into the cross-chain message.
+		PointsTeleported {
+			/// The account whose points were burned
+			from: T::AccountId,
+			/// ID of the destination parachain
+			dest_para: u32,
+			/// Encoded beneficiary on the destination chain
+			beneficiary: BoundedVec<u8, ConstU32<MAX_BENEFICIARY_LEN>>,
+			/// Points burned
+			amount: u128,
+		},
+
 		/// A ticket was unminted/burned by its owner
 		TicketUnminted {
 			/// Ticket ID
@@ -811,6 +1969,39 @@ pub mod pallet {
 			amount: u128,
 		},
 
+		/// A staker was placed on the waitlist because the active set is full
+		StakerWaitlisted {
+			/// Staker account
+			staker: T::AccountId,
+			/// Stake of the waitlisted staker
+			amount: u128,
+		},
+
+		/// A waitlisted staker was promoted into the active set
+		StakerPromoted {
+			/// Staker account
+			staker: T::AccountId,
+			/// Stake of the promoted staker
+			amount: u128,
+		},
+
+		/// A staker was pushed out of a full waitlist by a larger stake. It keeps its stake
+		/// but earns no rewards until it is placed again
+		StakerEvicted {
+			/// Staker account
+			staker: T::AccountId,
+			/// Stake of the evicted staker
+			amount: u128,
+		},
+
+		/// `StakerList` and `StakerWaitlist` were rebuilt from `EnhancedStakes`
+		StakerListRebuilt {
+			/// Number of stakers in the rebuilt list
+			count: u32,
+			/// Number of stakers in the rebuilt waitlist
+			waitlisted: u32,
+		},
+
 		/// Tokens were unstaked
 		Unstaked {
 			/// Staker account
@@ -819,6 +2010,12 @@ pub mod pallet {
 			amount: u128,
 		},
 
+		/// The reward pool balance was set by the admin or root
+		RewardPoolSet {
+			/// New reward pool balance
+			amount: u128,
+		},
+
 		/// Rewards were distributed
 		RewardsDistributed {
 			/// Period for which rewards were distributed
@@ -837,6 +2034,42 @@ pub mod pallet {
 			amount: u128,
 		},
 
+		/// A claim was capped at `MaxClaimPerCall`; the remainder stays pending
+		PartialRewardClaimed {
+			/// Account that claimed
+			account: T::AccountId,
+			/// Amount paid out by this claim
+			amount: u128,
+			/// Amount left pending for a later claim
+			remaining: u128,
+		},
+
+		/// Pending rewards were left unclaimed past the claim window and returned to the pool
+		RewardExpired {
+			/// Account whose rewards expired
+			account: T::AccountId,
+			/// Amount recycled into the reward pool
+			amount: u128,
+		},
+
+		/// Claimed currency rewards started vesting
+		RewardVestingStarted {
+			/// Account that claimed
+			account: T::AccountId,
+			/// Amount now vesting, including any unvested amount carried over
+			amount: u128,
+			/// Block from which the whole amount is vested
+			ends_at: BlockNumberFor<T>,
+		},
+
+		/// Vested rewards were paid out
+		VestedRewardWithdrawn {
+			/// Account that received the rewards
+			account: T::AccountId,
+			/// Amount paid out
+			amount: u128,
+		},
+
 		// ============================================================================
 		// ADVANCED STAKING EVENTS
 		// ============================================================================
@@ -851,6 +2084,14 @@ pub mod pallet {
 			reason: SlashReason,
 		},
 
+		/// Part of a slash was added to the reward pool
+		SlashRecycled {
+			/// Staker account that was slashed
+			staker: T::AccountId,
+			/// Amount of the slash added to the reward pool
+			amount: u128,
+		},
+
 		/// Unbonding initiated (stake locked until unbonding period ends)
 		UnbondingInitiated {
 			/// Staker account
@@ -861,24 +2102,62 @@ pub mod pallet {
 			unlocks_at: BlockNumberFor<T>,
 		},
 
-		/// Unbonded funds withdrawn
-		UnbondingWithdrawn {
+		/// A small unbonding request was paid out immediately, skipping the unbonding period
+		InstantUnbonded {
 			/// Staker account
 			staker: T::AccountId,
-			/// Amount withdrawn
+			/// Amount returned
 			amount: u128,
 		},
 
-		/// Unbonding request was cancelled
-		UnbondingCancelled {
+		/// Unbonding stake was withdrawn before it unlocked, for a penalty
+		EmergencyWithdrawn {
 			/// Staker account
 			staker: T::AccountId,
-			/// Amount re-staked
+			/// Stake removed
 			amount: u128,
+			/// Amount returned to the staker after the penalty
+			returned: u128,
+			/// Penalty added to the reward pool
+			penalty: u128,
 		},
 
-		/// A new staking pool was created
-		PoolCreated {
+		/// Unbonded funds withdrawn
+		UnbondingWithdrawn {
+			/// Staker account
+			staker: T::AccountId,
+			/// Amount withdrawn
+			amount: u128,
+		},
+
+		/// A staker designated a recovery account
+		StakingRecoverySet {
+			/// Staker account
+			staker: T::AccountId,
+			/// Account allowed to recover the staker's unbonded funds
+			recovery: T::AccountId,
+		},
+
+		/// A recovery account withdrew a staker's matured unbonding
+		StakingRecovered {
+			/// Staker whose unbonding was withdrawn
+			staker: T::AccountId,
+			/// Recovery account that received the funds
+			recovery: T::AccountId,
+			/// Amount withdrawn
+			amount: u128,
+		},
+
+		/// Unbonding request was cancelled
+		UnbondingCancelled {
+			/// Staker account
+			staker: T::AccountId,
+			/// Amount re-staked
+			amount: u128,
+		},
+
+		/// A new staking pool was created
+		PoolCreated {
 			/// Pool ID
 			pool_id: u32,
 			/// Operator account
@@ -925,6 +2204,35 @@ pub mod pallet {
 			operator: T::AccountId,
 		},
 
+		/// A leftover delegator index for a removed pool was cleaned up
+		PoolIndexCleaned {
+			/// Pool ID
+			pool_id: u32,
+		},
+
+		/// Delegations stranded in a removed pool were released by the admin
+		/// [pool_id, amount]
+		ClosedPoolResidueSwept {
+			/// Pool ID
+			pool_id: u32,
+			/// Total delegated amount released from `TotalStaked`
+			amount: u128,
+		},
+
+		/// Pool creation was restricted to the allow-list, or opened to everyone
+		PoolCreationRestrictionSet {
+			/// Whether pool creation is now restricted
+			restricted: bool,
+		},
+
+		/// An account was added to or removed from the pool creator allow-list
+		PoolCreatorUpdated {
+			/// The account
+			account: T::AccountId,
+			/// Whether the account may now create pools
+			allowed: bool,
+		},
+
 		/// New era started and verifiers rotated
 		EraRotated {
 			/// New era number
@@ -941,6 +2249,22 @@ pub mod pallet {
 			verifier: T::AccountId,
 		},
 
+		/// A verifier attested to a period's activity
+		VerificationReported {
+			/// Verifier account
+			verifier: T::AccountId,
+			/// Period number
+			period: BlockNumberFor<T>,
+		},
+
+		/// The verification reports of an undistributed period were removed
+		VerificationReportsPruned {
+			/// Period number
+			period: BlockNumberFor<T>,
+			/// Number of reports removed
+			removed: u32,
+		},
+
 		/// Staker added additional stake
 		StakeIncreased {
 			/// Staker account
@@ -950,6 +2274,22 @@ pub mod pallet {
 			/// New total stake
 			new_total: u128,
 		},
+
+		/// The per-user spend totals of a past period were removed
+		UserPeriodSpendPruned {
+			/// The pruned period
+			period: BlockNumberFor<T>,
+			/// Number of users' totals removed by this call
+			removed: u32,
+		},
+
+		/// The per-issuer ticket counts of a past period were removed
+		IssuerTicketCountsPruned {
+			/// The pruned period
+			period: BlockNumberFor<T>,
+			/// Number of issuers' counts removed by this call
+			removed: u32,
+		},
 	}
 
 	// ============================================================================
@@ -1044,6 +2384,146 @@ pub mod pallet {
 		NotVerifier,
 		/// Insufficient balance for operation
 		InsufficientBalance,
+		/// User holds enough points, but some are still within `MinHoldingPeriod`
+		PointsNotYetSpendable,
+		/// Spend would leave a non-zero balance below `MinRetainedPoints`
+		WouldLeaveDust,
+		/// The user already spent with `MaxIssuersPerUserPerPeriod` issuers this period
+		TooManyIssuersThisPeriod,
+		/// The spend would take the user past `MaxUserSpendPerPeriod` this period
+		UserSpendCapExceeded,
+		/// A redemption bonus must be below 10000 basis points
+		InvalidRedemptionBonus,
+		/// An award multiplier must be non-zero
+		InvalidMultiplier,
+		/// A retroactive bonus must be between 1 and 10000 basis points
+		InvalidBonusRate,
+		/// The period has not ended yet
+		PeriodNotEnded,
+		/// The account has been active within `DormancyPeriod`
+		AccountNotDormant,
+		/// The dormant account holds no unexpired points
+		NothingToEscheat,
+		/// Demurrage is disabled (`DemurrageRate` is 0)
+		DemurrageDisabled,
+		/// No demurrage is due, e.g. because it was already applied in this block
+		NoDemurrageDue,
+		/// Points cannot be migrated from an account to itself
+		MigrationToSameAccount,
+		/// The source account holds no unexpired points to migrate
+		NothingToMigrate,
+		/// No point batch exists at the given index
+		InvalidBatchIndex,
+		/// The user holds no unexpired or reinstatable point batches to extend
+		NothingToExtend,
+		/// The batch would exceed the maximum number of distinct travel types per user
+		TooManyTravelTypes,
+		/// Award amount is below `MinAwardAmount`
+		AwardBelowMinimum,
+		/// A custom expiration period or extension must be at least one block
+		InvalidExpiration,
+		/// No pending award exists with the given ID
+		PendingAwardNotFound,
+		/// Only the issuer that created a pending award (or the admin, to cancel) may act on it
+		NotAwardIssuer,
+		/// The user already has a point lock with this ID
+		PointLockExists,
+		/// No point lock exists with the given ID
+		PointLockNotFound,
+		/// The point lock has passed `LockTimeout` and can only be released
+		PointLockTimedOut,
+		/// The user already has `MaxPointLocks` open point locks
+		TooManyPointLocks,
+		/// An award or spend was attempted while another one is still in progress
+		ReentrancyDetected,
+		/// The issuer was active within `IssuerInactivityPeriod` periods, or pruning is disabled
+		IssuerStillActive,
+		/// Only the user or an authorized issuer may read the user's point details
+		PointDetailsNotVisible,
+		/// A ticket must cover at least one segment
+		InvalidSegmentCount,
+		/// A ticket must cover at least one traveler
+		InvalidTicketCapacity,
+		/// The recipient opted out of receiving gifted or transferred tickets
+		RecipientRejectsGifts,
+		/// The issuer already minted `MaxTicketsPerIssuerPerPeriod` tickets this period
+		IssuerTicketCapExceeded,
+		/// Verifiers of the current era cannot withdraw stake in an emergency
+		VerifierCannotEmergencyWithdraw,
+		/// The account has no vesting rewards
+		NoVestingRewards,
+		/// None of the account's vesting rewards have vested yet
+		NothingVested,
+		/// The teleport beneficiary is empty or longer than `MAX_BENEFICIARY_LEN`
+		InvalidBeneficiary,
+		/// No tickets were provided for a batch operation
+		EmptyTicketBatch,
+		/// Too many tickets provided for a single batch operation
+		TooManyTicketsInBatch,
+		/// No accounts were provided for a batch reward claim
+		EmptyClaimBatch,
+		/// Too many accounts provided for a single batch reward claim
+		TooManyClaimsInBatch,
+		/// Location code is empty, too long, or not alphanumeric
+		InvalidLocationCode,
+		/// A staker cannot be its own recovery account
+		InvalidRecoveryAccount,
+		/// A recovery account cannot be set after the staker has been slashed
+		RecoveryAfterSlash,
+		/// Caller is not the staker's recovery account
+		NotRecoveryAccount,
+		/// Pool still exists, its delegator index cannot be cleaned up
+		PoolStillExists,
+		/// No delegator index stored for the pool
+		PoolIndexNotFound,
+		/// Pool creation is restricted and the caller is not an allowed pool creator
+		NotAuthorizedPoolCreator,
+		/// Delegation would exceed `MaxDelegationPerPoolPerAccount`
+		DelegationCapExceeded,
+		/// Pool has not existed for `MinPoolLifetime` blocks yet, cannot close
+		PoolTooYoung,
+		/// The verifier already reported for this period
+		AlreadyReported,
+		/// Too few verifiers have reported for the period to release its rewards
+		VerificationQuorumNotMet,
+		/// `BlocksPerRewardPeriod` is zero, so reward periods are meaningless
+		RewardPeriodMisconfigured,
+		/// The period is past its `RetroactiveBonusWindow`
+		RetroactiveBonusWindowClosed,
+		/// The period is still within its `RetroactiveBonusWindow`
+		RetroactiveBonusWindowOpen,
+		/// The user's point balance would exceed the maximum representable amount
+		PointBalanceOverflow,
+		/// The spend would take the user's point balance below zero
+		PointBalanceUnderflow,
+		/// A point batch would hold more than the maximum representable amount
+		BatchPointsOverflow,
+		/// A deduction would take a point batch below zero
+		BatchPointsUnderflow,
+		/// The staker's stake would exceed the maximum representable amount
+		StakeOverflow,
+		/// `TotalStaked` would exceed the maximum representable amount
+		TotalStakeOverflow,
+		/// The reward pool would exceed the maximum representable amount
+		RewardPoolOverflow,
+		/// The period has not started yet
+		PeriodInFuture,
+		/// The `stakes` witness is lower than the number of `EnhancedStakes` entries
+		InvalidStakeWitness,
+		/// The account still has open point locks
+		PointLocksOpen,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Reject a runtime configured with a zero `BlocksPerRewardPeriod`, which would make
+		/// every block its own reward period. Runs in the runtime's integrity tests.
+		fn integrity_test() {
+			assert!(
+				!T::BlocksPerRewardPeriod::get().is_zero(),
+				"`BlocksPerRewardPeriod` must not be zero"
+			);
+		}
 	}
 
 	// ============================================================================
@@ -1063,79 +2543,292 @@ pub mod pallet {
 		/// ## Parameters
 		/// - `origin`: Must be an authorized issuer
 		/// - `recipient`: The account to receive the points
-		/// - `amount`: The number of points to award (must be > 0)
+		/// - `amount`: The number of points to award (must be > 0), scaled by the travel type's
+		///   `TravelTypeMultiplier` if one is configured
 		/// - `travel_type`: The type of travel that earned these points
-		/// - `custom_expiration`: Optional custom expiration period in blocks.
-		///   If None, uses the default expiration period.
+		/// - `custom_expiration`: Optional custom expiration period in blocks (must be > 0, as
+		///   a zero period would expire the points right away). If None, uses the travel type's
+		///   `TravelTypeExpiry`, or else the default expiration period.
 		///
 		/// ## Emits
 		/// - `PointsEarned` on success
 		///
 		/// ## Errors
 		/// - `NotAuthorizedIssuer` if the caller is not authorized
-		/// - `ZeroAmount` if amount is 0
+		/// - `ZeroAmount` if amount is 0, or rounds to 0 with the travel type's multiplier
+		/// - `AwardBelowMinimum` if amount is below `MinAwardAmount`
+		/// - `InvalidExpiration` if `custom_expiration` is zero
+		/// - `TooManyTravelTypes` if the batch adds one distinct travel type too many
 		/// - `TooManyBatches` if the user already has max batches
-		/// - `ArithmeticOverflow` if calculations overflow
+		/// - `PointBalanceOverflow` if the recipient's balance would overflow
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::award_points())]
+		#[pallet::weight(T::WeightInfo::award_points(T::MaxPointBatches::get()))]
 		pub fn award_points(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
 			amount: u128,
 			travel_type: TravelType,
 			custom_expiration: Option<BlockNumberFor<T>>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			// Verify the caller is an authorized issuer
 			let issuer = ensure_signed(origin)?;
-			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
 
-			// Amount must be greater than zero
+			let (batches_held, _) = Self::award_points_internal(
+				issuer,
+				recipient,
+				amount,
+				travel_type,
+				custom_expiration,
+			)?;
+
+			Ok(Some(T::WeightInfo::award_points(batches_held)).into())
+		}
+
+		/// Create an award that only credits points once the issuer confirms it with
+		/// `confirm_award`, e.g. after a flight has been flown. Until then the points are not
+		/// part of the recipient's balance. Only callable by authorized issuers.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be an authorized issuer
+		/// - `recipient`: The account to receive the points
+		/// - `amount`: The number of points to award (must be > 0)
+		/// - `travel_type`: The type of travel that earns these points
+		/// - `custom_expiration`: As for `award_points`, counted from confirmation
+		///
+		/// ## Emits
+		/// - `AwardPending` with the new award's ID
+		///
+		/// ## Errors
+		/// - `NotAuthorizedIssuer` if the caller is not authorized
+		/// - `ZeroAmount` if amount is 0
+		/// - `AwardBelowMinimum` if amount is below `MinAwardAmount`
+		/// - `InvalidExpiration` if `custom_expiration` is zero
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::award_points_pending())]
+		pub fn award_points_pending(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			amount: u128,
+			travel_type: TravelType,
+			custom_expiration: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
 			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(amount >= T::MinAwardAmount::get(), Error::<T>::AwardBelowMinimum);
+			ensure!(
+				custom_expiration.is_none_or(|period| !period.is_zero()),
+				Error::<T>::InvalidExpiration
+			);
 
-			// Get current block number
-			let current_block = frame_system::Pallet::<T>::block_number();
+			let award_id = NextPendingAwardId::<T>::get();
+			NextPendingAwardId::<T>::put(award_id.saturating_add(1));
+
+			PendingAwards::<T>::insert(
+				award_id,
+				PendingAward {
+					issuer: issuer.clone(),
+					recipient: recipient.clone(),
+					amount,
+					travel_type,
+					custom_expiration,
+					created_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
 
-			// Calculate expiration block
-			let expiration_period = custom_expiration.unwrap_or(T::DefaultExpirationPeriod::get());
-			let expires_at_block = current_block.saturating_add(expiration_period);
+			Self::deposit_event(Event::AwardPending { award_id, issuer, recipient, amount });
+			Ok(())
+		}
 
-			// Create the new point batch
-			let new_batch = PointBatch {
-				earned_at_block: current_block,
-				expires_at_block,
-				remaining_points: amount,
-				travel_type: travel_type.clone(),
-			};
+		/// Confirm a pending award, crediting its points to the recipient as `award_points`
+		/// would at this block, including the travel type's current multiplier. The issuer
+		/// must still be authorized.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the issuer that created the award
+		/// - `award_id`: ID of the pending award
+		///
+		/// ## Emits
+		/// - `AwardConfirmed` and `PointsEarned` on success
+		///
+		/// ## Errors
+		/// - `PendingAwardNotFound` if no pending award has this ID
+		/// - `NotAwardIssuer` if the caller did not create the award
+		/// - otherwise as for `award_points`
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::confirm_award(T::MaxPointBatches::get()))]
+		pub fn confirm_award(origin: OriginFor<T>, award_id: u128) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
 
-			// Add the batch to the user's batches
-			UserPoints::<T>::try_mutate(&recipient, |batches| -> DispatchResult {
-				// First, clean up any expired batches to make room
-				Self::remove_expired_batches_internal(&recipient, batches, current_block);
+			let award =
+				PendingAwards::<T>::take(award_id).ok_or(Error::<T>::PendingAwardNotFound)?;
+			ensure!(award.issuer == caller, Error::<T>::NotAwardIssuer);
+
+			let (batches_held, outcome) = Self::award_points_internal(
+				award.issuer,
+				award.recipient.clone(),
+				award.amount,
+				award.travel_type,
+				award.custom_expiration,
+			)?;
+
+			Self::deposit_event(Event::AwardConfirmed {
+				award_id,
+				recipient: award.recipient,
+				amount: outcome.amount,
+			});
 
-				// Try to add the new batch
-				batches.try_push(new_batch).map_err(|_| Error::<T>::TooManyBatches)?;
+			Ok(Some(T::WeightInfo::confirm_award(batches_held)).into())
+		}
 
-				// Sort batches by expiration date (oldest first) for FIFO deduction
-				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+		/// Void a pending award without crediting any points.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the issuer that created the award, or the admin
+		/// - `award_id`: ID of the pending award
+		///
+		/// ## Emits
+		/// - `AwardCancelled` on success
+		///
+		/// ## Errors
+		/// - `PendingAwardNotFound` if no pending award has this ID
+		/// - `NotAwardIssuer` if the caller is neither the award's issuer nor the admin
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::cancel_pending_award())]
+		pub fn cancel_pending_award(origin: OriginFor<T>, award_id: u128) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-				Ok(())
-			})?;
+			let award =
+				PendingAwards::<T>::get(award_id).ok_or(Error::<T>::PendingAwardNotFound)?;
+			ensure!(award.issuer == caller || Self::is_admin(&caller), Error::<T>::NotAwardIssuer);
 
-			// Update total points balance
-			TotalPoints::<T>::try_mutate(&recipient, |total| -> DispatchResult {
-				*total = total.checked_add(amount).ok_or(Error::<T>::ArithmeticOverflow)?;
-				Ok(())
+			PendingAwards::<T>::remove(award_id);
+
+			Self::deposit_event(Event::AwardCancelled { award_id });
+			Ok(())
+		}
+
+		/// Credit bonus points to every user that spent with the caller in a past period, e.g.
+		/// after a promotion. Each user gets `spent * bonus_bps / 10000` points (rounded down)
+		/// of the `Other` travel type with the default expiry, based on their
+		/// `UserIssuerSpend` at the caller in `period`. `MinAwardAmount` and travel type
+		/// multipliers do not apply.
+		///
+		/// At most `MaxRetroactiveRecipients` users are credited per call; call again to
+		/// continue with the rest. Every user is credited once per issuer and period, so the
+		/// call can be repeated until no redeemers are left. A user whose bonus rounds to zero,
+		/// or who cannot take another batch, is passed over without a bonus.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be an authorized issuer
+		/// - `period`: The ended reward period whose spending earns the bonus
+		/// - `bonus_bps`: Bonus in basis points of each user's spend (1 to 10000)
+		///
+		/// ## Emits
+		/// - `PointsEarned` for every credited user (unless the issuer suppresses events)
+		/// - `RetroactiveBonusAwarded` on success
+		///
+		/// ## Errors
+		/// - `NotAuthorizedIssuer` if the caller is not authorized
+		/// - `InvalidBonusRate` if `bonus_bps` is 0 or above 10000
+		/// - `PeriodNotEnded` if `period` is the current period or later
+		/// - `RetroactiveBonusWindowClosed` if `period` ended more than
+		///   `RetroactiveBonusWindow` periods ago
+		#[pallet::call_index(64)]
+		#[pallet::weight(T::WeightInfo::retroactive_bonus(T::MaxRetroactiveRecipients::get()))]
+		pub fn retroactive_bonus(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+			bonus_bps: u32,
+		) -> DispatchResultWithPostInfo {
+			let issuer = ensure_signed(origin)?;
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
+			ensure!((1..=10_000).contains(&bonus_bps), Error::<T>::InvalidBonusRate);
+			// Spends in the current period would re-add users that were already credited
+			ensure!(period < Self::current_period(), Error::<T>::PeriodNotEnded);
+			ensure!(
+				Self::current_period() <= period.saturating_add(T::RetroactiveBonusWindow::get()),
+				Error::<T>::RetroactiveBonusWindowClosed
+			);
+
+			let redeemers: Vec<T::AccountId> =
+				IssuerPeriodRedeemers::<T>::drain_prefix((period, issuer.clone()))
+					.take(T::MaxRetroactiveRecipients::get() as usize)
+					.map(|(user, ())| user)
+					.collect();
+			let processed = redeemers.len() as u32;
+
+			let emit_event = !SuppressEvents::<T>::get(&issuer);
+			let (recipients, total) = Self::with_reentrancy_guard(|| {
+				let mut recipients: u32 = 0;
+				let mut total: u128 = 0;
+				for user in redeemers {
+					let bonus = UserIssuerSpend::<T>::get((period, &user, &issuer))
+						.saturating_mul(bonus_bps as u128)
+						.saturating_div(10_000);
+					if bonus.is_zero() {
+						continue;
+					}
+					// A user that cannot take the batch is skipped without partial writes
+					let credited = frame_support::storage::with_storage_layer(|| {
+						Self::credit_point_batch(user, bonus, TravelType::Other, None, emit_event)
+					});
+					if credited.is_ok() {
+						recipients = recipients.saturating_add(1);
+						total = total.saturating_add(bonus);
+					}
+				}
+				Ok((recipients, total))
 			})?;
 
-			// Emit event
-			Self::deposit_event(Event::PointsEarned {
-				recipient,
-				amount,
-				expires_at_block,
-				travel_type,
+			IssuerLastActivePeriod::<T>::insert(&issuer, Self::current_period());
+			Self::deposit_event(Event::RetroactiveBonusAwarded {
+				issuer,
+				period,
+				recipients,
+				total,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::retroactive_bonus(processed)).into())
+		}
+
+		/// Remove the redeemers left in `IssuerPeriodRedeemers` for `issuer` in a period past
+		/// its `RetroactiveBonusWindow`, so that periods nobody bonuses do not stay in storage.
+		/// Can be called by anyone.
+		///
+		/// At most `MaxRetroactiveRecipients` redeemers are removed per call; call again to
+		/// continue with the rest.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `issuer`: The issuer whose redeemers to remove
+		/// - `period`: The period to prune
+		///
+		/// ## Emits
+		/// - `PeriodRedeemersPruned` on success
+		///
+		/// ## Errors
+		/// - `RetroactiveBonusWindowOpen` if `period` can still receive a `retroactive_bonus`
+		#[pallet::call_index(67)]
+		#[pallet::weight(T::WeightInfo::prune_period_redeemers(T::MaxRetroactiveRecipients::get()))]
+		pub fn prune_period_redeemers(
+			origin: OriginFor<T>,
+			issuer: T::AccountId,
+			period: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				Self::current_period() > period.saturating_add(T::RetroactiveBonusWindow::get()),
+				Error::<T>::RetroactiveBonusWindowOpen
+			);
+
+			let removed = IssuerPeriodRedeemers::<T>::drain_prefix((period, issuer.clone()))
+				.take(T::MaxRetroactiveRecipients::get() as usize)
+				.count() as u32;
+
+			Self::deposit_event(Event::PeriodRedeemersPruned { issuer, period, removed });
+
+			Ok(Some(T::WeightInfo::prune_period_redeemers(removed)).into())
 		}
 
 		/// Spend points from a user's balance. Uses FIFO (oldest points first).
@@ -1149,13 +2842,22 @@ pub mod pallet {
 		/// - `amount`: The number of points to spend (must be > 0)
 		///
 		/// ## Emits
+		/// - `SpendBreakdown` listing the consumed batches (if within `MaxSpendBreakdownEntries`)
 		/// - `PointsSpent` on success
 		///
 		/// ## Errors
 		/// - `ZeroAmount` if amount is 0
 		/// - `InsufficientPoints` if user doesn't have enough points
-		/// - `ArithmeticUnderflow` if calculations underflow
+		/// - `PointsNotYetSpendable` if the user holds enough points, but too many of them
+		///   were earned less than `MinHoldingPeriod` blocks ago
+		/// - `WouldLeaveDust` if the remaining balance would be non-zero but below
+		///   `MinRetainedPoints`
+		/// - `PointBalanceUnderflow` if the user's balance would underflow
 		/// - `NotAuthorizedIssuer` if issuer is not authorized
+		/// - `TooManyIssuersThisPeriod` if `issuer` would be the user's
+		///   (`MaxIssuersPerUserPerPeriod` + 1)th distinct issuer this period
+		/// - `UserSpendCapExceeded` if the user would spend more than `MaxUserSpendPerPeriod`
+		///   this period, across all issuers
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::spend_points())]
 		pub fn spend_points(
@@ -1171,147 +2873,1075 @@ pub mod pallet {
 			// Verify the issuer is authorized
 			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
 
-			// Get current block for expiration checking
-			let current_block = frame_system::Pallet::<T>::block_number();
-
-			let mut remaining_to_spend = amount;
-
-			// Deduct points from batches (FIFO - oldest first)
-			UserPoints::<T>::try_mutate(&user, |batches| -> DispatchResult {
-				// First, remove expired batches
-				Self::remove_expired_batches_internal(&user, batches, current_block);
-
-				// Calculate total available points (non-expired)
-				let available: u128 = batches.iter().map(|b| b.remaining_points).sum();
-				ensure!(available >= amount, Error::<T>::InsufficientPoints);
-
-				// Deduct from batches (they're already sorted by expiration - oldest first)
-				// We iterate through and deduct from each batch until we've spent enough
-				for batch in batches.iter_mut() {
-					if remaining_to_spend == 0 {
-						break;
-					}
-
-					// How much can we take from this batch?
-					let deduction = remaining_to_spend.min(batch.remaining_points);
-					batch.remaining_points = batch
-						.remaining_points
-						.checked_sub(deduction)
-						.ok_or(Error::<T>::ArithmeticUnderflow)?;
-					remaining_to_spend = remaining_to_spend
-						.checked_sub(deduction)
-						.ok_or(Error::<T>::ArithmeticUnderflow)?;
-				}
-
-				// Remove any batches that are now empty
-				batches.retain(|b| b.remaining_points > 0);
-
-				Ok(())
-			})?;
-
-			// Update total points balance
-			let new_balance =
-				TotalPoints::<T>::try_mutate(&user, |total| -> Result<u128, DispatchError> {
-					*total = total.checked_sub(amount).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					Ok(*total)
-				})?;
-
-			// Track spending for issuer reward distribution
-			let period = Self::current_period();
-			IssuerDailyRecords::<T>::mutate(period, &issuer, |record| {
-				record.points_spent = record.points_spent.saturating_add(amount);
-				record.transaction_count = record.transaction_count.saturating_add(1);
-			});
-			PeriodTotalSpent::<T>::mutate(period, |total| {
-				*total = total.saturating_add(amount);
-			});
-
-			// Emit event
-			Self::deposit_event(Event::PointsSpent {
-				user,
-				amount_spent: amount,
-				remaining_balance: new_balance,
-				issuer,
-			});
-
-			Ok(())
+			Self::spend_points_internal(&user, amount, &issuer, None)
 		}
 
-		/// Clean up expired point batches for a user.
+		/// Redeem `value` points' worth from batches of a single travel type, discounted by
+		/// that type's `TravelTypeRedemptionBonus`.
 		///
-		/// This is a maintenance function that can be called by anyone to remove
-		/// expired batches from a user's storage. This helps keep storage clean
-		/// and reduces storage costs.
+		/// The bonus is applied as a discount, not as credited points: the user is charged
+		/// `value - value * bonus / 10000` points (the discount rounds to nearest), taken FIFO
+		/// from their live batches of `travel_type` only. Issuer reward accounting and
+		/// `MaxUserSpendPerPeriod` count the points actually deducted. `MinRetainedPoints`
+		/// applies to the user's whole balance.
 		///
 		/// ## Parameters
-		/// - `origin`: Any signed origin
-		/// - `user`: The account whose expired batches should be cleaned
+		/// - `origin`: The signed origin (the user spending their points)
+		/// - `value`: The value being redeemed, in points (must be > 0)
+		/// - `issuer`: The issuer where points are spent
+		/// - `travel_type`: The travel type whose points are spent
 		///
 		/// ## Emits
-		/// - `PointsExpired` if any batches were removed
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::cleanup_expired())]
-		pub fn cleanup_expired(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
-			ensure_signed(origin)?;
+		/// - `SpendBreakdown` listing the consumed batches (if within `MaxSpendBreakdownEntries`)
+		/// - `PointsSpent` with the points deducted
+		/// - `RedemptionBonusApplied` if a bonus reduced the cost
+		///
+		/// ## Errors
+		/// - `InsufficientPoints` if the user holds too few points of `travel_type`
+		/// - otherwise the same as `spend_points`
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::spend_points_of_type())]
+		pub fn spend_points_of_type(
+			origin: OriginFor<T>,
+			value: u128,
+			issuer: T::AccountId,
+			travel_type: TravelType,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
 
-			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(!value.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
 
-			UserPoints::<T>::mutate(&user, |batches| {
-				Self::remove_expired_batches_internal(&user, batches, current_block);
-			});
+			let bonus = TravelTypeRedemptionBonus::<T>::get(&travel_type) as u128;
+			// Rounds to nearest so the user does not lose a point of discount to truncation
+			let discount = div_round_nearest(value.saturating_mul(bonus), 10_000);
+			let points_deducted = value.saturating_sub(discount);
 
+			Self::spend_points_internal(&user, points_deducted, &issuer, Some(&travel_type))?;
+
+			if !discount.is_zero() {
+				Self::deposit_event(Event::RedemptionBonusApplied {
+					user,
+					travel_type,
+					value,
+					points_deducted,
+				});
+			}
 			Ok(())
 		}
 
-		/// Authorize an account to issue points.
+		/// Burn points to have them credited on another parachain. The pallet only burns the
+		/// points (FIFO, like a spend) and signals the teleport with `PointsTeleported`; the
+		/// runtime's XCM adapter is responsible for sending the cross-chain message.
+		///
+		/// The burn is not a spend at any issuer, so it is left out of issuer tracking.
+		/// `MinHoldingPeriod` and `MinRetainedPoints` apply as for spends.
 		///
 		/// ## Parameters
-		/// - `origin`: Must be the admin
-		/// - `issuer`: The account to authorize
+		/// - `origin`: The account whose points are teleported
+		/// - `dest_para`: ID of the destination parachain
+		/// - `beneficiary`: Encoded beneficiary on the destination chain
+		/// - `amount`: The number of points to teleport (must be > 0)
+		///
+		/// ## Emits
+		/// - `PointsTeleported` on success
+		///
+		/// ## Errors
+		/// - `ZeroAmount` if amount is 0
+		/// - `InvalidBeneficiary` if `beneficiary` is empty or longer than `MAX_BENEFICIARY_LEN`
+		/// - `InsufficientPoints` if the caller holds fewer than `amount` points
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::teleport_points())]
+		pub fn teleport_points(
+			origin: OriginFor<T>,
+			dest_para: u32,
+			beneficiary: Vec<u8>,
+			amount: u128,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(!beneficiary.is_empty(), Error::<T>::InvalidBeneficiary);
+			let beneficiary: BoundedVec<u8, ConstU32<MAX_BENEFICIARY_LEN>> =
+				BoundedVec::try_from(beneficiary).map_err(|_| Error::<T>::InvalidBeneficiary)?;
+
+			Self::with_reentrancy_guard(|| Self::deduct_points_fifo(&from, amount, None))?;
+			LastActivity::<T>::insert(&from, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::PointsTeleported { from, dest_para, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Lock points for a pending off-chain payment, e.g. while a booking's payment
+		/// authorizes. The points are taken from the caller's batches (FIFO, like a spend) and
+		/// are not part of the balance while locked. `commit_lock` spends them at an issuer,
+		/// `release_lock` returns them.
+		///
+		/// A lock can be committed for `LockTimeout` blocks. After that it can only be released,
+		/// by the caller or by anyone through `cleanup_expired`.
+		///
+		/// ## Parameters
+		/// - `origin`: The account whose points are locked
+		/// - `amount`: The number of points to lock (must be > 0)
+		/// - `lock_id`: ID of the lock, chosen by the caller and unique among its open locks
+		///
+		/// ## Emits
+		/// - `PointsLocked` on success
+		///
+		/// ## Errors
+		/// - `ZeroAmount` if amount is 0
+		/// - `PointLockExists` if the caller already has an open lock with this ID
+		/// - `TooManyPointLocks` if the caller already has `MaxPointLocks` open locks
+		/// - `InsufficientPoints` if the caller holds fewer than `amount` points
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::lock_points())]
+		pub fn lock_points(origin: OriginFor<T>, amount: u128, lock_id: u64) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+
+			ensure!(!LockedPoints::<T>::contains_key(&user, lock_id), Error::<T>::PointLockExists);
+			let locks = PointLockCount::<T>::get(&user);
+			ensure!(locks < T::MaxPointLocks::get(), Error::<T>::TooManyPointLocks);
+
+			let (_, consumed) =
+				Self::with_reentrancy_guard(|| Self::deduct_points_fifo(&user, amount, None))?;
+			// Every piece comes from a distinct batch, so they always fit
+			let batches: BoundedVec<_, T::MaxPointBatches> =
+				BoundedVec::try_from(consumed).map_err(|_| Error::<T>::TooManyBatches)?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let expires_at = current_block.saturating_add(T::LockTimeout::get());
+			LockedPoints::<T>::insert(&user, lock_id, PointLock { amount, expires_at, batches });
+			PointLockCount::<T>::insert(&user, locks.saturating_add(1));
+			LastActivity::<T>::insert(&user, current_block);
+
+			Self::deposit_event(Event::PointsLocked { user, lock_id, amount, expires_at });
+			Ok(())
+		}
+
+		/// Spend the points of one of the caller's point locks at an issuer, e.g. once the
+		/// booking's payment has gone through. The spend is tracked for the issuer like a
+		/// regular `spend_points`.
+		///
+		/// ## Parameters
+		/// - `origin`: The account that created the lock
+		/// - `lock_id`: ID of the lock to commit
+		/// - `issuer`: The issuer the points are spent at
+		///
+		/// ## Emits
+		/// - `PointLockCommitted` on success
+		/// - `PointsSpent` and `SpendBreakdown` unless the issuer suppresses events
+		///
+		/// ## Errors
+		/// - `NotAuthorizedIssuer` if `issuer` is not an authorized issuer
+		/// - `PointLockNotFound` if the caller has no lock with this ID
+		/// - `PointLockTimedOut` if the lock has passed `LockTimeout`
+		/// - `TooManyIssuersThisPeriod` if the caller already spent at
+		///   `MaxIssuersPerUserPerPeriod` other issuers this period
+		/// - `UserSpendCapExceeded` if the lock would take the caller past
+		///   `MaxUserSpendPerPeriod` this period
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::commit_lock())]
+		pub fn commit_lock(
+			origin: OriginFor<T>,
+			lock_id: u64,
+			issuer: T::AccountId,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
+			let lock =
+				LockedPoints::<T>::get(&user, lock_id).ok_or(Error::<T>::PointLockNotFound)?;
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(current_block < lock.expires_at, Error::<T>::PointLockTimedOut);
+
+			Self::with_reentrancy_guard(|| {
+				Self::ensure_issuer_slot(&user, &issuer)?;
+				Self::ensure_user_spend_cap(&user, lock.amount)?;
+				let balance = TotalPoints::<T>::get(&user);
+				Self::record_spend(&user, lock.amount, &issuer, balance, &lock.batches);
+				Ok(())
+			})?;
+
+			LockedPoints::<T>::remove(&user, lock_id);
+			PointLockCount::<T>::mutate(&user, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::PointLockCommitted {
+				user,
+				lock_id,
+				issuer,
+				amount: lock.amount,
+			});
+			Ok(())
+		}
+
+		/// Return the points of one of the caller's point locks, e.g. when the booking's
+		/// payment failed. The points go back into the batches they were taken from and keep
+		/// their original expiry. Timed-out locks can be released as well.
+		///
+		/// ## Parameters
+		/// - `origin`: The account that created the lock
+		/// - `lock_id`: ID of the lock to release
+		///
+		/// ## Emits
+		/// - `PointLockReleased` on success
+		///
+		/// ## Errors
+		/// - `PointLockNotFound` if the caller has no lock with this ID
+		/// - `TooManyBatches` if the caller has no room for a batch that was used up since
+		#[pallet::call_index(63)]
+		#[pallet::weight(T::WeightInfo::release_lock())]
+		pub fn release_lock(origin: OriginFor<T>, lock_id: u64) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+
+			let lock =
+				LockedPoints::<T>::get(&user, lock_id).ok_or(Error::<T>::PointLockNotFound)?;
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let timed_out = lock.expires_at <= current_block;
+
+			Self::return_locked_points(&user, lock_id, lock, timed_out)
+		}
+
+		/// Clean up expired point batches for a user.
+		///
+		/// This is a maintenance function that can be called by anyone to remove
+		/// expired batches from a user's storage. This helps keep storage clean
+		/// and reduces storage costs. Point locks past `LockTimeout` are released first,
+		/// so that holds on the user's points cannot outlive the timeout.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `user`: The account whose expired batches should be cleaned
+		///
+		/// ## Emits
+		/// - `PointLockReleased` for every timed-out point lock
+		/// - `PointsExpired` if any batches were removed
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cleanup_expired(T::MaxPointLocks::get()))]
+		pub fn cleanup_expired(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			let locks_read = Self::release_timed_out_locks(&user, current_block);
+			UserPoints::<T>::mutate(&user, |batches| {
+				Self::remove_expired_batches_internal(&user, batches, current_block);
+			});
+
+			Ok(Some(T::WeightInfo::cleanup_expired(locks_read)).into())
+		}
+
+		/// Reduce the value of a user's live point batches for the time they were held.
+		///
+		/// Each batch loses `DemurrageRate` basis points of its remaining points per
+		/// `DemurragePeriod` blocks since its `last_demurrage_block`, capped at
+		/// `MaxDemurragePerCall` basis points per call. The removed points are burned.
+		/// Batches whose reduction rounds down to zero keep accruing until the next call.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `user`: The account whose points decay
+		///
+		/// ## Emits
+		/// - `DemurrageApplied` on success
+		/// - `PointsExpired` if expired batches were removed first
+		///
+		/// ## Errors
+		/// - `DemurrageDisabled` if `DemurrageRate` is 0
+		/// - `NoDemurrageDue` if nothing would be removed, e.g. when demurrage was already
+		///   applied to the user in this block
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::apply_demurrage())]
+		pub fn apply_demurrage(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let rate = T::DemurrageRate::get() as u128;
+			ensure!(!rate.is_zero(), Error::<T>::DemurrageDisabled);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let period: u128 = T::DemurragePeriod::get().saturated_into::<u128>().max(1);
+			let max_per_call = T::MaxDemurragePerCall::get() as u128;
+
+			let amount_reduced = UserPoints::<T>::mutate(&user, |batches| {
+				Self::remove_expired_batches_internal(&user, batches, current_block);
+
+				let mut amount_reduced: u128 = 0;
+				for batch in batches.iter_mut() {
+					let elapsed: u128 =
+						current_block.saturating_sub(batch.last_demurrage_block).saturated_into();
+					// Rounds down so holders are never charged more than the configured rate
+					let due = batch
+						.remaining_points
+						.saturating_mul(rate)
+						.saturating_mul(elapsed)
+						.saturating_div(period.saturating_mul(10_000));
+					let cap = batch.remaining_points.saturating_mul(max_per_call) / 10_000;
+					let reduction = due.min(cap);
+					if reduction.is_zero() {
+						continue;
+					}
+
+					batch.remaining_points = batch.remaining_points.saturating_sub(reduction);
+					batch.last_demurrage_block = current_block;
+					amount_reduced = amount_reduced.saturating_add(reduction);
+				}
+				batches.retain(|b| !b.remaining_points.is_zero());
+
+				amount_reduced
+			});
+			ensure!(amount_reduced > 0, Error::<T>::NoDemurrageDue);
+
+			let remaining = TotalPoints::<T>::mutate(&user, |total| {
+				*total = total.saturating_sub(amount_reduced);
+				*total
+			});
+
+			Self::deposit_event(Event::DemurrageApplied { user: user.clone(), amount_reduced });
+			if remaining.is_zero() {
+				Self::deposit_event(Event::BalanceEmptied { user });
+			}
+			Ok(())
+		}
+
+		/// Store a snapshot of a user's available points in `AvailableCache`, so repeated
+		/// reads through `cached_available_points` in this block need not re-sum the batches.
+		/// Awards and spends later in the same block are not reflected in the snapshot.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `user`: The account whose balance should be cached
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::refresh_balance_cache())]
+		pub fn refresh_balance_cache(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			AvailableCache::<T>::insert(&user, (current_block, Self::get_available_points(&user)));
+			Ok(())
+		}
+
+		/// Authorize an account to issue points.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `issuer`: The account to authorize
 		///
 		/// ## Emits
 		/// - `IssuerAuthorized` on success
 		///
 		/// ## Errors
 		/// - `NotAdmin` if caller is not the admin
-		/// - `AlreadyAuthorized` if the issuer is already authorized
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::authorize_issuer())]
-		pub fn authorize_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+		/// - `AlreadyAuthorized` if the issuer is already authorized
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::authorize_issuer())]
+		pub fn authorize_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			ensure!(!AuthorizedIssuers::<T>::get(&issuer), Error::<T>::AlreadyAuthorized);
+
+			AuthorizedIssuers::<T>::insert(&issuer, true);
+			IssuerLastActivePeriod::<T>::insert(&issuer, Self::current_period());
+
+			Self::deposit_event(Event::IssuerAuthorized { issuer });
+			Ok(())
+		}
+
+		/// Revoke an account's authorization to issue points.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `issuer`: The account to revoke
+		///
+		/// ## Emits
+		/// - `IssuerRevoked` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `NotAuthorized` if the issuer wasn't authorized
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::revoke_issuer())]
+		pub fn revoke_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorized);
+
+			AuthorizedIssuers::<T>::remove(&issuer);
+			IssuerLastActivePeriod::<T>::remove(&issuer);
+
+			Self::deposit_event(Event::IssuerRevoked {
+				issuer,
+				reason: IssuerRevocationReason::Admin,
+			});
+			Ok(())
+		}
+
+		/// Give up the caller's own authorization to issue points, without involving the
+		/// admin. Rewards the issuer has already accrued stay claimable.
+		///
+		/// ## Parameters
+		/// - `origin`: The authorized issuer
+		///
+		/// ## Emits
+		/// - `IssuerRevoked` on success
+		///
+		/// ## Errors
+		/// - `NotAuthorized` if the caller isn't an authorized issuer
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::renounce_issuer())]
+		pub fn renounce_issuer(origin: OriginFor<T>) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorized);
+
+			AuthorizedIssuers::<T>::remove(&issuer);
+			IssuerLastActivePeriod::<T>::remove(&issuer);
+
+			Self::deposit_event(Event::IssuerRevoked {
+				issuer,
+				reason: IssuerRevocationReason::Renounced,
+			});
+			Ok(())
+		}
+
+		/// Revoke the authorization of an issuer that has neither awarded points nor been
+		/// spent at for `IssuerInactivityPeriod` reward periods, keeping the authorized set
+		/// free of dormant issuers. Rewards the issuer has already accrued stay claimable.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `issuer`: The inactive issuer to revoke
+		///
+		/// ## Emits
+		/// - `IssuerRevoked` with reason `Inactive` on success
+		///
+		/// ## Errors
+		/// - `NotAuthorized` if the issuer isn't authorized
+		/// - `IssuerStillActive` if the issuer was active within `IssuerInactivityPeriod`
+		///   periods, or pruning is disabled
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::prune_inactive_issuer())]
+		pub fn prune_inactive_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorized);
+			let inactivity_period = T::IssuerInactivityPeriod::get();
+			let inactive_since = IssuerLastActivePeriod::<T>::get(&issuer);
+			ensure!(
+				!inactivity_period.is_zero()
+					&& Self::current_period() >= inactive_since.saturating_add(inactivity_period),
+				Error::<T>::IssuerStillActive
+			);
+
+			AuthorizedIssuers::<T>::remove(&issuer);
+			IssuerLastActivePeriod::<T>::remove(&issuer);
+
+			Self::deposit_event(Event::IssuerRevoked {
+				issuer,
+				reason: IssuerRevocationReason::Inactive,
+			});
+			Ok(())
+		}
+
+		/// Suppress, or re-enable, the routine events of a high-throughput issuer.
+		/// While suppressed, awards and spends attributed to the issuer still update
+		/// storage but skip `PointsEarned`, `SpendBreakdown` and `PointsSpent`; the
+		/// issuer is expected to track them off-chain.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `issuer`: The issuer to update
+		/// - `suppress`: Whether to suppress the issuer's routine events
+		///
+		/// ## Emits
+		/// - `IssuerEventsSuppressionSet` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_suppress_events())]
+		pub fn set_suppress_events(
+			origin: OriginFor<T>,
+			issuer: T::AccountId,
+			suppress: bool,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			if suppress {
+				SuppressEvents::<T>::insert(&issuer, true);
+			} else {
+				SuppressEvents::<T>::remove(&issuer);
+			}
+
+			Self::deposit_event(Event::IssuerEventsSuppressionSet { issuer, suppressed: suppress });
+			Ok(())
+		}
+
+		/// Leave an issuer's spends out of, or count them again towards, issuer reward
+		/// accounting. Spends at an untracked issuer still take the points and update the
+		/// per-user spend, but not `IssuerDailyRecords` or `PeriodTotalSpent`. Issuers route
+		/// redemption categories that should not earn rewards, such as internal transfers or
+		/// promotional burns, through a separate untracked issuer account.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `issuer`: The issuer to update
+		/// - `tracked`: Whether the issuer's spends count towards issuer rewards
+		///
+		/// ## Emits
+		/// - `IssuerSpendTrackingSet` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		#[pallet::call_index(69)]
+		#[pallet::weight(T::WeightInfo::set_spend_tracking())]
+		pub fn set_spend_tracking(
+			origin: OriginFor<T>,
+			issuer: T::AccountId,
+			tracked: bool,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			if tracked {
+				UntrackedSpends::<T>::remove(&issuer);
+			} else {
+				UntrackedSpends::<T>::insert(&issuer, true);
+			}
+
+			Self::deposit_event(Event::IssuerSpendTrackingSet { issuer, tracked });
+			Ok(())
+		}
+
+		/// Set the redemption bonus applied to type-scoped spends of a travel type.
+		/// See `spend_points_of_type` for how the bonus is applied.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `travel_type`: The travel type to update
+		/// - `bonus`: The bonus in basis points (0 disables it)
+		///
+		/// ## Emits
+		/// - `RedemptionBonusSet` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `InvalidRedemptionBonus` if `bonus` is 10000 or more
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::set_redemption_bonus())]
+		pub fn set_redemption_bonus(
+			origin: OriginFor<T>,
+			travel_type: TravelType,
+			bonus: u32,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+			ensure!(bonus < 10_000, Error::<T>::InvalidRedemptionBonus);
+
+			if bonus.is_zero() {
+				TravelTypeRedemptionBonus::<T>::remove(&travel_type);
+			} else {
+				TravelTypeRedemptionBonus::<T>::insert(&travel_type, bonus);
+			}
+
+			Self::deposit_event(Event::RedemptionBonusSet { travel_type, bonus });
+			Ok(())
+		}
+
+		/// Configure several settings of a travel type in one call, e.g. when launching a
+		/// promotion. Only the fields set in `config` are changed.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `travel_type`: The travel type to configure
+		/// - `config`: Award multiplier, default expiry and redemption bonus to apply
+		///
+		/// ## Emits
+		/// - `TravelTypeConfigured` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `InvalidMultiplier` if the multiplier is zero
+		/// - `InvalidRedemptionBonus` if the redemption bonus is 10000 or more
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::configure_travel_type())]
+		pub fn configure_travel_type(
+			origin: OriginFor<T>,
+			travel_type: TravelType,
+			config: TravelTypeConfig<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+			ensure!(config.multiplier != Some(0), Error::<T>::InvalidMultiplier);
+			ensure!(
+				config.redemption_bonus.is_none_or(|bonus| bonus < 10_000),
+				Error::<T>::InvalidRedemptionBonus
+			);
+
+			// Default values are removed rather than stored
+			if let Some(multiplier) = config.multiplier {
+				if multiplier == 10_000 {
+					TravelTypeMultiplier::<T>::remove(&travel_type);
+				} else {
+					TravelTypeMultiplier::<T>::insert(&travel_type, multiplier);
+				}
+			}
+			if let Some(expiry) = config.default_expiry {
+				if expiry.is_zero() {
+					TravelTypeExpiry::<T>::remove(&travel_type);
+				} else {
+					TravelTypeExpiry::<T>::insert(&travel_type, expiry);
+				}
+			}
+			if let Some(bonus) = config.redemption_bonus {
+				if bonus.is_zero() {
+					TravelTypeRedemptionBonus::<T>::remove(&travel_type);
+				} else {
+					TravelTypeRedemptionBonus::<T>::insert(&travel_type, bonus);
+				}
+			}
+
+			Self::deposit_event(Event::TravelTypeConfigured { travel_type });
+			Ok(())
+		}
+
+		/// Reset the points a user has spent at an issuer in a period, e.g. after a refunded
+		/// redemption. Frees the issuer's slot among the user's `MaxIssuersPerUserPerPeriod`,
+		/// takes the amount off the user's `UserPeriodSpend` and leaves nothing for a
+		/// `retroactive_bonus` to credit.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `user`: The user whose spend record to clear
+		/// - `issuer`: The issuer the spend was recorded against
+		/// - `period`: The period number to reset
+		///
+		/// ## Emits
+		/// - `UserIssuerSpendReset` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::reset_user_issuer_spend())]
+		pub fn reset_user_issuer_spend(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			issuer: T::AccountId,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			let previous_amount = UserIssuerSpend::<T>::take((period, &user, &issuer));
+			// The issuer no longer counts towards the user's distinct issuers, nor its points
+			// towards the user's total for the period
+			if !previous_amount.is_zero() {
+				UserPeriodIssuerCount::<T>::mutate(period, &user, |count| {
+					*count = count.saturating_sub(1);
+				});
+				// Leaves periods that are not tracked, or were already pruned, alone
+				UserPeriodSpend::<T>::mutate_exists(period, &user, |spent| {
+					if let Some(spent) = spent {
+						*spent = spent.saturating_sub(previous_amount);
+					}
+				});
+			}
+
+			Self::deposit_event(Event::UserIssuerSpendReset {
+				user,
+				issuer,
+				period,
+				previous_amount,
+			});
+			Ok(())
+		}
+
+		/// Remove the `UserPeriodSpend` totals of a past period, which no spend can add to
+		/// any more. Can be called by anyone.
+		///
+		/// At most `MaxPeriodEntriesPruned` totals are removed per call; call again to
+		/// continue with the rest.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `period`: The period to prune
+		///
+		/// ## Emits
+		/// - `UserPeriodSpendPruned` on success
+		///
+		/// ## Errors
+		/// - `PeriodNotEnded` if `period` is the current period or later
+		#[pallet::call_index(70)]
+		#[pallet::weight(T::WeightInfo::prune_user_period_spend(T::MaxPeriodEntriesPruned::get()))]
+		pub fn prune_user_period_spend(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(period < Self::current_period(), Error::<T>::PeriodNotEnded);
+
+			let removed = UserPeriodSpend::<T>::drain_prefix(period)
+				.take(T::MaxPeriodEntriesPruned::get() as usize)
+				.count() as u32;
+
+			Self::deposit_event(Event::UserPeriodSpendPruned { period, removed });
+
+			Ok(Some(T::WeightInfo::prune_user_period_spend(removed)).into())
+		}
+
+		/// Remove the `IssuerPeriodTicketCount` counts of a past period, which no mint can add
+		/// to any more. Can be called by anyone.
+		///
+		/// At most `MaxPeriodEntriesPruned` counts are removed per call; call again to
+		/// continue with the rest.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `period`: The period to prune
+		///
+		/// ## Emits
+		/// - `IssuerTicketCountsPruned` on success
+		///
+		/// ## Errors
+		/// - `PeriodNotEnded` if `period` is the current period or later
+		#[pallet::call_index(71)]
+		#[pallet::weight(T::WeightInfo::prune_issuer_ticket_counts(
+			T::MaxPeriodEntriesPruned::get()
+		))]
+		pub fn prune_issuer_ticket_counts(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(period < Self::current_period(), Error::<T>::PeriodNotEnded);
+
+			let removed = IssuerPeriodTicketCount::<T>::drain_prefix(period)
+				.take(T::MaxPeriodEntriesPruned::get() as usize)
+				.count() as u32;
+
+			Self::deposit_event(Event::IssuerTicketCountsPruned { period, removed });
+
+			Ok(Some(T::WeightInfo::prune_issuer_ticket_counts(removed)).into())
+		}
+
+		/// Move all unexpired points of a dormant account, including those held in its point
+		/// locks, to `EscheatmentAccount` and clear the account's batches and locks. An account
+		/// is dormant once `DormancyPeriod` blocks have passed since its last award, spend or
+		/// ticket transfer.
+		///
+		/// The points are credited as one `Other` batch that never expires. It is merged into
+		/// the escheatment account's existing escheated batch whenever `batches_mergeable`
+		/// allows, so repeated escheatments do not use up its `MaxPointBatches`.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `user`: The dormant account
+		///
+		/// ## Emits
+		/// - `PointsEscheated` and `AdminActionPerformed` on success
+		/// - `BalanceEmptied` for the dormant account
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `AccountNotDormant` if the account was active within `DormancyPeriod`, has no
+		///   recorded activity, or is the escheatment account itself
+		/// - `NothingToEscheat` if the account holds no unexpired points
+		/// - `TooManyBatches` / `TooManyTravelTypes` if the escheatment account cannot hold
+		///   another batch
+		/// - `BatchPointsOverflow` / `PointBalanceOverflow` if the escheatment account's
+		///   balance would overflow
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::escheat_dormant(T::MaxPointLocks::get()))]
+		pub fn escheat_dormant(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			Self::ensure_admin(&caller)?;
 
-			ensure!(!AuthorizedIssuers::<T>::get(&issuer), Error::<T>::AlreadyAuthorized);
+			ensure!(user != T::EscheatmentAccount::get(), Error::<T>::AccountNotDormant);
 
-			AuthorizedIssuers::<T>::insert(&issuer, true);
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let last_active = LastActivity::<T>::get(&user).ok_or(Error::<T>::AccountNotDormant)?;
+			ensure!(
+				current_block >= last_active.saturating_add(T::DormancyPeriod::get()),
+				Error::<T>::AccountNotDormant
+			);
 
-			Self::deposit_event(Event::IssuerAuthorized { issuer });
+			// Expired points are simply dropped, only live points are escheated
+			let mut batches = UserPoints::<T>::take(&user);
+			Self::remove_expired_batches_internal(&user, &mut batches, current_block);
+			let mut amount: u128 = batches.iter().map(|b| b.remaining_points).sum();
+
+			// Locked points would return to the account once their lock times out
+			let mut locks: u32 = 0;
+			for (_, lock) in LockedPoints::<T>::drain_prefix(&user) {
+				locks = locks.saturating_add(1);
+				let live: u128 = lock
+					.batches
+					.iter()
+					.filter_map(|piece| Self::batch_after_expiry(piece, current_block))
+					.map(|piece| piece.remaining_points)
+					.sum();
+				amount = amount.saturating_add(live);
+			}
+			PointLockCount::<T>::remove(&user);
+			ensure!(amount > 0, Error::<T>::NothingToEscheat);
+
+			let escheatment_account = T::EscheatmentAccount::get();
+			let escheated = PointBatch {
+				earned_at_block: current_block,
+				expires_at_block: Bounded::max_value(),
+				remaining_points: amount,
+				travel_type: TravelType::Other,
+				last_demurrage_block: current_block,
+				expiring_per_step: 0,
+			};
+			UserPoints::<T>::try_mutate(&escheatment_account, |batches| -> DispatchResult {
+				let existing = batches.iter_mut().find(|b| Self::batches_mergeable(b, &escheated));
+				if let Some(existing) = existing {
+					ensure!(
+						existing.remaining_points.checked_add(amount).is_some(),
+						Error::<T>::BatchPointsOverflow
+					);
+					Self::merge_batch(existing, &escheated);
+				} else {
+					Self::ensure_travel_type_allowed(batches, &escheated.travel_type)?;
+					batches.try_push(escheated).map_err(|_| Error::<T>::TooManyBatches)?;
+				}
+				Ok(())
+			})?;
+			TotalPoints::<T>::try_mutate(&escheatment_account, |total| -> DispatchResult {
+				*total = total.checked_add(amount).ok_or(Error::<T>::PointBalanceOverflow)?;
+				Ok(())
+			})?;
+
+			TotalPoints::<T>::remove(&user);
+			LastActivity::<T>::remove(&user);
+			AvailableCache::<T>::remove(&user);
+
+			Self::deposit_event(Event::PointsEscheated { user: user.clone(), amount });
+			Self::deposit_event(Event::BalanceEmptied { user: user.clone() });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::EscheatDormant,
+				target: Some(user),
+			});
+			Ok(Some(T::WeightInfo::escheat_dormant(locks)).into())
+		}
+
+		/// Move all unexpired point batches of `from` to `to`, e.g. after a user switched
+		/// wallets. Expiries and travel types are preserved; a batch that `to` could compact
+		/// with one it already holds (see `compact_point_batches`) is merged into it, keeping
+		/// the later earning block so the merge never shortens `MinHoldingPeriod`. The call
+		/// fails without changes if `to` would exceed `MaxPointBatches` or
+		/// `MaxTravelTypesPerUser`.
+		///
+		/// `from` must not hold any point locks, as locked points are already taken out of its
+		/// batches; they have to be committed or released (timed-out ones by
+		/// `cleanup_expired`) first. `from`'s spend breakdown is cleared with its balance.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `from`: The account whose points are moved
+		/// - `to`: The account receiving the points
+		///
+		/// ## Emits
+		/// - `AccountPointsMigrated` and `AdminActionPerformed` on success
+		/// - `BalanceEmptied` for `from`
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `MigrationToSameAccount` if `from` and `to` are the same account
+		/// - `PointLocksOpen` if `from` still has open point locks
+		/// - `NothingToMigrate` if `from` holds no unexpired points
+		/// - `TooManyBatches` / `TooManyTravelTypes` if `to` cannot hold the batches
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::migrate_account_points())]
+		pub fn migrate_account_points(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			ensure!(from != to, Error::<T>::MigrationToSameAccount);
+			ensure!(PointLockCount::<T>::get(&from) == 0, Error::<T>::PointLocksOpen);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			let mut moved = UserPoints::<T>::take(&from);
+			Self::remove_expired_batches_internal(&from, &mut moved, current_block);
+			let amount: u128 = moved.iter().map(|b| b.remaining_points).sum();
+			ensure!(amount > 0, Error::<T>::NothingToMigrate);
+
+			UserPoints::<T>::try_mutate(&to, |batches| -> DispatchResult {
+				Self::remove_expired_batches_internal(&to, batches, current_block);
+
+				for batch in moved.into_iter() {
+					let existing = batches.iter_mut().find(|b| Self::batches_mergeable(b, &batch));
+					if let Some(existing) = existing {
+						ensure!(
+							existing.remaining_points.checked_add(batch.remaining_points).is_some(),
+							Error::<T>::BatchPointsOverflow
+						);
+						Self::merge_batch(existing, &batch);
+					} else {
+						Self::ensure_travel_type_allowed(batches, &batch.travel_type)?;
+						batches.try_push(batch).map_err(|_| Error::<T>::TooManyBatches)?;
+					}
+				}
+
+				// Keep batches sorted by expiration date for FIFO deduction
+				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+				Ok(())
+			})?;
+
+			TotalPoints::<T>::remove(&from);
+			TotalPoints::<T>::try_mutate(&to, |total| -> DispatchResult {
+				*total = total.checked_add(amount).ok_or(Error::<T>::PointBalanceOverflow)?;
+				Ok(())
+			})?;
+
+			LastActivity::<T>::remove(&from);
+			LastActivity::<T>::insert(&to, current_block);
+			AvailableCache::<T>::remove(&from);
+			LastSpendBreakdown::<T>::remove(&from);
+
+			Self::deposit_event(Event::AccountPointsMigrated { from: from.clone(), to, amount });
+			Self::deposit_event(Event::BalanceEmptied { user: from.clone() });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::MigrateAccountPoints,
+				target: Some(from),
+			});
 			Ok(())
 		}
 
-		/// Revoke an account's authorization to issue points.
+		/// Correct the travel type of one of a user's point batches, e.g. points recorded as
+		/// `Bus` that should have been `Train`.
+		///
+		/// Only the type changes; amount, expiry and FIFO position are kept. The batch then
+		/// counts towards the new type for `spend_points_of_type` (including its
+		/// `TravelTypeRedemptionBonus`), `points_by_travel_type` and the
+		/// `MaxTravelTypesPerUser` limit, and may later be compacted with batches of the new
+		/// type that share its expiry.
+		///
+		/// Batches do not record the issuer that awarded them, so issuers cannot be limited to
+		/// their own batches and only the admin may reclassify.
 		///
 		/// ## Parameters
 		/// - `origin`: Must be the admin
-		/// - `issuer`: The account to revoke
+		/// - `user`: The account holding the batch
+		/// - `batch_index`: Index of the batch in the user's `UserPoints`
+		/// - `new_type`: The corrected travel type
 		///
 		/// ## Emits
-		/// - `IssuerRevoked` on success
+		/// - `BatchReclassified` and `AdminActionPerformed` on success
 		///
 		/// ## Errors
 		/// - `NotAdmin` if caller is not the admin
-		/// - `NotAuthorized` if the issuer wasn't authorized
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::revoke_issuer())]
-		pub fn revoke_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+		/// - `InvalidBatchIndex` if the user has no batch at `batch_index`
+		/// - `TooManyTravelTypes` if the change exceeds `MaxTravelTypesPerUser`
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::reclassify_batch())]
+		pub fn reclassify_batch(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			batch_index: u32,
+			new_type: TravelType,
+		) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 			Self::ensure_admin(&caller)?;
 
-			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorized);
+			let old_type = UserPoints::<T>::try_mutate(
+				&user,
+				|batches| -> Result<TravelType, DispatchError> {
+					let index = batch_index as usize;
+					ensure!(index < batches.len(), Error::<T>::InvalidBatchIndex);
+
+					let others: Vec<_> = batches
+						.iter()
+						.enumerate()
+						.filter(|(i, _)| *i != index)
+						.map(|(_, b)| b.clone())
+						.collect();
+					Self::ensure_travel_type_allowed(&others, &new_type)?;
+
+					Ok(core::mem::replace(&mut batches[index].travel_type, new_type.clone()))
+				},
+			)?;
+
+			Self::deposit_event(Event::BatchReclassified {
+				user: user.clone(),
+				batch_index,
+				old_type,
+				new_type,
+			});
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::ReclassifyBatch,
+				target: Some(user),
+			});
+			Ok(())
+		}
 
-			AuthorizedIssuers::<T>::remove(&issuer);
+		/// Push back the expiry of all of a user's point batches at once, e.g. for a goodwill
+		/// campaign. Every unexpired batch, and every batch that expired no more than
+		/// `ReinstatementWindow` blocks ago, gets `additional_blocks` added to its expiry.
+		/// Reinstated batches whose new expiry lies ahead become spendable again. Batches are
+		/// re-sorted by expiry afterwards, so FIFO order is preserved.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin or an authorized issuer
+		/// - `user`: The account holding the batches
+		/// - `additional_blocks`: Number of blocks to add to each batch's expiry
+		///
+		/// ## Emits
+		/// - `AllBatchesExtended` on success
+		///
+		/// ## Errors
+		/// - `NotAuthorizedIssuer` if caller is neither the admin nor an authorized issuer
+		/// - `InvalidExpiration` if `additional_blocks` is zero
+		/// - `NothingToExtend` if no batch is unexpired or within the reinstatement window
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::extend_all_batches())]
+		pub fn extend_all_batches(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			additional_blocks: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(
+				Self::is_admin(&caller) || AuthorizedIssuers::<T>::get(&caller),
+				Error::<T>::NotAuthorizedIssuer
+			);
+			ensure!(!additional_blocks.is_zero(), Error::<T>::InvalidExpiration);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reinstatable_after = current_block.saturating_sub(T::ReinstatementWindow::get());
+
+			let batch_count =
+				UserPoints::<T>::try_mutate(&user, |batches| -> Result<u32, DispatchError> {
+					let mut batch_count = 0u32;
+					for batch in batches.iter_mut() {
+						if batch.expires_at_block >= reinstatable_after {
+							batch.expires_at_block =
+								batch.expires_at_block.saturating_add(additional_blocks);
+							batch_count = batch_count.saturating_add(1);
+						}
+					}
+					ensure!(batch_count > 0, Error::<T>::NothingToExtend);
+					batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+					Ok(batch_count)
+				})?;
 
-			Self::deposit_event(Event::IssuerRevoked { issuer });
+			Self::deposit_event(Event::AllBatchesExtended { user, batch_count });
 			Ok(())
 		}
 
@@ -1322,7 +3952,7 @@ pub mod pallet {
 		/// - `new_admin`: The new admin account
 		///
 		/// ## Emits
-		/// - `AdminChanged` on success
+		/// - `AdminChanged` and `AdminActionPerformed` on success
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::set_admin())]
 		pub fn set_admin(origin: OriginFor<T>, new_admin: T::AccountId) -> DispatchResult {
@@ -1338,7 +3968,12 @@ pub mod pallet {
 			let old_admin = Admin::<T>::get();
 			Admin::<T>::put(&new_admin);
 
-			Self::deposit_event(Event::AdminChanged { old_admin, new_admin });
+			Self::deposit_event(Event::AdminChanged { old_admin, new_admin: new_admin.clone() });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: caller,
+				action: AdminAction::SetAdmin,
+				target: Some(new_admin),
+			});
 			Ok(())
 		}
 
@@ -1362,6 +3997,17 @@ pub mod pallet {
 		/// - `arrival`: Arrival location
 		/// - `departure_time`: Departure time
 		/// - `metadata`: Additional metadata
+		/// - `departure_code`: Optional short departure code (e.g. "JFK")
+		/// - `arrival_code`: Optional short arrival code (e.g. "LAX")
+		/// - `segments`: Number of legs covered by the ticket (at least 1)
+		/// - `capacity`: Number of travelers covered by the ticket (at least 1)
+		///
+		/// Tickets minted with both codes are added to the `TicketsByRoute` index. An issuer
+		/// can mint at most `MaxTicketsPerIssuerPerPeriod` tickets per reward period
+		/// (`IssuerTicketCapExceeded`).
+		///
+		/// `departure_code`, `arrival_code`, `segments` and `capacity` were appended to this
+		/// call's arguments, which breaks clients encoding the earlier argument list.
 		#[pallet::call_index(6)]
 		#[pallet::weight(T::WeightInfo::mint_ticket())]
 		pub fn mint_ticket(
@@ -1378,67 +4024,114 @@ pub mod pallet {
 			arrival: Vec<u8>,
 			departure_time: Vec<u8>,
 			metadata: Vec<u8>,
+			departure_code: Option<Vec<u8>>,
+			arrival_code: Option<Vec<u8>>,
+			segments: u8,
+			capacity: u32,
 		) -> DispatchResult {
 			let issuer = ensure_signed(origin)?;
 			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
 
-			let current_block = frame_system::Pallet::<T>::block_number();
-
-			// If points_cost > 0, deduct from owner using internal spend
-			if points_cost > 0 {
-				Self::spend_points_internal(&owner, points_cost, &issuer)?;
-			}
-
-			// Get and increment ticket ID
-			let ticket_id = NextTicketId::<T>::get();
-			NextTicketId::<T>::put(ticket_id.saturating_add(1));
-
-			// Create the ticket
-			let ticket = Ticket {
-				id: ticket_id,
-				owner: owner.clone(),
-				issuer: issuer.clone(),
-				ticket_type: ticket_type.clone(),
-				created_at: current_block,
-				expires_at,
+			Self::do_mint_ticket(
+				issuer,
+				owner.clone(),
+				owner,
+				ticket_type,
 				points_cost,
-				is_redeemed: false,
-				passenger_name: BoundedVec::try_from(passenger_name)
-					.map_err(|_| Error::<T>::StringTooLong)?,
-				travel_number: BoundedVec::try_from(travel_number)
-					.map_err(|_| Error::<T>::StringTooLong)?,
-				gate: BoundedVec::try_from(gate).map_err(|_| Error::<T>::StringTooLong)?,
-				seat: BoundedVec::try_from(seat).map_err(|_| Error::<T>::StringTooLong)?,
-				departure: BoundedVec::try_from(departure)
-					.map_err(|_| Error::<T>::StringTooLong)?,
-				arrival: BoundedVec::try_from(arrival).map_err(|_| Error::<T>::StringTooLong)?,
-				departure_time: BoundedVec::try_from(departure_time)
-					.map_err(|_| Error::<T>::StringTooLong)?,
-				metadata: BoundedVec::try_from(metadata).map_err(|_| Error::<T>::StringTooLong)?,
-			};
+				expires_at,
+				passenger_name,
+				travel_number,
+				gate,
+				seat,
+				departure,
+				arrival,
+				departure_time,
+				metadata,
+				departure_code,
+				arrival_code,
+				segments,
+				capacity,
+			)?;
 
-			// Store the ticket
-			Tickets::<T>::insert(ticket_id, ticket);
+			Ok(())
+		}
 
-			// Add to user's ticket list
-			UserTickets::<T>::try_mutate(&owner, |tickets| -> DispatchResult {
-				tickets.try_push(ticket_id).map_err(|_| Error::<T>::TooManyTickets)?;
-				Ok(())
-			})?;
+		/// Mint a ticket to `recipient`, paid for with `payer`'s points. Only callable by
+		/// authorized issuers.
+		///
+		/// Behaves like `mint_ticket`, except `points_cost` is deducted from `payer` while the
+		/// ticket is owned by `recipient`. Emits `TicketGifted` alongside `TicketMinted`.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be an authorized issuer
+		/// - `payer`: The account whose points pay for the ticket
+		/// - `recipient`: The account that will own the ticket
+		/// - remaining parameters as for `mint_ticket`
+		///
+		/// ## Errors
+		/// - `RecipientRejectsGifts` if `recipient` opted out with `set_accepts_gifts`
+		/// - otherwise as for `mint_ticket`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::gift_ticket())]
+		pub fn gift_ticket(
+			origin: OriginFor<T>,
+			payer: T::AccountId,
+			recipient: T::AccountId,
+			ticket_type: TicketType,
+			points_cost: u128,
+			expires_at: Option<BlockNumberFor<T>>,
+			passenger_name: Vec<u8>,
+			travel_number: Vec<u8>,
+			gate: Vec<u8>,
+			seat: Vec<u8>,
+			departure: Vec<u8>,
+			arrival: Vec<u8>,
+			departure_time: Vec<u8>,
+			metadata: Vec<u8>,
+			departure_code: Option<Vec<u8>>,
+			arrival_code: Option<Vec<u8>>,
+			segments: u8,
+			capacity: u32,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
+			ensure!(AcceptsGifts::<T>::get(&recipient), Error::<T>::RecipientRejectsGifts);
 
-			Self::deposit_event(Event::TicketMinted {
-				ticket_id,
-				owner,
+			let ticket_id = Self::do_mint_ticket(
 				issuer,
+				payer.clone(),
+				recipient.clone(),
 				ticket_type,
 				points_cost,
-			});
+				expires_at,
+				passenger_name,
+				travel_number,
+				gate,
+				seat,
+				departure,
+				arrival,
+				departure_time,
+				metadata,
+				departure_code,
+				arrival_code,
+				segments,
+				capacity,
+			)?;
+
+			Self::deposit_event(Event::TicketGifted { ticket_id, payer, recipient });
 
 			Ok(())
 		}
 
 		/// Redeem/use a ticket. Only the owner can redeem their ticket.
 		///
+		/// Multi-segment tickets are redeemed one segment per call, each emitting
+		/// `TicketSegmentRedeemed`. The ticket is only marked redeemed (and `TicketRedeemed`
+		/// emitted) once its last segment is used. Single-segment tickets are redeemed at once.
+		///
+		/// Group tickets use one seat per call (`TicketSeatUsed`). A segment is only redeemed
+		/// once all `capacity` seats on it are used; the next segment starts with no seats used.
+		///
 		/// ## Parameters
 		/// - `origin`: Must be the ticket owner
 		/// - `ticket_id`: ID of the ticket to redeem
@@ -1447,18 +4140,55 @@ pub mod pallet {
 		pub fn redeem_ticket(origin: OriginFor<T>, ticket_id: u128) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 
-			Tickets::<T>::try_mutate(ticket_id, |maybe_ticket| -> DispatchResult {
-				let ticket = maybe_ticket.as_mut().ok_or(Error::<T>::TicketNotFound)?;
-				ensure!(ticket.owner == owner, Error::<T>::NotTicketOwner);
-				ensure!(!ticket.is_redeemed, Error::<T>::TicketAlreadyRedeemed);
+			let (ticket, seat, segment_done) =
+				Tickets::<T>::try_mutate(ticket_id, |maybe_ticket| -> Result<_, DispatchError> {
+					let ticket = maybe_ticket.as_mut().ok_or(Error::<T>::TicketNotFound)?;
+					ensure!(ticket.owner == owner, Error::<T>::NotTicketOwner);
+					ensure!(!ticket.is_redeemed, Error::<T>::TicketAlreadyRedeemed);
 
-				// Check if ticket has expired
-				if let Some(expires_at) = ticket.expires_at {
-					let current_block = frame_system::Pallet::<T>::block_number();
-					ensure!(current_block < expires_at, Error::<T>::TicketExpired);
-				}
+					// Check if ticket has expired
+					if let Some(expires_at) = ticket.expires_at {
+						let current_block = frame_system::Pallet::<T>::block_number();
+						ensure!(current_block < expires_at, Error::<T>::TicketExpired);
+					}
+
+					ticket.used = ticket.used.saturating_add(1);
+					let seat = ticket.used;
+					let segment_done = ticket.used >= ticket.capacity;
+					if segment_done {
+						ticket.segments_redeemed = ticket.segments_redeemed.saturating_add(1);
+						ticket.is_redeemed = ticket.segments_redeemed >= ticket.segments;
+						// Every traveler takes a seat again on the next segment
+						if !ticket.is_redeemed {
+							ticket.used = 0;
+						}
+					}
+					Ok((ticket.clone(), seat, segment_done))
+				})?;
+
+			if ticket.capacity > 1 {
+				Self::deposit_event(Event::TicketSeatUsed {
+					ticket_id,
+					used: seat,
+					capacity: ticket.capacity,
+				});
+			}
+
+			if segment_done && ticket.segments > 1 {
+				Self::deposit_event(Event::TicketSegmentRedeemed {
+					ticket_id,
+					segment: ticket.segments_redeemed,
+				});
+			}
 
-				ticket.is_redeemed = true;
+			// The ticket is only redeemed once its last segment has been used
+			if !ticket.is_redeemed {
+				return Ok(());
+			}
+
+			// Hold the re-entrancy lock so the hook cannot award or spend points
+			Self::with_reentrancy_guard(|| {
+				T::OnTicketRedeemed::on_redeemed(ticket_id, &owner, &ticket.ticket_type);
 				Ok(())
 			})?;
 
@@ -1469,12 +4199,17 @@ pub mod pallet {
 
 		/// Transfer a ticket to another account.
 		///
+		/// Burns `TicketTransferFee` points from the sender (`TicketTransferFeeBurned`).
+		///
 		/// ## Parameters
 		/// - `origin`: Must be the ticket owner
 		/// - `ticket_id`: ID of the ticket to transfer
-		/// - `to`: The new owner
+		/// - `to`: The new owner, which must accept gifts (see `set_accepts_gifts`)
+		///
+		/// ## Errors
+		/// - `RecipientRejectsGifts` if `to` opted out of receiving tickets
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::spend_points())]
+		#[pallet::weight(T::WeightInfo::transfer_ticket())]
 		pub fn transfer_ticket(
 			origin: OriginFor<T>,
 			ticket_id: u128,
@@ -1482,27 +4217,78 @@ pub mod pallet {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 
-			Tickets::<T>::try_mutate(ticket_id, |maybe_ticket| -> DispatchResult {
-				let ticket = maybe_ticket.as_mut().ok_or(Error::<T>::TicketNotFound)?;
-				ensure!(ticket.owner == from, Error::<T>::NotTicketOwner);
-				ensure!(!ticket.is_redeemed, Error::<T>::TicketAlreadyRedeemed);
+			Self::do_transfer_ticket(ticket_id, &from, &to)?;
+			Self::burn_ticket_transfer_fee(&from, 1)
+		}
 
-				ticket.owner = to.clone();
-				Ok(())
-			})?;
+		/// Transfer several tickets to another account in a single call.
+		///
+		/// The batch is all-or-nothing: if any ticket is missing, not owned by the caller,
+		/// already redeemed, or the recipient would exceed `MaxTicketsPerUser`, the whole
+		/// call is rolled back and no ticket changes hands. `TicketTransferFee` is burned
+		/// from the sender once per ticket.
+		///
+		/// ## Parameters
+		/// - `origin`: Must own every ticket in `ticket_ids`
+		/// - `ticket_ids`: IDs of the tickets to transfer (at most `MaxTicketsPerBatchTransfer`)
+		/// - `to`: The new owner
+		///
+		/// ## Emits
+		/// - `TicketTransferred` once per ticket
+		///
+		/// ## Errors
+		/// - `EmptyTicketBatch` if `ticket_ids` is empty
+		/// - `TooManyTicketsInBatch` if more than `MaxTicketsPerBatchTransfer` IDs are given
+		/// - `TicketNotFound` / `NotTicketOwner` / `TicketAlreadyRedeemed` for an invalid ticket
+		/// - `TooManyTickets` if the recipient would exceed `MaxTicketsPerUser`
+		/// - `RecipientRejectsGifts` if `to` opted out of receiving tickets
+		/// - `InsufficientPoints` / `WouldLeaveDust` if the sender cannot pay the transfer fee
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::transfer_tickets_batch(ticket_ids.len() as u32))]
+		pub fn transfer_tickets_batch(
+			origin: OriginFor<T>,
+			ticket_ids: Vec<u128>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(!ticket_ids.is_empty(), Error::<T>::EmptyTicketBatch);
+			ensure!(
+				ticket_ids.len() as u32 <= T::MaxTicketsPerBatchTransfer::get(),
+				Error::<T>::TooManyTicketsInBatch
+			);
 
-			// Update user ticket lists
-			UserTickets::<T>::mutate(&from, |tickets| {
-				tickets.retain(|&id| id != ticket_id);
-			});
+			let ticket_count = ticket_ids.len() as u32;
+			for ticket_id in ticket_ids {
+				Self::do_transfer_ticket(ticket_id, &from, &to)?;
+			}
 
-			UserTickets::<T>::try_mutate(&to, |tickets| -> DispatchResult {
-				tickets.try_push(ticket_id).map_err(|_| Error::<T>::TooManyTickets)?;
-				Ok(())
-			})?;
+			Self::burn_ticket_transfer_fee(&from, ticket_count)
+		}
 
-			Self::deposit_event(Event::TicketTransferred { ticket_id, from, to });
+		/// Opt out of, or back into, receiving tickets from other accounts. While opted out,
+		/// `gift_ticket`, `transfer_ticket` and `transfer_tickets_batch` to the caller fail.
+		/// Issuers minting a ticket to the caller with `mint_ticket`, and issuers awarding
+		/// points, are not affected: those stem from the caller's own business with them.
+		///
+		/// ## Parameters
+		/// - `origin`: The account updating its preference
+		/// - `accepts`: Whether to accept gifted and transferred tickets
+		///
+		/// ## Emits
+		/// - `GiftAcceptanceSet` on success
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::set_accepts_gifts())]
+		pub fn set_accepts_gifts(origin: OriginFor<T>, accepts: bool) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			if accepts {
+				AcceptsGifts::<T>::remove(&account);
+			} else {
+				AcceptsGifts::<T>::insert(&account, false);
+			}
 
+			Self::deposit_event(Event::GiftAcceptanceSet { account, accepts });
 			Ok(())
 		}
 
@@ -1531,6 +4317,8 @@ pub mod pallet {
 
 			// Remove ticket from storage
 			Tickets::<T>::remove(ticket_id);
+			TicketProvenance::<T>::remove(ticket_id);
+			Self::remove_from_route_index(&ticket);
 
 			// Remove from user's ticket list
 			UserTickets::<T>::mutate(&owner, |tickets| {
@@ -1551,7 +4339,7 @@ pub mod pallet {
 		/// - `ticket_id`: ID of the ticket to unmint
 		///
 		/// ## Emits
-		/// - `TicketForceUnminted` on success
+		/// - `TicketForceUnminted` and `AdminActionPerformed` on success
 		///
 		/// ## Errors
 		/// - `NotAdmin` if the caller is not the admin
@@ -1568,13 +4356,24 @@ pub mod pallet {
 
 			// Remove ticket from storage
 			Tickets::<T>::remove(ticket_id);
+			TicketProvenance::<T>::remove(ticket_id);
+			Self::remove_from_route_index(&ticket);
 
 			// Remove from user's ticket list
 			UserTickets::<T>::mutate(&owner, |tickets| {
 				tickets.retain(|&id| id != ticket_id);
 			});
 
-			Self::deposit_event(Event::TicketForceUnminted { ticket_id, owner, admin });
+			Self::deposit_event(Event::TicketForceUnminted {
+				ticket_id,
+				owner: owner.clone(),
+				admin: admin.clone(),
+			});
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(admin),
+				action: AdminAction::ForceUnmintTicket,
+				target: Some(owner),
+			});
 
 			Ok(())
 		}
@@ -1618,6 +4417,8 @@ pub mod pallet {
 						if expires_at <= current_block {
 							// Remove the expired ticket from storage
 							Tickets::<T>::remove(ticket_id);
+							TicketProvenance::<T>::remove(ticket_id);
+							Self::remove_from_route_index(&ticket);
 							tickets_removed = tickets_removed.saturating_add(1);
 						}
 					}
@@ -1648,33 +4449,53 @@ pub mod pallet {
 
 		/// Stake tokens to become a verifier/staker and earn rewards.
 		///
+		/// When the active staker set is full, a stake larger than the smallest active
+		/// stake takes its slot and the displaced staker moves to the waitlist. Otherwise
+		/// the new staker is waitlisted until a slot frees up. Once the waitlist is full as
+		/// well, the smallest stake falls out of the staker set (`StakerEvicted`). Only the
+		/// active set earns staker rewards, and the set is rebalanced whenever a stake
+		/// changes, so a waitlisted staker that outgrows the smallest active stake swaps
+		/// places with it.
+		///
 		/// ## Parameters
 		/// - `origin`: The staker account
 		/// - `amount`: Amount to stake (must be >= MinStakeAmount)
+		///
+		/// ## Errors
+		/// - `TooManyStakers` if both the active set and the waitlist are full of stakes at least as large
+		/// - `TotalStakeOverflow` if `TotalStaked` would overflow
 		#[pallet::call_index(9)]
 		#[pallet::weight(T::WeightInfo::stake())]
 		pub fn stake(origin: OriginFor<T>, amount: u128) -> DispatchResult {
 			let staker = ensure_signed(origin)?;
 
 			ensure!(amount >= T::MinStakeAmount::get(), Error::<T>::StakeBelowMinimum);
-			ensure!(Stakes::<T>::get(&staker).is_none(), Error::<T>::AlreadyStaking);
+			ensure!(EnhancedStakes::<T>::get(&staker).is_none(), Error::<T>::AlreadyStaking);
 
 			let current_block = frame_system::Pallet::<T>::block_number();
 
-			let stake_info = StakeInfo { amount, staked_at: current_block, is_verifier: false };
-
-			Stakes::<T>::insert(&staker, stake_info);
+			let stake_info = EnhancedStakeInfo {
+				active: amount,
+				staked_at: current_block,
+				is_verifier: false,
+				total_slashed: 0,
+			};
 
-			// Add to staker list
-			StakerList::<T>::try_mutate(|stakers| -> DispatchResult {
-				stakers.try_push(staker.clone()).map_err(|_| Error::<T>::TooManyStakers)?;
+			EnhancedStakes::<T>::insert(&staker, stake_info);
+			StakerCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			Self::note_stake_change(&staker);
+			BondedStake::<T>::try_mutate(&staker, |bonded| -> DispatchResult {
+				let new_bonded =
+					bonded.unwrap_or(0).checked_add(amount).ok_or(Error::<T>::StakeOverflow)?;
+				*bonded = Some(new_bonded);
 				Ok(())
 			})?;
 
+			// Add to the active set, displacing the smallest stake or waitlisting if full
+			Self::add_to_staker_set(&staker, amount)?;
+
 			// Update total staked
-			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_add(amount);
-			});
+			Self::increase_total_staked(amount)?;
 
 			Self::deposit_event(Event::Staked { staker, amount });
 
@@ -1683,6 +4504,10 @@ pub mod pallet {
 
 		/// Unstake tokens and withdraw from staking.
 		///
+		/// The active stake is released right away. Stake that is still unbonding keeps
+		/// maturing and can be withdrawn as usual; the stake, together with its recovery
+		/// account, is closed once nothing is left unbonding.
+		///
 		/// ## Parameters
 		/// - `origin`: The staker account
 		#[pallet::call_index(10)]
@@ -1690,16 +4515,18 @@ pub mod pallet {
 		pub fn unstake(origin: OriginFor<T>) -> DispatchResult {
 			let staker = ensure_signed(origin)?;
 
-			let stake_info = Stakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
-			let amount = stake_info.amount;
+			let mut stake_info = EnhancedStakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
+			let amount = stake_info.active;
 
-			// Remove stake
-			Stakes::<T>::remove(&staker);
+			// Release the active stake
+			stake_info.active = 0;
+			EnhancedStakes::<T>::insert(&staker, stake_info);
+			Self::note_stake_change(&staker);
+			Self::release_bonded_stake(&staker, amount);
 
-			// Remove from staker list
-			StakerList::<T>::mutate(|stakers| {
-				stakers.retain(|s| s != &staker);
-			});
+			// Remove from staker list (promoting a waitlisted staker if a slot frees up)
+			Self::remove_from_staker_set(&staker);
+			Self::close_stake_if_empty(&staker);
 
 			// Update total staked
 			TotalStaked::<T>::mutate(|total| {
@@ -1722,10 +4549,39 @@ pub mod pallet {
 			ensure_signed(origin)?;
 			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 
-			RewardPool::<T>::mutate(|pool| {
-				*pool = pool.saturating_add(amount);
-			});
+			Self::increase_reward_pool(amount)?;
+
+			Ok(())
+		}
 
+		/// Set the reward pool balance directly, e.g. to seed it while bootstrapping a chain.
+		/// Can be called by the admin or root.
+		///
+		/// The reward pool is accounting-only, so no funds are moved. Should the pool become
+		/// backed by a currency pot, this must reconcile with the pot's balance instead.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin or root
+		/// - `amount`: New reward pool balance
+		///
+		/// ## Emits
+		/// - `RewardPoolSet` and `AdminActionPerformed` on success
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_reward_pool())]
+		pub fn set_reward_pool(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+			let caller = ensure_signed(origin.clone()).ok();
+			let is_root = ensure_root(origin).is_ok();
+			let is_admin = caller.as_ref().is_some_and(|c| Self::is_admin(c));
+			ensure!(is_root || is_admin, Error::<T>::NotAdmin);
+
+			RewardPool::<T>::put(amount);
+
+			Self::deposit_event(Event::RewardPoolSet { amount });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: caller,
+				action: AdminAction::SetRewardPool,
+				target: None,
+			});
 			Ok(())
 		}
 
@@ -1736,6 +4592,11 @@ pub mod pallet {
 		/// Request unbonding of staked tokens. Initiates the unbonding period.
 		/// Tokens will be locked until the unbonding period ends.
 		///
+		/// Requests below `InstantUnbondThreshold` are returned immediately as long as the
+		/// staker stays within `MaxInstantUnbondPerPeriod` for the current reward period;
+		/// anything else goes through the regular unbonding queue. An instant unbond of the
+		/// last active stake closes the stake if nothing else is unbonding.
+		///
 		/// ## Parameters
 		/// - `origin`: The staker account
 		/// - `amount`: Amount to unbond (must be <= current stake)
@@ -1747,8 +4608,51 @@ pub mod pallet {
 			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 
 			// Check if staker has a stake
-			let stake_info = Stakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
-			ensure!(stake_info.amount >= amount, Error::<T>::InsufficientBalance);
+			let stake_info = EnhancedStakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
+			ensure!(stake_info.active >= amount, Error::<T>::InsufficientBalance);
+
+			// Active stake plus everything already unbonding may never exceed what was bonded,
+			// so an accounting drift cannot be unbonded twice
+			if let Some(bonded) = BondedStake::<T>::get(&staker) {
+				let unbonding = UnbondingRequests::<T>::get(&staker)
+					.iter()
+					.fold(0u128, |total, request| total.saturating_add(request.amount));
+				ensure!(
+					stake_info.active.saturating_add(unbonding) <= bonded,
+					Error::<T>::InsufficientBalance
+				);
+			}
+
+			// Small requests skip the unbonding queue, up to a per-period allowance. The
+			// allowance stops the fast path from being used to pull stake out ahead of a slash.
+			if amount < T::InstantUnbondThreshold::get() {
+				let period = Self::current_period();
+				let already_unbonded = InstantUnbonded::<T>::get(period, &staker);
+				let new_unbonded = already_unbonded.saturating_add(amount);
+
+				if new_unbonded <= T::MaxInstantUnbondPerPeriod::get() {
+					InstantUnbonded::<T>::insert(period, &staker, new_unbonded);
+
+					EnhancedStakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
+						let info = maybe_info.as_mut().ok_or(Error::<T>::NotStaker)?;
+						info.active = info.active.saturating_sub(amount);
+						Ok(())
+					})?;
+					Self::note_stake_change(&staker);
+					Self::rebalance_staker_set(&staker);
+					Self::release_bonded_stake(&staker, amount);
+					// Unbonding everything that was left closes the stake
+					Self::close_stake_if_empty(&staker);
+
+					TotalStaked::<T>::mutate(|total| {
+						*total = total.saturating_sub(amount);
+					});
+
+					Self::deposit_event(Event::InstantUnbonded { staker, amount });
+
+					return Ok(());
+				}
+			}
 
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let unlocks_at = current_block.saturating_add(T::UnbondingPeriod::get());
@@ -1765,11 +4669,13 @@ pub mod pallet {
 			})?;
 
 			// Reduce active stake
-			Stakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
+			EnhancedStakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
 				let info = maybe_info.as_mut().ok_or(Error::<T>::NotStaker)?;
-				info.amount = info.amount.saturating_sub(amount);
+				info.active = info.active.saturating_sub(amount);
 				Ok(())
 			})?;
+			Self::note_stake_change(&staker);
+			Self::rebalance_staker_set(&staker);
 
 			// Update total staked
 			TotalStaked::<T>::mutate(|total| {
@@ -1790,42 +4696,145 @@ pub mod pallet {
 		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
 			let staker = ensure_signed(origin)?;
 
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let mut total_withdrawn: u128 = 0;
+			let total_withdrawn = Self::withdraw_matured_unbonding(&staker)?;
+
+			Self::deposit_event(Event::UnbondingWithdrawn { staker, amount: total_withdrawn });
+
+			Ok(())
+		}
+
+		/// Withdraw stake that is still unbonding right away, rather than at its `unlocks_at`,
+		/// in exchange for a penalty of `EmergencyWithdrawPenaltyBps`. Active stake already
+		/// leaves instantly through `unstake`, so only unbonding stake can be withdrawn here.
+		///
+		/// `amount` is taken from the requests that unlock last first. The staker gets
+		/// `amount * (10000 - EmergencyWithdrawPenaltyBps) / 10000` back, rounded to the
+		/// nearest point with `div_round_nearest`, and the rest is added to the reward pool.
+		/// The stake is closed once no active stake and no unbonding requests remain.
+		///
+		/// ## Parameters
+		/// - `origin`: The staker account
+		/// - `amount`: Amount of unbonding stake to withdraw
+		///
+		/// ## Emits
+		/// - `EmergencyWithdrawn` on success
+		///
+		/// ## Errors
+		/// - `ZeroAmount` if `amount` is zero
+		/// - `NoUnbondingRequests` if the caller has nothing unbonding
+		/// - `InsufficientBalance` if `amount` exceeds the caller's stake that has not unlocked
+		///   yet
+		/// - `VerifierCannotEmergencyWithdraw` if the caller is a verifier of the current era
+		/// - `RewardPoolOverflow` if the penalty would overflow the reward pool
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::emergency_withdraw())]
+		pub fn emergency_withdraw(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				!Self::is_current_verifier(&staker),
+				Error::<T>::VerifierCannotEmergencyWithdraw
+			);
 
+			let current_block = frame_system::Pallet::<T>::block_number();
 			UnbondingRequests::<T>::try_mutate(&staker, |requests| -> DispatchResult {
 				ensure!(!requests.is_empty(), Error::<T>::NoUnbondingRequests);
-
-				// Process all completed unbonding requests
-				let mut remaining = Vec::new();
-				for req in requests.iter() {
-					if req.unlocks_at <= current_block {
-						total_withdrawn = total_withdrawn.saturating_add(req.amount);
-					} else {
-						remaining.push(req.clone());
+				let pending = requests
+					.iter()
+					.filter(|request| request.unlocks_at > current_block)
+					.fold(0u128, |total, request| total.saturating_add(request.amount));
+				ensure!(pending >= amount, Error::<T>::InsufficientBalance);
+
+				// Requests are kept in request order, so the last ones unlock last
+				let mut remaining = amount;
+				for request in requests.iter_mut().rev() {
+					if remaining.is_zero() {
+						break;
 					}
+					if request.unlocks_at <= current_block {
+						continue;
+					}
+					let taken = remaining.min(request.amount);
+					request.amount = request.amount.saturating_sub(taken);
+					remaining = remaining.saturating_sub(taken);
 				}
+				requests.retain(|request| !request.amount.is_zero());
+				Ok(())
+			})?;
 
-				ensure!(total_withdrawn > 0, Error::<T>::UnbondingNotComplete);
+			let penalty_bps = T::EmergencyWithdrawPenaltyBps::get().min(10_000);
+			// Rounds to nearest so the staker does not lose a point to truncation
+			let returned = div_round_nearest(
+				amount.saturating_mul(10_000u32.saturating_sub(penalty_bps) as u128),
+				10_000,
+			);
+			let penalty = amount.saturating_sub(returned);
 
-				// Replace with remaining requests
-				*requests = BoundedVec::try_from(remaining)
-					.map_err(|_| Error::<T>::TooManyUnbondingRequests)?;
+			Self::increase_reward_pool(penalty)?;
+			Self::release_bonded_stake(&staker, amount);
+			Self::close_stake_if_empty(&staker);
 
-				Ok(())
-			})?;
+			Self::deposit_event(Event::EmergencyWithdrawn { staker, amount, returned, penalty });
 
-			// Clean up staker if no stake and no unbonding requests remain
-			if let Some(info) = Stakes::<T>::get(&staker) {
-				if info.amount == 0 && UnbondingRequests::<T>::get(&staker).is_empty() {
-					Stakes::<T>::remove(&staker);
-					StakerList::<T>::mutate(|stakers| {
-						stakers.retain(|s| s != &staker);
-					});
-				}
-			}
+			Ok(())
+		}
 
-			Self::deposit_event(Event::UnbondingWithdrawn { staker, amount: total_withdrawn });
+		/// Designate a recovery account that can withdraw the caller's matured unbonding
+		/// if the caller loses access to their key. Replaces any previous recovery account.
+		///
+		/// The recovery account must be set while the staker has a clean record; stakers
+		/// that have already been slashed cannot set or change it.
+		///
+		/// ## Parameters
+		/// - `origin`: The staker account
+		/// - `recovery`: The account allowed to call `recover_unbonded`
+		///
+		/// ## Errors
+		/// - `NotStaker` if the caller has no stake
+		/// - `InvalidRecoveryAccount` if `recovery` is the caller
+		/// - `RecoveryAfterSlash` if the caller has been slashed
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_staking_recovery())]
+		pub fn set_staking_recovery(
+			origin: OriginFor<T>,
+			recovery: T::AccountId,
+		) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+
+			ensure!(EnhancedStakes::<T>::contains_key(&staker), Error::<T>::NotStaker);
+			ensure!(recovery != staker, Error::<T>::InvalidRecoveryAccount);
+			ensure!(SlashRecords::<T>::get(&staker).is_empty(), Error::<T>::RecoveryAfterSlash);
+
+			RecoveryAccount::<T>::insert(&staker, &recovery);
+
+			Self::deposit_event(Event::StakingRecoverySet { staker, recovery });
+
+			Ok(())
+		}
+
+		/// Withdraw a staker's matured unbonding to the calling recovery account.
+		///
+		/// ## Parameters
+		/// - `origin`: The staker's recovery account
+		/// - `staker`: The staker whose unbonding is withdrawn
+		///
+		/// ## Errors
+		/// - `NotRecoveryAccount` if the caller is not the staker's recovery account
+		/// - `NoUnbondingRequests` / `UnbondingNotComplete` as in `withdraw_unbonded`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::recover_unbonded())]
+		pub fn recover_unbonded(origin: OriginFor<T>, staker: T::AccountId) -> DispatchResult {
+			let recovery = ensure_signed(origin)?;
+
+			ensure!(
+				RecoveryAccount::<T>::get(&staker).as_ref() == Some(&recovery),
+				Error::<T>::NotRecoveryAccount
+			);
+
+			let amount = Self::withdraw_matured_unbonding(&staker)?;
+
+			Self::deposit_event(Event::StakingRecovered { staker, recovery, amount });
 
 			Ok(())
 		}
@@ -1854,16 +4863,18 @@ pub mod pallet {
 			})?;
 
 			// Re-add to stake
-			Stakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
+			EnhancedStakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
 				let info = maybe_info.as_mut().ok_or(Error::<T>::NotStaker)?;
-				info.amount = info.amount.saturating_add(total_rebonded);
+				info.staked_at = Self::blended_staked_at(info, total_rebonded);
+				info.active =
+					info.active.checked_add(total_rebonded).ok_or(Error::<T>::StakeOverflow)?;
 				Ok(())
 			})?;
+			Self::note_stake_change(&staker);
+			Self::rebalance_staker_set(&staker);
 
 			// Update total staked
-			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_add(total_rebonded);
-			});
+			Self::increase_total_staked(total_rebonded)?;
 
 			Self::deposit_event(Event::UnbondingCancelled { staker, amount: total_rebonded });
 
@@ -1872,16 +4883,34 @@ pub mod pallet {
 
 		/// Slash a staker for misbehavior. Admin only.
 		///
+		/// With an `offense_block`, the slash is computed on the active stake the staker held
+		/// at that block (from `StakeHistory`), so unbonding after the offense does not reduce
+		/// the penalty. When `StakeHistory` no longer reaches back to the offense block, the
+		/// largest stake still on record (or the current stake, if larger) is used instead.
+		/// Without an offense block, the current stake is used. The slash is taken from active stake first, then from pending unbonding
+		/// requests (newest first).
+		///
 		/// ## Parameters
 		/// - `origin`: Must be admin
 		/// - `staker`: Account to slash
 		/// - `reason`: Reason for slashing
+		/// - `offense_block`: Block at which the offense happened, if known
+		///
+		/// `SlashToRewardPoolBps` of the slash is added to the reward pool, the rest is burned.
+		///
+		/// ## Emits
+		/// - `Slashed` and `AdminActionPerformed` on success
+		/// - `SlashRecycled` if part of the slash was added to the reward pool
+		///
+		/// ## Errors
+		/// - `RewardPoolOverflow` if the recycled share would overflow the reward pool
 		#[pallet::call_index(15)]
 		#[pallet::weight(T::WeightInfo::slash_staker())]
 		pub fn slash_staker(
 			origin: OriginFor<T>,
 			staker: T::AccountId,
 			reason: SlashReason,
+			offense_block: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 			Self::ensure_admin(&caller)?;
@@ -1894,11 +4923,24 @@ pub mod pallet {
 				SlashReason::Other => T::OfflineSlashPercent::get(), // Use offline as default
 			};
 
-			let stake_info = Stakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
-			let slash_amount = stake_info
-				.amount
-				.saturating_mul(slash_percent as u128)
-				.saturating_div(10_000);
+			let stake_info = EnhancedStakes::<T>::get(&staker).ok_or(Error::<T>::NotStaker)?;
+			let slashable_stake = match offense_block {
+				Some(block) => Self::stake_at(&staker, block).unwrap_or_else(|| {
+					StakeHistory::<T>::get(&staker)
+						.iter()
+						.map(|(_, amount)| *amount)
+						.fold(stake_info.active, u128::max)
+				}),
+				None => stake_info.active,
+			};
+			// Rounds down: a fractional slash is never taken from the staker
+			let slash_amount =
+				slashable_stake.saturating_mul(slash_percent as u128).saturating_div(10_000);
+
+			// Never take more than the staker still has bonded
+			let unbonding: u128 =
+				UnbondingRequests::<T>::get(&staker).iter().map(|r| r.amount).sum();
+			let slash_amount = slash_amount.min(stake_info.active.saturating_add(unbonding));
 
 			ensure!(slash_amount > 0, Error::<T>::SlashAmountZero);
 
@@ -1915,27 +4957,150 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			// Reduce stake
-			Stakes::<T>::mutate(&staker, |maybe_info| {
+			// Reduce active stake, then pending unbonding for the rest
+			let from_active = slash_amount.min(stake_info.active);
+			EnhancedStakes::<T>::mutate(&staker, |maybe_info| {
 				if let Some(info) = maybe_info {
-					info.amount = info.amount.saturating_sub(slash_amount);
+					info.active = info.active.saturating_sub(from_active);
+					info.total_slashed = info.total_slashed.saturating_add(slash_amount);
 				}
 			});
+			Self::note_stake_change(&staker);
+			Self::rebalance_staker_set(&staker);
+
+			let mut from_unbonding = slash_amount.saturating_sub(from_active);
+			if from_unbonding > 0 {
+				UnbondingRequests::<T>::mutate(&staker, |requests| {
+					for req in requests.iter_mut().rev() {
+						let taken = from_unbonding.min(req.amount);
+						req.amount = req.amount.saturating_sub(taken);
+						from_unbonding = from_unbonding.saturating_sub(taken);
+					}
+					requests.retain(|r| r.amount > 0);
+				});
+			}
 
 			// Update totals
 			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_sub(slash_amount);
+				*total = total.saturating_sub(from_active);
 			});
+			Self::release_bonded_stake(&staker, slash_amount);
 			TotalSlashed::<T>::mutate(|total| {
 				*total = total.saturating_add(slash_amount);
 			});
+			SlashStatsByReason::<T>::mutate(&reason, |(count, total)| {
+				*count = count.saturating_add(1);
+				*total = total.saturating_add(slash_amount);
+			});
+
+			// Recycle the configured share into the reward pool; the rest is burned
+			let recycle_bps = T::SlashToRewardPoolBps::get().min(10_000);
+			let recycled = slash_amount.saturating_mul(recycle_bps as u128).saturating_div(10_000);
+			if !recycled.is_zero() {
+				Self::increase_reward_pool(recycled)?;
+			}
 
-			Self::deposit_event(Event::Slashed { staker, amount: slash_amount, reason });
+			Self::deposit_event(Event::Slashed {
+				staker: staker.clone(),
+				amount: slash_amount,
+				reason,
+			});
+			if !recycled.is_zero() {
+				Self::deposit_event(Event::SlashRecycled {
+					staker: staker.clone(),
+					amount: recycled,
+				});
+			}
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::SlashStaker,
+				target: Some(staker),
+			});
 
 			Ok(())
 		}
 
+		/// Rebuild `StakerList` and `StakerWaitlist` from `EnhancedStakes`, repairing drift
+		/// between them. Admin only.
+		///
+		/// Accounts with a non-zero stake are ranked by stake, as `add_to_staker_set` would
+		/// place them: the largest `MaxStakers` form the active set, the next
+		/// `MaxStakerWaitlist` are waitlisted and any others are evicted. Evicted stakers keep
+		/// their stake and are placed again once it ranks.
+		///
+		/// Evicted stakers keep their `EnhancedStakes` entry, so the number of entries is not
+		/// bounded by the set sizes. The caller passes it as the `stakes` witness, which the
+		/// call is charged for, and the call fails once it scans more entries than that.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be admin
+		/// - `stakes`: Number of `EnhancedStakes` entries, or an upper bound of it
+		///
+		/// ## Emits
+		/// - `StakerEvicted` for every staker that fits in neither set
+		/// - `StakerListRebuilt` with the sizes of the rebuilt active set and waitlist
+		/// - `AdminActionPerformed` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `InvalidStakeWitness` if there are more than `stakes` entries in `EnhancedStakes`
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::rebuild_staker_list(*stakes))]
+		pub fn rebuild_staker_list(
+			origin: OriginFor<T>,
+			stakes: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			let mut scanned: u32 = 0;
+			let mut stakers: Vec<(T::AccountId, u128)> = Vec::new();
+			for (staker, info) in EnhancedStakes::<T>::iter() {
+				scanned = scanned.saturating_add(1);
+				ensure!(scanned <= stakes, Error::<T>::InvalidStakeWitness);
+				if info.active > 0 {
+					stakers.push((staker, info.active));
+				}
+			}
+
+			// Largest stakes fill the active set, the next largest the waitlist
+			stakers.sort_by(|a, b| b.1.cmp(&a.1));
+			let mut ranked = stakers.into_iter();
+			let active = BoundedVec::<_, T::MaxStakers>::truncate_from(
+				ranked
+					.by_ref()
+					.take(T::MaxStakers::get() as usize)
+					.map(|(staker, _)| staker)
+					.collect(),
+			);
+			let waitlist = BoundedVec::<_, T::MaxStakerWaitlist>::truncate_from(
+				ranked
+					.by_ref()
+					.take(T::MaxStakerWaitlist::get() as usize)
+					.map(|(staker, _)| staker)
+					.collect(),
+			);
+			let count = active.len() as u32;
+			let waitlisted = waitlist.len() as u32;
+			StakerList::<T>::put(active);
+			StakerWaitlist::<T>::put(waitlist);
+
+			// Whoever fits in neither set is evicted
+			for (staker, amount) in ranked {
+				Self::deposit_event(Event::StakerEvicted { staker, amount });
+			}
+			Self::deposit_event(Event::StakerListRebuilt { count, waitlisted });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::RebuildStakerList,
+				target: None,
+			});
+
+			Ok(Some(T::WeightInfo::rebuild_staker_list(scanned)).into())
+		}
+
 		/// Create a new staking pool. Caller becomes the pool operator.
+		/// While `PoolCreationRestricted` is set, only accounts in `PoolCreators` may call this.
 		///
 		/// ## Parameters
 		/// - `origin`: The operator account
@@ -1950,6 +5115,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			let operator = ensure_signed(origin)?;
 
+			ensure!(
+				!PoolCreationRestricted::<T>::get() || PoolCreators::<T>::get(&operator),
+				Error::<T>::NotAuthorizedPoolCreator
+			);
 			ensure!(
 				initial_stake >= T::MinPoolOperatorStake::get(),
 				Error::<T>::InsufficientOperatorStake
@@ -1975,9 +5144,7 @@ pub mod pallet {
 			NextPoolId::<T>::put(pool_id.saturating_add(1));
 
 			// Update total staked
-			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_add(initial_stake);
-			});
+			Self::increase_total_staked(initial_stake)?;
 
 			Self::deposit_event(Event::PoolCreated { pool_id, operator, initial_stake, commission });
 
@@ -1986,6 +5153,8 @@ pub mod pallet {
 
 		/// Delegate stake to a pool.
 		///
+		/// An account's position in a pool may not exceed `MaxDelegationPerPoolPerAccount`.
+		///
 		/// ## Parameters
 		/// - `origin`: The delegator account
 		/// - `pool_id`: Pool to delegate to
@@ -1999,6 +5168,10 @@ pub mod pallet {
 			ensure!(amount >= T::MinStakeAmount::get(), Error::<T>::DelegationBelowMinimum);
 			ensure!(Delegations::<T>::get(&delegator).is_none(), Error::<T>::AlreadyDelegating);
 
+			// An account holds at most one delegation, so its position in the pool is `amount`
+			let cap = T::MaxDelegationPerPoolPerAccount::get();
+			ensure!(cap.is_zero() || amount <= cap, Error::<T>::DelegationCapExceeded);
+
 			let current_block = frame_system::Pallet::<T>::block_number();
 
 			// Update pool
@@ -2006,7 +5179,8 @@ pub mod pallet {
 				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
 				ensure!(pool.is_active, Error::<T>::PoolNotActive);
 
-				pool.total_stake = pool.total_stake.saturating_add(amount);
+				pool.total_stake =
+					pool.total_stake.checked_add(amount).ok_or(Error::<T>::StakeOverflow)?;
 				pool.delegator_count = pool.delegator_count.saturating_add(1);
 
 				Ok(())
@@ -2026,9 +5200,7 @@ pub mod pallet {
 			Delegations::<T>::insert(&delegator, delegation_info);
 
 			// Update total staked
-			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_add(amount);
-			});
+			Self::increase_total_staked(amount)?;
 
 			Self::deposit_event(Event::Delegated { delegator, pool_id, amount });
 
@@ -2057,9 +5229,14 @@ pub mod pallet {
 				}
 			});
 
-			// Remove from pool delegators
-			PoolDelegators::<T>::mutate(pool_id, |delegators| {
-				delegators.retain(|d| d != &delegator);
+			// Remove from pool delegators, dropping the index once it is empty
+			PoolDelegators::<T>::mutate_exists(pool_id, |maybe_delegators| {
+				if let Some(delegators) = maybe_delegators {
+					delegators.retain(|d| d != &delegator);
+					if delegators.is_empty() {
+						*maybe_delegators = None;
+					}
+				}
 			});
 
 			// Remove delegation record
@@ -2106,11 +5283,18 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Close/deactivate a pool. Operator only. Pool must have no delegators.
+		/// Close/deactivate a pool. Operator only. Pool must have no delegators and must
+		/// have existed for at least `MinPoolLifetime` blocks.
 		///
 		/// ## Parameters
 		/// - `origin`: Must be pool operator
 		/// - `pool_id`: Pool ID
+		///
+		/// ## Errors
+		/// - `PoolNotFound` if the pool does not exist
+		/// - `NotPoolOperator` if the caller does not operate the pool
+		/// - `PoolHasDelegators` if anyone still delegates to the pool
+		/// - `PoolTooYoung` if the pool was created less than `MinPoolLifetime` blocks ago
 		#[pallet::call_index(20)]
 		#[pallet::weight(T::WeightInfo::close_pool())]
 		pub fn close_pool(origin: OriginFor<T>, pool_id: u32) -> DispatchResult {
@@ -2119,25 +5303,170 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			ensure!(pool.operator == caller, Error::<T>::NotPoolOperator);
 			ensure!(pool.delegator_count == 0, Error::<T>::PoolHasDelegators);
+			ensure!(
+				frame_system::Pallet::<T>::block_number()
+					>= pool.created_at.saturating_add(T::MinPoolLifetime::get()),
+				Error::<T>::PoolTooYoung
+			);
 
 			// Return operator stake
 			TotalStaked::<T>::mutate(|total| {
 				*total = total.saturating_sub(pool.operator_stake);
 			});
 
-			// Remove pool
+			// Remove pool and its delegator index
 			Pools::<T>::remove(pool_id);
+			PoolDelegators::<T>::remove(pool_id);
+
+			Self::deposit_event(Event::PoolClosed { pool_id, operator: caller });
+
+			Ok(())
+		}
+
+		/// Remove a leftover delegator index for a pool that no longer exists.
+		/// Maintenance call, can be called by anyone.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `pool_id`: Pool ID
+		///
+		/// ## Errors
+		/// - `PoolStillExists` if the pool has not been removed
+		/// - `PoolIndexNotFound` if there is no delegator index for the pool
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::cleanup_pool_index())]
+		pub fn cleanup_pool_index(origin: OriginFor<T>, pool_id: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!Pools::<T>::contains_key(pool_id), Error::<T>::PoolStillExists);
+			ensure!(PoolDelegators::<T>::contains_key(pool_id), Error::<T>::PoolIndexNotFound);
+
+			PoolDelegators::<T>::remove(pool_id);
+
+			Self::deposit_event(Event::PoolIndexCleaned { pool_id });
+
+			Ok(())
+		}
+
+		/// Release delegations left behind by a pool that no longer exists. Each delegator
+		/// still listed in the pool's delegator index and delegating to it is undelegated as
+		/// by `undelegate`, returning its amount out of `TotalStaked`, and the index is
+		/// removed. Admin only.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `pool_id`: Pool ID
+		///
+		/// ## Emits
+		/// - `Undelegated` for each released delegation
+		/// - `ClosedPoolResidueSwept` with the total amount released
+		/// - `AdminActionPerformed` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `PoolStillExists` if the pool has not been removed
+		/// - `PoolIndexNotFound` if there is no delegator index for the pool
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::sweep_closed_pool_residue(T::MaxDelegatorsPerPool::get()))]
+		pub fn sweep_closed_pool_residue(
+			origin: OriginFor<T>,
+			pool_id: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			ensure!(!Pools::<T>::contains_key(pool_id), Error::<T>::PoolStillExists);
+			ensure!(PoolDelegators::<T>::contains_key(pool_id), Error::<T>::PoolIndexNotFound);
+			let delegators = PoolDelegators::<T>::take(pool_id);
+
+			let mut amount: u128 = 0;
+			for delegator in delegators.iter() {
+				// Delegators that moved on to another pool keep their delegation
+				let Some(delegation) = Delegations::<T>::get(delegator) else { continue };
+				if delegation.pool_id != pool_id {
+					continue;
+				}
+				Delegations::<T>::remove(delegator);
+				amount = amount.saturating_add(delegation.amount);
+				Self::deposit_event(Event::Undelegated {
+					delegator: delegator.clone(),
+					pool_id,
+					amount: delegation.amount,
+				});
+			}
+
+			TotalStaked::<T>::mutate(|total| {
+				*total = total.saturating_sub(amount);
+			});
+
+			Self::deposit_event(Event::ClosedPoolResidueSwept { pool_id, amount });
+			Self::deposit_event(Event::AdminActionPerformed {
+				admin: Some(caller),
+				action: AdminAction::SweepClosedPoolResidue,
+				target: None,
+			});
+
+			Ok(Some(T::WeightInfo::sweep_closed_pool_residue(delegators.len() as u32)).into())
+		}
+
+		/// Restrict pool creation to the `PoolCreators` allow-list, or lift the restriction.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `restricted`: Whether pool creation requires being in the allow-list
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_pool_creation_restricted())]
+		pub fn set_pool_creation_restricted(
+			origin: OriginFor<T>,
+			restricted: bool,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+
+			PoolCreationRestricted::<T>::put(restricted);
+
+			Self::deposit_event(Event::PoolCreationRestrictionSet { restricted });
+			Ok(())
+		}
+
+		/// Add an account to, or remove it from, the pool creator allow-list.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be the admin
+		/// - `account`: The account to update
+		/// - `allowed`: Whether the account may create pools while creation is restricted
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_pool_creator())]
+		pub fn set_pool_creator(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			allowed: bool,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
 
-			Self::deposit_event(Event::PoolClosed { pool_id, operator: caller });
+			if allowed {
+				PoolCreators::<T>::insert(&account, true);
+			} else {
+				PoolCreators::<T>::remove(&account);
+			}
 
+			Self::deposit_event(Event::PoolCreatorUpdated { account, allowed });
 			Ok(())
 		}
 
 		/// Trigger era rotation and verifier selection. Can be called by anyone when due.
-		/// Selects verifiers based on stake-weighted randomness.
+		/// Selects verifiers based on stake-weighted randomness, scanning and sorting the
+		/// whole `StakerList`.
 		#[pallet::call_index(21)]
-		#[pallet::weight(T::WeightInfo::rotate_era())]
-		pub fn rotate_era(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::rotate_era(T::MaxStakers::get()))]
+		pub fn rotate_era(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
 			let current_block = frame_system::Pallet::<T>::block_number();
@@ -2155,6 +5484,7 @@ pub mod pallet {
 			LastEraBlock::<T>::put(current_block);
 
 			// Select verifiers using stake-weighted selection
+			let staker_count = StakerList::<T>::decode_len().unwrap_or(0) as u32;
 			let selected = Self::select_verifiers_for_era(new_era);
 			let verifier_count = selected.len() as u32;
 
@@ -2166,15 +5496,116 @@ pub mod pallet {
 
 			Self::deposit_event(Event::EraRotated { era: new_era, verifier_count });
 
+			Ok(Some(T::WeightInfo::rotate_era(staker_count)).into())
+		}
+
+		/// Attest to a period's activity as one of its verifiers.
+		/// Reports count towards the `VerifierQuorumPercent` required by `distribute_rewards`.
+		///
+		/// The first report for a period ties it to the current era: from then on only that
+		/// era's verifiers can report for it, even after a rotation, and its quorum is
+		/// measured against that era's verifier set.
+		///
+		/// ## Parameters
+		/// - `origin`: A verifier of the era attesting `period`
+		/// - `period`: Period number being attested (the current period or an earlier one)
+		///
+		/// ## Errors
+		/// - `RewardPeriodMisconfigured` if `BlocksPerRewardPeriod` is zero
+		/// - `PeriodInFuture` if `period` has not started yet
+		/// - `NotVerifier` if the caller is not a verifier of the era attesting `period`
+		/// - `AlreadyReported` if the caller already reported for `period`
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::report_verification())]
+		pub fn report_verification(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let verifier = ensure_signed(origin)?;
+			Self::ensure_reward_period_configured()?;
+			ensure!(period <= Self::current_period(), Error::<T>::PeriodInFuture);
+
+			let era = Self::verification_era(period);
+			ensure!(EraVerifiers::<T>::get(era).contains(&verifier), Error::<T>::NotVerifier);
+			ensure!(
+				!VerificationReports::<T>::contains_key(period, &verifier),
+				Error::<T>::AlreadyReported
+			);
+
+			PeriodVerificationEra::<T>::insert(period, era);
+			VerificationReports::<T>::insert(period, &verifier, ());
+			VerificationReportCount::<T>::mutate(period, |count| {
+				*count = count.saturating_add(1);
+			});
+
+			Self::deposit_event(Event::VerificationReported { verifier, period });
+
 			Ok(())
 		}
 
+		/// Remove the verification reports of a past period. Admin only.
+		/// `distribute_rewards` removes a period's reports itself; this clears the ones left
+		/// behind by periods whose rewards are never distributed.
+		///
+		/// ## Parameters
+		/// - `origin`: Must be admin
+		/// - `period`: Period number whose reports to remove
+		///
+		/// ## Emits
+		/// - `VerificationReportsPruned` on success
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `PeriodNotEnded` if `period` is the current period or later
+		#[pallet::call_index(68)]
+		#[pallet::weight(T::WeightInfo::prune_verification_reports(T::VerifiersPerEra::get()))]
+		pub fn prune_verification_reports(
+			origin: OriginFor<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_admin(&caller)?;
+			ensure!(period < Self::current_period(), Error::<T>::PeriodNotEnded);
+
+			// Only the period's verifiers can report, so there are at most `VerifiersPerEra`
+			let removed =
+				VerificationReports::<T>::clear_prefix(period, T::VerifiersPerEra::get(), None)
+					.unique;
+			VerificationReportCount::<T>::remove(period);
+			PeriodVerificationEra::<T>::remove(period);
+
+			Self::deposit_event(Event::VerificationReportsPruned { period, removed });
+
+			Ok(Some(T::WeightInfo::prune_verification_reports(removed)).into())
+		}
+
 		/// Distribute rewards for a completed period. Admin only.
 		/// Distributes rewards to stakers and issuers based on their proportions.
+		/// Stakers earn on their active stake only: amounts sitting in `UnbondingRequests`
+		/// are already excluded from both `EnhancedStakes` and `TotalStaked`. Issuers earn in
+		/// proportion to a score blending points spent and transaction count, weighted by
+		/// `IssuerRewardSpendWeight` and `IssuerRewardTransactionWeight`. Stakes made less
+		/// than `StakeRewardDelay` blocks ago earn nothing; the eligible stakes share their part.
+		/// With `RequireVerificationForReward` set, so do stakers that did not report for
+		/// `period` as one of its verifiers.
+		///
+		/// Requires `VerifierQuorumPercent` of the verifiers of the era attesting `period` (see
+		/// `report_verification`) to have reported for it. Eras without selected verifiers
+		/// impose no quorum. The period's verification reports are removed once its rewards
+		/// are distributed.
+		///
+		/// Accounts accruing again after their `RewardClaimWindow` ran out first lose their
+		/// stale pending rewards, which stay in the reward pool (`RewardExpired`).
 		///
 		/// ## Parameters
 		/// - `origin`: Must be admin
 		/// - `period`: Period number to distribute rewards for
+		///
+		/// ## Errors
+		/// - `NotAdmin` if caller is not the admin
+		/// - `RewardPeriodMisconfigured` if `BlocksPerRewardPeriod` is zero
+		/// - `VerificationQuorumNotMet` if too few verifiers reported for `period`
+		/// - `NoRewardsToClaim` if the reward pool is empty
 		#[pallet::call_index(22)]
 		#[pallet::weight(T::WeightInfo::distribute_rewards())]
 		pub fn distribute_rewards(
@@ -2183,28 +5614,35 @@ pub mod pallet {
 		) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 			Self::ensure_admin(&caller)?;
+			Self::ensure_reward_period_configured()?;
+
+			ensure!(Self::verification_quorum_met(period), Error::<T>::VerificationQuorumNotMet);
 
 			let reward_pool = RewardPool::<T>::get();
 			ensure!(reward_pool > 0, Error::<T>::NoRewardsToClaim);
 
-			let issuer_percent = T::IssuerRewardPercent::get();
-			let issuer_share = reward_pool
-				.saturating_mul(issuer_percent as u128)
-				.saturating_div(10_000);
-			let staker_share = reward_pool.saturating_sub(issuer_share);
+			let staker_share = Self::staker_reward_share(reward_pool);
+			let issuer_share = reward_pool.saturating_sub(staker_share);
+			// Stale rewards taken back from accounts accruing again
+			let mut recycled = 0u128;
 
-			// Distribute to issuers based on period spending
-			let period_total = PeriodTotalSpent::<T>::get(period);
+			// Distribute to issuers based on period spending and transaction counts
+			let period_total = IssuerDailyRecords::<T>::iter_prefix_values(period)
+				.fold(0u128, |total, record| {
+					total.saturating_add(Self::issuer_reward_score(&record))
+				});
 			if period_total > 0 && issuer_share > 0 {
-				// Iterate through authorized issuers and distribute based on spending
+				// Iterate through authorized issuers and distribute based on their score
 				// Note: In production, this should use pagination for large numbers
 				for (issuer, is_authorized) in AuthorizedIssuers::<T>::iter() {
 					if is_authorized {
 						let record = IssuerDailyRecords::<T>::get(period, &issuer);
-						if record.points_spent > 0 {
-							let issuer_reward = issuer_share
-								.saturating_mul(record.points_spent)
-								.saturating_div(period_total);
+						let score = Self::issuer_reward_score(&record);
+						if score > 0 {
+							// Rounds down so pro-rata payouts never exceed the issuer share
+							let issuer_reward =
+								issuer_share.saturating_mul(score).saturating_div(period_total);
+							recycled = recycled.saturating_add(Self::note_reward_accrual(&issuer));
 							PendingIssuerRewards::<T>::mutate(&issuer, |pending| {
 								*pending = pending.saturating_add(issuer_reward);
 							});
@@ -2214,22 +5652,27 @@ pub mod pallet {
 			}
 
 			// Distribute to stakers based on stake
-			let total_staked = TotalStaked::<T>::get();
-			if total_staked > 0 && staker_share > 0 {
-				for (staker, stake_info) in Stakes::<T>::iter() {
-					if stake_info.amount > 0 {
-						let staker_reward = staker_share
-							.saturating_mul(stake_info.amount)
-							.saturating_div(total_staked);
-						PendingStakerRewards::<T>::mutate(&staker, |pending| {
-							*pending = pending.saturating_add(staker_reward);
-						});
-					}
+			let (eligible, total_eligible) = Self::reward_eligible_stakes(period);
+			if total_eligible > 0 && staker_share > 0 {
+				for (staker, amount) in eligible {
+					// Rounds down so pro-rata payouts never exceed the staker share
+					let staker_reward =
+						staker_share.saturating_mul(amount).saturating_div(total_eligible);
+					recycled = recycled.saturating_add(Self::note_reward_accrual(&staker));
+					PendingStakerRewards::<T>::mutate(&staker, |pending| {
+						*pending = pending.saturating_add(staker_reward);
+					});
 				}
 			}
 
-			// Clear reward pool
-			RewardPool::<T>::put(0u128);
+			// Clear reward pool, keeping only the recycled stale rewards
+			RewardPool::<T>::put(recycled);
+
+			// The reports have served their purpose. Only the period's verifiers can report,
+			// so there are at most `VerifiersPerEra` of them.
+			let _ = VerificationReports::<T>::clear_prefix(period, T::VerifiersPerEra::get(), None);
+			VerificationReportCount::<T>::remove(period);
+			PeriodVerificationEra::<T>::remove(period);
 
 			Self::deposit_event(Event::RewardsDistributed {
 				period,
@@ -2241,22 +5684,149 @@ pub mod pallet {
 		}
 
 		/// Claim pending rewards (for stakers or issuers).
+		///
+		/// Rewards must be claimed within `RewardClaimWindow` blocks of the latest accrual or
+		/// partial claim. Stale rewards are not paid: they are returned to `RewardPool` and a
+		/// `RewardExpired` event is emitted instead of `RewardClaimed`. The call still
+		/// succeeds in that case so the recycling is persisted. The staleness check lives in
+		/// `rewards_expired`, which any reward sweeping logic should share so that claiming
+		/// and sweeping apply the same policy.
+		///
+		/// Staker and issuer rewards are paid according to `StakerRewardKind` and
+		/// `IssuerRewardKind`. A `Points` reward is minted into the caller's point balance as
+		/// a batch expiring after `DefaultExpirationPeriod`, so the claim fails if the caller
+		/// cannot hold another batch.
+		///
+		/// With a non-zero `RewardVestingPeriod`, `Currency` rewards are not paid out right
+		/// away: they vest linearly over the period and are withdrawn with
+		/// `withdraw_vested_reward` (`RewardVestingStarted`).
+		///
+		/// A claim pays at most `MaxClaimPerCall`, staker rewards first. Anything above the
+		/// cap stays pending and `PartialRewardClaimed` is emitted instead of `RewardClaimed`.
 		#[pallet::call_index(23)]
 		#[pallet::weight(T::WeightInfo::claim_rewards())]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 
-			let staker_reward = PendingStakerRewards::<T>::get(&caller);
-			let issuer_reward = PendingIssuerRewards::<T>::get(&caller);
-			let total_reward = staker_reward.saturating_add(issuer_reward);
+			ensure!(
+				Self::settle_pending_rewards(&caller, true, true)?,
+				Error::<T>::NoRewardsToClaim
+			);
+
+			Ok(())
+		}
+
+		/// Claim pending rewards on behalf of several accounts. Can be called by anyone
+		/// (e.g. a keeper bot). Rewards are paid to each account, never to the caller.
+		///
+		/// Accounts without pending rewards are skipped. Stale rewards are recycled into
+		/// the reward pool exactly as in `claim_rewards`. An account whose claim fails is
+		/// skipped without partial writes and keeps its rewards pending, so it cannot block
+		/// the rest of the batch.
+		///
+		/// ## Parameters
+		/// - `origin`: Any signed origin
+		/// - `accounts`: Accounts to claim for (at most `MaxClaimsPerBatch`)
+		///
+		/// ## Emits
+		/// - `RewardClaimed` (or `RewardExpired`) for each account with pending rewards
+		///
+		/// ## Errors
+		/// - `EmptyClaimBatch` if `accounts` is empty
+		/// - `TooManyClaimsInBatch` if more than `MaxClaimsPerBatch` accounts are given
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::claim_rewards_for(accounts.len() as u32))]
+		pub fn claim_rewards_for(
+			origin: OriginFor<T>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!accounts.is_empty(), Error::<T>::EmptyClaimBatch);
+			ensure!(
+				accounts.len() as u32 <= T::MaxClaimsPerBatch::get(),
+				Error::<T>::TooManyClaimsInBatch
+			);
+
+			for account in accounts.iter() {
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::settle_pending_rewards(account, true, true)
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Claim only the caller's pending staker rewards, leaving issuer rewards pending, e.g.
+		/// to book the two separately. Otherwise behaves like `claim_rewards`, including the
+		/// claim window, `StakerRewardKind`, vesting and `MaxClaimPerCall`.
+		///
+		/// ## Parameters
+		/// - `origin`: The account claiming its staker rewards
+		///
+		/// ## Emits
+		/// - `RewardClaimed`, `PartialRewardClaimed` or `RewardExpired`, as for `claim_rewards`
+		///
+		/// ## Errors
+		/// - `NoRewardsToClaim` if the caller has no pending staker rewards
+		#[pallet::call_index(65)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_staker_rewards_only(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			ensure!(
+				Self::settle_pending_rewards(&caller, true, false)?,
+				Error::<T>::NoRewardsToClaim
+			);
+
+			Ok(())
+		}
 
-			ensure!(total_reward > 0, Error::<T>::NoRewardsToClaim);
+		/// Claim only the caller's pending issuer rewards, leaving staker rewards pending.
+		/// Otherwise behaves like `claim_rewards`, paying according to `IssuerRewardKind`.
+		///
+		/// ## Parameters
+		/// - `origin`: The account claiming its issuer rewards
+		///
+		/// ## Emits
+		/// - `RewardClaimed`, `PartialRewardClaimed` or `RewardExpired`, as for `claim_rewards`
+		///
+		/// ## Errors
+		/// - `NoRewardsToClaim` if the caller has no pending issuer rewards
+		#[pallet::call_index(66)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_issuer_rewards_only(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-			// Clear pending rewards
-			PendingStakerRewards::<T>::remove(&caller);
-			PendingIssuerRewards::<T>::remove(&caller);
+			ensure!(
+				Self::settle_pending_rewards(&caller, false, true)?,
+				Error::<T>::NoRewardsToClaim
+			);
+
+			Ok(())
+		}
+
+		/// Withdraw the part of the caller's claimed rewards that has vested so far.
+		///
+		/// ## Parameters
+		/// - `origin`: The account whose rewards are vesting
+		///
+		/// ## Emits
+		/// - `VestedRewardWithdrawn` on success
+		///
+		/// ## Errors
+		/// - `NoVestingRewards` if the caller has no vesting rewards
+		/// - `NothingVested` if nothing has vested since the last withdrawal
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::withdraw_vested_reward())]
+		pub fn withdraw_vested_reward(origin: OriginFor<T>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-			Self::deposit_event(Event::RewardClaimed { account: caller, amount: total_reward });
+			ensure!(
+				RewardVestingSchedules::<T>::contains_key(&caller),
+				Error::<T>::NoVestingRewards
+			);
+			ensure!(Self::release_vested_reward(&caller) > 0, Error::<T>::NothingVested);
 
 			Ok(())
 		}
@@ -2275,17 +5845,24 @@ pub mod pallet {
 
 			let mut new_total: u128 = 0;
 
-			Stakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
+			EnhancedStakes::<T>::try_mutate(&staker, |maybe_info| -> DispatchResult {
 				let info = maybe_info.as_mut().ok_or(Error::<T>::NotStaker)?;
-				info.amount = info.amount.saturating_add(amount);
-				new_total = info.amount;
+				info.staked_at = Self::blended_staked_at(info, amount);
+				info.active = info.active.checked_add(amount).ok_or(Error::<T>::StakeOverflow)?;
+				new_total = info.active;
+				Ok(())
+			})?;
+			Self::note_stake_change(&staker);
+			Self::rebalance_staker_set(&staker);
+			BondedStake::<T>::try_mutate(&staker, |bonded| -> DispatchResult {
+				if let Some(bonded) = bonded {
+					*bonded = bonded.checked_add(amount).ok_or(Error::<T>::StakeOverflow)?;
+				}
 				Ok(())
 			})?;
 
 			// Update total staked
-			TotalStaked::<T>::mutate(|total| {
-				*total = total.saturating_add(amount);
-			});
+			Self::increase_total_staked(amount)?;
 
 			Self::deposit_event(Event::StakeIncreased { staker, amount, new_total });
 
@@ -2293,23 +5870,440 @@ pub mod pallet {
 		}
 	}
 
-	// ============================================================================
-	// INTERNAL HELPER FUNCTIONS
-	// ============================================================================
+	// ============================================================================
+	// VIEW FUNCTIONS
+	// ============================================================================
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// Slash count and total amount slashed for each reason, in declaration order
+		pub fn slash_stats_by_reason() -> Vec<(SlashReason, u32, u128)> {
+			SlashReason::ALL
+				.into_iter()
+				.map(|reason| {
+					let (count, total) = SlashStatsByReason::<T>::get(&reason);
+					(reason, count, total)
+				})
+				.collect()
+		}
+
+		/// `(current_batches, max_batches)` of a user, so UIs can warn before awards fail
+		pub fn batch_utilization(user: T::AccountId) -> (u32, u32) {
+			(UserPoints::<T>::decode_len(&user).unwrap_or(0) as u32, T::MaxPointBatches::get())
+		}
+
+		/// `(staker_rewards, issuer_rewards)` pending for an account, for accounts that are
+		/// both a staker and an issuer and claim the two separately
+		pub fn pending_rewards_breakdown(account: T::AccountId) -> (u128, u128) {
+			(PendingStakerRewards::<T>::get(&account), PendingIssuerRewards::<T>::get(&account))
+		}
+
+		/// A user's point batches, each paired with its current index in `UserPoints` as taken
+		/// by `reclassify_batch`. Indices shift when batches are sorted by expiry, merged or
+		/// removed, so they should be read right before submitting.
+		pub fn get_indexed_batches(
+			user: T::AccountId,
+		) -> Vec<(u32, PointBatch<BlockNumberFor<T>>)> {
+			UserPoints::<T>::get(&user)
+				.into_iter()
+				.enumerate()
+				.map(|(index, batch)| (index as u32, batch))
+				.collect()
+		}
+
+		/// Whether the batch at `batch_index` in `UserPoints` could fund a spend right now:
+		/// it exists, has points left after expiry (including partial expiry) and was earned
+		/// at least `MinHoldingPeriod` blocks ago. `MinRetainedPoints` is not checked, as it
+		/// depends on the amount spent.
+		pub fn is_batch_spendable(user: T::AccountId, batch_index: u32) -> bool {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let seasoned_before = current_block.saturating_sub(T::MinHoldingPeriod::get());
+			UserPoints::<T>::get(&user)
+				.get(batch_index as usize)
+				.and_then(|batch| Self::batch_after_expiry(batch, current_block))
+				.is_some_and(|batch| {
+					!batch.remaining_points.is_zero() && batch.earned_at_block <= seasoned_before
+				})
+		}
+
+		/// Estimated annual return of delegating to a pool, in basis points.
+		/// Assumes the current `RewardPool` is paid out every reward period, with the
+		/// stakers' `StakerRewardPercent` share split by stake and the operator commission
+		/// taken before delegators are paid. Returns 0 for a missing or empty pool.
+		pub fn pool_apy(pool_id: u32) -> u32 {
+			let total_staked = TotalStaked::<T>::get();
+			let Some(pool) = Pools::<T>::get(pool_id) else { return 0 };
+			if pool.total_stake.is_zero() || total_staked.is_zero() {
+				return 0;
+			}
+
+			let periods_per_year: u128 =
+				Self::period_for_block(BLOCKS_PER_YEAR.into()).saturated_into();
+			let delegator_percent = 10_000u32.saturating_sub(pool.commission) as u128;
+			// Per-period yield on one unit of stake is independent of the pool's size
+			RewardPool::<T>::get()
+				.saturating_mul(T::StakerRewardPercent::get() as u128)
+				.saturating_mul(delegator_percent)
+				.saturating_mul(periods_per_year)
+				.saturating_div(total_staked.saturating_mul(10_000))
+				.saturated_into()
+		}
+
+		/// Commission, operator stake ratio, delegators, stake, status and operator slashing
+		/// history of a pool, for delegators weighing its risk. `None` for an unknown pool.
+		pub fn pool_health(pool_id: u32) -> Option<PoolHealth> {
+			let pool = Pools::<T>::get(pool_id)?;
+			let operator_stake_ratio = if pool.total_stake.is_zero() {
+				0
+			} else {
+				pool.operator_stake
+					.saturating_mul(10_000)
+					.saturating_div(pool.total_stake)
+					.saturated_into()
+			};
+			Some(PoolHealth {
+				commission: pool.commission,
+				operator_stake_ratio,
+				delegator_count: PoolDelegators::<T>::get(pool_id).len() as u32,
+				total_stake: pool.total_stake,
+				is_active: pool.is_active,
+				operator_slashed: !SlashRecords::<T>::get(&pool.operator).is_empty(),
+			})
+		}
+
+		/// Everything `account` has at stake: flat stake, pools it operates, its delegation
+		/// and its pending unbonding. Iterates `Pools`, which is bounded by `MaxPools`.
+		pub fn full_staking_position(account: T::AccountId) -> StakingPosition {
+			StakingPosition {
+				active: EnhancedStakes::<T>::get(&account)
+					.map(|info| info.active)
+					.unwrap_or_default(),
+				pools_operated: Pools::<T>::iter()
+					.filter(|(_, pool)| pool.operator == account)
+					.map(|(pool_id, pool)| (pool_id, pool.operator_stake))
+					.collect(),
+				delegation: Delegations::<T>::get(&account)
+					.map(|delegation| (delegation.pool_id, delegation.amount)),
+				unbonding: UnbondingRequests::<T>::get(&account)
+					.iter()
+					.map(|request| request.amount)
+					.fold(0u128, |total, amount| total.saturating_add(amount)),
+			}
+		}
+
+		/// Reward `account` would be credited if `distribute_rewards` ran now with the
+		/// current `RewardPool`, i.e. its stake's share of the stakers' part. Returns 0 when
+		/// the account has no stake or its stake is younger than `StakeRewardDelay`. Under
+		/// `RequireVerificationForReward`, verification reports for the current period count.
+		pub fn projected_reward(account: T::AccountId) -> u128 {
+			let staker_share = Self::staker_reward_share(RewardPool::<T>::get());
+			let (eligible, total_eligible) = Self::reward_eligible_stakes(Self::current_period());
+			if total_eligible.is_zero() {
+				return 0;
+			}
+			eligible
+				.into_iter()
+				.find(|(staker, _)| *staker == account)
+				.map_or(0, |(_, amount)| {
+					staker_share.saturating_mul(amount).saturating_div(total_eligible)
+				})
+		}
+
+		/// Whether an account currently meets the prerequisites for verifier selection
+		pub fn is_verifier_eligible(account: T::AccountId) -> bool {
+			StakerList::<T>::get().contains(&account) && Self::verifier_stake(&account).is_some()
+		}
+
+		/// Verifiers selected for `era`, past or current, so auditors can tell who verified
+		/// when. Empty for an era that selected no verifiers or has not started yet.
+		pub fn get_era_verifiers(era: u32) -> Vec<T::AccountId> {
+			EraVerifiers::<T>::get(era).to_vec()
+		}
+
+		/// `(blocks_elapsed, blocks_per_era)` of the current era, for progress bars. Blocks
+		/// elapsed are capped at `BlocksPerEra` once rotation is due but has not happened.
+		pub fn era_progress() -> (BlockNumberFor<T>, BlockNumberFor<T>) {
+			let blocks_per_era = T::BlocksPerEra::get();
+			let elapsed = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(LastEraBlock::<T>::get())
+				.min(blocks_per_era);
+			(elapsed, blocks_per_era)
+		}
+
+		/// Period number that `block` falls into.
+		/// With a zero `BlocksPerRewardPeriod`, each block is its own period.
+		pub fn period_for_block(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let blocks_per_period = T::BlocksPerRewardPeriod::get();
+			if blocks_per_period.is_zero() {
+				// Fallback: treat each block as its own period
+				// This is not recommended for production use
+				return block;
+			}
+			block / blocks_per_period
+		}
+
+		/// First block of `period`. The period ends right before the next period's start block.
+		/// With a zero `BlocksPerRewardPeriod`, each block is its own period.
+		pub fn period_start_block(period: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let blocks_per_period = T::BlocksPerRewardPeriod::get();
+			if blocks_per_period.is_zero() {
+				return period;
+			}
+			period.saturating_mul(blocks_per_period)
+		}
+
+		/// Authorization, current-period spend and pending reward of an issuer
+		pub fn issuer_dashboard(issuer: T::AccountId) -> IssuerDashboard<BlockNumberFor<T>> {
+			let period = Self::current_period();
+			let record = IssuerDailyRecords::<T>::get(period, &issuer);
+
+			IssuerDashboard {
+				authorized: AuthorizedIssuers::<T>::get(&issuer),
+				period,
+				points_spent: record.points_spent,
+				transaction_count: record.transaction_count,
+				pending_reward: PendingIssuerRewards::<T>::get(&issuer),
+			}
+		}
+
+		/// The user's live batch that expires soonest, as (remaining_points, expires_at_block,
+		/// travel_type), or `None` without live points. Batches are kept sorted by expiry, so
+		/// this only skips over expired batches that have not been cleaned up yet.
+		pub fn next_expiring_batch(
+			user: T::AccountId,
+		) -> Option<(u128, BlockNumberFor<T>, TravelType)> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			UserPoints::<T>::get(&user)
+				.iter()
+				.filter_map(|b| Self::batch_after_expiry(b, current_block))
+				.filter(|b| b.remaining_points > 0)
+				.min_by_key(|b| b.expires_at_block)
+				.map(|b| (b.remaining_points, b.expires_at_block, b.travel_type))
+		}
+
+		/// The user's live points summed per travel type, in order of each type's soonest
+		/// expiry. Types without live points are left out.
+		pub fn points_by_travel_type(user: T::AccountId) -> Vec<(TravelType, u128)> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut totals: Vec<(TravelType, u128)> = Vec::new();
+
+			for batch in UserPoints::<T>::get(&user)
+				.iter()
+				.filter_map(|b| Self::batch_after_expiry(b, current_block))
+				.filter(|b| b.remaining_points > 0)
+			{
+				match totals.iter_mut().find(|(travel_type, _)| *travel_type == batch.travel_type) {
+					Some((_, total)) => *total = total.saturating_add(batch.remaining_points),
+					None => totals.push((batch.travel_type, batch.remaining_points)),
+				}
+			}
+
+			totals
+		}
+
+		/// For each (ticket_id, account) pair, whether the ticket exists, is owned by the
+		/// account and is still usable (not redeemed or expired). Results are positional,
+		/// one per pair; only the first `MaxOwnershipChecks` pairs are checked and any pair
+		/// beyond them is reported as `false`.
+		pub fn verify_ticket_ownership(pairs: Vec<(u128, T::AccountId)>) -> Vec<bool> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let max_checks = T::MaxOwnershipChecks::get() as usize;
+			pairs
+				.into_iter()
+				.enumerate()
+				.map(|(index, (ticket_id, account))| {
+					if index >= max_checks {
+						return false;
+					}
+					Tickets::<T>::get(ticket_id)
+						.filter(|ticket| ticket.owner == account && !ticket.is_redeemed)
+						.is_some_and(|ticket| {
+							ticket.expires_at.is_none_or(|expires_at| current_block < expires_at)
+						})
+				})
+				.collect()
+		}
+
+		/// The status of each ticket in `ticket_ids`, positionally, for gates scanning a
+		/// group of tickets at once. Only the first `MaxTicketStatusQueries` IDs are looked
+		/// up; every ID beyond them is reported as `NotChecked`.
+		pub fn ticket_statuses(ticket_ids: Vec<u128>) -> Vec<TicketStatus> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let max_queries = T::MaxTicketStatusQueries::get() as usize;
+			ticket_ids
+				.into_iter()
+				.enumerate()
+				.map(|(index, ticket_id)| {
+					if index >= max_queries {
+						return TicketStatus::NotChecked;
+					}
+					let Some(ticket) = Tickets::<T>::get(ticket_id) else {
+						return TicketStatus::NotFound;
+					};
+					let expired =
+						ticket.expires_at.is_some_and(|expires_at| current_block >= expires_at);
+					if ticket.is_redeemed {
+						TicketStatus::Redeemed
+					} else if expired {
+						TicketStatus::Expired
+					} else {
+						TicketStatus::Valid
+					}
+				})
+				.collect()
+		}
+
+		/// A ticket's ownership history as (owner, since-block), oldest first, starting with
+		/// the minting owner unless it was pruned by `MaxProvenanceEntries`. Empty for unknown
+		/// or burned tickets.
+		pub fn get_ticket_provenance(ticket_id: u128) -> Vec<(T::AccountId, BlockNumberFor<T>)> {
+			TicketProvenance::<T>::get(ticket_id).into_inner()
+		}
+	}
+
+	// ============================================================================
+	// INTERNAL HELPER FUNCTIONS
+	// ============================================================================
+
+	impl<T: Config> Pallet<T> {
+		/// Check if an account is the admin
+		pub fn is_admin(account: &T::AccountId) -> bool {
+			Admin::<T>::get().as_ref().is_some_and(|admin| admin == account)
+		}
+
+		/// Ensure the caller is the admin
+		fn ensure_admin(account: &T::AccountId) -> DispatchResult {
+			ensure!(Self::is_admin(account), Error::<T>::NotAdmin);
+			Ok(())
+		}
+
+		/// Ensure adding a batch of `travel_type` keeps the user within
+		/// `MaxTravelTypesPerUser` distinct travel types.
+		fn ensure_travel_type_allowed(
+			batches: &[PointBatch<BlockNumberFor<T>>],
+			travel_type: &TravelType,
+		) -> DispatchResult {
+			if batches.iter().any(|b| &b.travel_type == travel_type) {
+				return Ok(());
+			}
+
+			let mut distinct: Vec<&TravelType> = Vec::new();
+			for batch in batches.iter() {
+				if !distinct.contains(&&batch.travel_type) {
+					distinct.push(&batch.travel_type);
+				}
+			}
+
+			ensure!(
+				(distinct.len() as u32) < T::MaxTravelTypesPerUser::get(),
+				Error::<T>::TooManyTravelTypes
+			);
+			Ok(())
+		}
+
+		/// Merge batches that share a travel type and expiry block. With demurrage enabled,
+		/// only batches charged up to the same `last_demurrage_block` merge, so no demurrage
+		/// still owed is lost. The merged batch keeps the latest `earned_at_block`, so its
+		/// points are only spendable once all of them have passed `MinHoldingPeriod`. Expiry
+		/// and FIFO order are unchanged. Emits `PointBatchesCompacted` if any batches were
+		/// merged.
+		fn compact_point_batches(
+			user: &T::AccountId,
+			batches: &mut BoundedVec<PointBatch<BlockNumberFor<T>>, T::MaxPointBatches>,
+		) {
+			let batches_before = batches.len() as u32;
+			let mut merged: Vec<PointBatch<BlockNumberFor<T>>> = Vec::with_capacity(batches.len());
+			for batch in core::mem::take(batches).into_inner() {
+				match merged.iter_mut().find(|m| Self::batches_mergeable(m, &batch)) {
+					Some(existing) => Self::merge_batch(existing, &batch),
+					None => merged.push(batch),
+				}
+			}
+			// Merging never grows the list, so this cannot truncate
+			*batches = BoundedVec::truncate_from(merged);
+
+			let batches_after = batches.len() as u32;
+			if batches_after < batches_before {
+				Self::deposit_event(Event::PointBatchesCompacted {
+					user: user.clone(),
+					batches_before,
+					batches_after,
+				});
+			}
+		}
+
+		/// Fold `batch` into `existing`, a batch `batches_mergeable` accepted. The merged batch
+		/// keeps the latest `earned_at_block` and `last_demurrage_block`.
+		fn merge_batch(
+			existing: &mut PointBatch<BlockNumberFor<T>>,
+			batch: &PointBatch<BlockNumberFor<T>>,
+		) {
+			existing.remaining_points =
+				existing.remaining_points.saturating_add(batch.remaining_points);
+			existing.earned_at_block = existing.earned_at_block.max(batch.earned_at_block);
+			existing.last_demurrage_block =
+				existing.last_demurrage_block.max(batch.last_demurrage_block);
+			existing.expiring_per_step =
+				existing.expiring_per_step.saturating_add(batch.expiring_per_step);
+		}
+
+		/// Whether two batches can be merged into one: same expiry block and travel type and,
+		/// while demurrage is enabled, charged up to the same block
+		fn batches_mergeable(
+			a: &PointBatch<BlockNumberFor<T>>,
+			b: &PointBatch<BlockNumberFor<T>>,
+		) -> bool {
+			a.expires_at_block == b.expires_at_block
+				&& a.travel_type == b.travel_type
+				&& (T::DemurrageRate::get().is_zero()
+					|| a.last_demurrage_block == b.last_demurrage_block)
+		}
+
+		/// The batch as it stands at `current_block` once expiry has been applied, or `None`
+		/// if it has fully expired.
+		///
+		/// Without partial expiry, a batch expires whole at `expires_at_block`. With it, every
+		/// `PartialExpiryInterval` blocks from `expires_at_block` on removes another
+		/// `expiring_per_step` points, fixed at the first step as `PartialExpiryPercent` of the
+		/// batch (rounded up, so an untouched batch is gone after a whole number of steps).
+		/// Steps that were missed are applied at once.
+		fn batch_after_expiry(
+			batch: &PointBatch<BlockNumberFor<T>>,
+			current_block: BlockNumberFor<T>,
+		) -> Option<PointBatch<BlockNumberFor<T>>> {
+			if batch.expires_at_block > current_block {
+				return Some(batch.clone());
+			}
+
+			let percent = T::PartialExpiryPercent::get() as u128;
+			let interval = T::PartialExpiryInterval::get();
+			if percent.is_zero() || interval.is_zero() {
+				return None;
+			}
+
+			let mut batch = batch.clone();
+			if batch.expiring_per_step.is_zero() {
+				batch.expiring_per_step =
+					batch.remaining_points.saturating_mul(percent).div_ceil(10_000).max(1);
+			}
 
-	impl<T: Config> Pallet<T> {
-		/// Check if an account is the admin
-		pub fn is_admin(account: &T::AccountId) -> bool {
-			Admin::<T>::get().as_ref().is_some_and(|admin| admin == account)
-		}
+			// The step at `expires_at_block` itself, plus one per full interval since
+			let first_step: BlockNumberFor<T> = 1u32.into();
+			let steps = (current_block.saturating_sub(batch.expires_at_block) / interval)
+				.saturating_add(first_step);
+			let expired = batch.expiring_per_step.saturating_mul(steps.saturated_into());
+			if expired >= batch.remaining_points {
+				return None;
+			}
 
-		/// Ensure the caller is the admin
-		fn ensure_admin(account: &T::AccountId) -> DispatchResult {
-			ensure!(Self::is_admin(account), Error::<T>::NotAdmin);
-			Ok(())
+			batch.remaining_points = batch.remaining_points.saturating_sub(expired);
+			batch.expires_at_block =
+				batch.expires_at_block.saturating_add(interval.saturating_mul(steps));
+			Some(batch)
 		}
 
-		/// Remove expired batches from a user's batch list.
+		/// Remove expired batches from a user's batch list, or step them down under
+		/// `PartialExpiryPercent` (see `batch_after_expiry`).
 		/// This updates both the batch list and the total points.
 		/// Returns the amount of points that expired.
 		fn remove_expired_batches_internal(
@@ -2317,24 +6311,29 @@ pub mod pallet {
 			batches: &mut BoundedVec<PointBatch<BlockNumberFor<T>>, T::MaxPointBatches>,
 			current_block: BlockNumberFor<T>,
 		) -> u128 {
-			// Calculate how many points are expiring
-			let expired_amount: u128 = batches
-				.iter()
-				.filter(|b| b.expires_at_block <= current_block)
-				.map(|b| b.remaining_points)
-				.sum();
-
+			let points_before: u128 = batches.iter().map(|b| b.remaining_points).sum();
 			let batches_before = batches.len();
 
-			// Remove expired batches
-			batches.retain(|batch| batch.expires_at_block > current_block);
+			// Drop fully expired batches and step down partially expiring ones
+			let kept: Vec<PointBatch<BlockNumberFor<T>>> = batches
+				.iter()
+				.filter_map(|b| Self::batch_after_expiry(b, current_block))
+				.collect();
+			// Expiry never adds batches, so this cannot truncate
+			*batches = BoundedVec::truncate_from(kept);
+			// Stepped batches move their expiry out, so restore the FIFO order
+			batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+
+			let points_after: u128 = batches.iter().map(|b| b.remaining_points).sum();
+			let expired_amount = points_before.saturating_sub(points_after);
 
 			let batches_removed = (batches_before - batches.len()) as u32;
 
 			// Update total points if any expired
 			if expired_amount > 0 {
-				TotalPoints::<T>::mutate(user, |total| {
+				let remaining = TotalPoints::<T>::mutate(user, |total| {
 					*total = total.saturating_sub(expired_amount);
+					*total
 				});
 
 				// Emit event
@@ -2343,6 +6342,10 @@ pub mod pallet {
 					amount_expired: expired_amount,
 					batches_removed,
 				});
+
+				if remaining.is_zero() {
+					Self::deposit_event(Event::BalanceEmptied { user: user.clone() });
+				}
 			}
 
 			expired_amount
@@ -2354,11 +6357,21 @@ pub mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number();
 			UserPoints::<T>::get(user)
 				.iter()
-				.filter(|b| b.expires_at_block > current_block)
+				.filter_map(|b| Self::batch_after_expiry(b, current_block))
 				.map(|b| b.remaining_points)
 				.sum()
 		}
 
+		/// Available points of a user, served from `AvailableCache` when the snapshot was
+		/// taken in the current block and recalculated from the batches otherwise
+		pub fn cached_available_points(user: &T::AccountId) -> u128 {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			match AvailableCache::<T>::get(user) {
+				Some((block, available)) if block == current_block => available,
+				_ => Self::get_available_points(user),
+			}
+		}
+
 		/// Get detailed point information for a user.
 		/// Returns a vector of (remaining_points, expires_at_block, travel_type) tuples.
 		pub fn get_point_details(
@@ -2367,47 +6380,361 @@ pub mod pallet {
 			let current_block = frame_system::Pallet::<T>::block_number();
 			UserPoints::<T>::get(user)
 				.iter()
-				.filter(|b| b.expires_at_block > current_block)
-				.map(|b| (b.remaining_points, b.expires_at_block, b.travel_type.clone()))
+				.filter_map(|b| Self::batch_after_expiry(b, current_block))
+				.map(|b| (b.remaining_points, b.expires_at_block, b.travel_type))
 				.collect()
 		}
 
+		/// Access-controlled variant of `get_point_details` for privacy-conscious deployments.
+		/// Only `user` themselves or an authorized issuer may read the details.
+		///
+		/// ## Errors
+		/// - `PointDetailsNotVisible` if `caller` is neither `user` nor an authorized issuer
+		pub fn get_point_details_authorized(
+			caller: &T::AccountId,
+			user: &T::AccountId,
+		) -> Result<Vec<(u128, BlockNumberFor<T>, TravelType)>, DispatchError> {
+			ensure!(
+				caller == user || AuthorizedIssuers::<T>::get(caller),
+				Error::<T>::PointDetailsNotVisible
+			);
+			Ok(Self::get_point_details(user))
+		}
+
+		/// Move a ticket from `from` to `to`, updating both owners' ticket lists.
+		/// Shared by `transfer_ticket` and `transfer_tickets_batch`.
+		fn do_transfer_ticket(
+			ticket_id: u128,
+			from: &T::AccountId,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			ensure!(AcceptsGifts::<T>::get(to), Error::<T>::RecipientRejectsGifts);
+
+			Tickets::<T>::try_mutate(ticket_id, |maybe_ticket| -> DispatchResult {
+				let ticket = maybe_ticket.as_mut().ok_or(Error::<T>::TicketNotFound)?;
+				ensure!(ticket.owner == *from, Error::<T>::NotTicketOwner);
+				ensure!(!ticket.is_redeemed, Error::<T>::TicketAlreadyRedeemed);
+
+				ticket.owner = to.clone();
+				Ok(())
+			})?;
+
+			// Update user ticket lists
+			UserTickets::<T>::mutate(from, |tickets| {
+				tickets.retain(|&id| id != ticket_id);
+			});
+
+			UserTickets::<T>::try_mutate(to, |tickets| -> DispatchResult {
+				tickets.try_push(ticket_id).map_err(|_| Error::<T>::TooManyTickets)?;
+				Ok(())
+			})?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			LastActivity::<T>::insert(from, current_block);
+			LastActivity::<T>::insert(to, current_block);
+			Self::record_ticket_owner(ticket_id, to, current_block);
+
+			Self::deposit_event(Event::TicketTransferred {
+				ticket_id,
+				from: from.clone(),
+				to: to.clone(),
+			});
+
+			Ok(())
+		}
+
+		/// Append `owner` to a ticket's provenance, dropping the oldest entry when full.
+		fn record_ticket_owner(ticket_id: u128, owner: &T::AccountId, since: BlockNumberFor<T>) {
+			TicketProvenance::<T>::mutate(ticket_id, |history| {
+				if history.is_full() && !history.is_empty() {
+					history.remove(0);
+				}
+				// Only fails if `MaxProvenanceEntries` is zero
+				let _ = history.try_push((owner.clone(), since));
+			});
+		}
+
+		/// Burn `TicketTransferFee` points per transferred ticket from the sender. The fee is
+		/// not a spend at any issuer, so it is left out of issuer tracking.
+		fn burn_ticket_transfer_fee(from: &T::AccountId, ticket_count: u32) -> DispatchResult {
+			let fee = T::TicketTransferFee::get().saturating_mul(ticket_count as u128);
+			if fee.is_zero() {
+				return Ok(());
+			}
+
+			Self::with_reentrancy_guard(|| Self::deduct_points_fifo(from, fee, None))?;
+
+			Self::deposit_event(Event::TicketTransferFeeBurned { account: from.clone(), fee });
+
+			Ok(())
+		}
+
 		/// Get the current reward period number based on block number.
 		/// Periods are used for tracking issuer rewards and staker distributions.
 		///
 		/// Note: If BlocksPerRewardPeriod is configured as zero, falls back to
 		/// using the current block number as the period (each block is its own period).
-		/// This should be avoided in production configurations.
+		/// This should be avoided in production configurations; `integrity_test` rejects it.
 		pub fn current_period() -> BlockNumberFor<T> {
+			Self::period_for_block(frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Blended score an issuer's period record earns towards the issuer reward share:
+		/// points spent and transaction count, weighted by the `IssuerReward*` config
+		fn issuer_reward_score(record: &IssuerDailyRecord) -> u128 {
+			let transaction_points = (record.transaction_count as u128)
+				.saturating_mul(T::IssuerRewardTransactionScale::get());
+			record
+				.points_spent
+				.saturating_mul(T::IssuerRewardSpendWeight::get() as u128)
+				.saturating_add(
+					transaction_points
+						.saturating_mul(T::IssuerRewardTransactionWeight::get() as u128),
+				)
+		}
+
+		/// Part of `reward_pool` that `distribute_rewards` splits among stakers. The issuer
+		/// share rounds down, so the remainder goes to stakers and the pool is split exactly.
+		fn staker_reward_share(reward_pool: u128) -> u128 {
+			let issuer_share = reward_pool
+				.saturating_mul(T::IssuerRewardPercent::get() as u128)
+				.saturating_div(10_000);
+			reward_pool.saturating_sub(issuer_share)
+		}
+
+		/// Stakers earning a part of a distribution for `period` with their stake amounts, and
+		/// the total stake the staker share is split over. Only the active set earns, so
+		/// waitlisted and evicted stakers are left out. Stakes younger than
+		/// `StakeRewardDelay` are left out, and so are stakers that did not report for
+		/// `period` under `RequireVerificationForReward`. Their amount is left out with them, so the
+		/// eligible stakes share their part.
+		fn reward_eligible_stakes(period: BlockNumberFor<T>) -> (Vec<(T::AccountId, u128)>, u128) {
+			let eligible_before = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(T::StakeRewardDelay::get());
+			let require_verification = T::RequireVerificationForReward::get();
+			// Only the period's verifiers can report for it
+			let verified =
+				|staker: &T::AccountId| VerificationReports::<T>::contains_key(period, staker);
+			let stakers = StakerList::<T>::get();
+			let (eligible, ineligible): (Vec<_>, Vec<_>) = EnhancedStakes::<T>::iter()
+				.filter(|(_, info)| info.active > 0)
+				.partition(|(staker, info)| {
+					stakers.contains(staker)
+						&& info.staked_at <= eligible_before
+						&& (!require_verification || verified(staker))
+				});
+			let ineligible_stake: u128 = ineligible.iter().map(|(_, info)| info.active).sum();
+			let total_eligible = TotalStaked::<T>::get().saturating_sub(ineligible_stake);
+			let eligible =
+				eligible.into_iter().map(|(staker, info)| (staker, info.active)).collect();
+			(eligible, total_eligible)
+		}
+
+		/// Fail with `RewardPeriodMisconfigured` if `BlocksPerRewardPeriod` is zero, so that
+		/// period-based reward calls refuse to run on per-block periods
+		fn ensure_reward_period_configured() -> DispatchResult {
+			ensure!(
+				!T::BlocksPerRewardPeriod::get().is_zero(),
+				Error::<T>::RewardPeriodMisconfigured
+			);
+			Ok(())
+		}
+
+		/// Internal function to award points (shared by `award_points` and the
+		/// `contract_award_points*` functions). Runs under the re-entrancy guard.
+		/// Returns the number of batches the recipient held before the award and the outcome.
+		fn award_points_internal(
+			issuer: T::AccountId,
+			recipient: T::AccountId,
+			amount: u128,
+			travel_type: TravelType,
+			custom_expiration: Option<BlockNumberFor<T>>,
+		) -> Result<(u32, AwardOutcome<BlockNumberFor<T>>), DispatchError> {
+			Self::with_reentrancy_guard(|| {
+				ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
+
+				// Amount must be greater than zero and at least the minimum award
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+				ensure!(amount >= T::MinAwardAmount::get(), Error::<T>::AwardBelowMinimum);
+				// A zero period would create a batch that is already expired
+				ensure!(
+					custom_expiration.is_none_or(|period| !period.is_zero()),
+					Error::<T>::InvalidExpiration
+				);
+
+				// Apply the travel type's award multiplier and expiry, if configured
+				let amount = match TravelTypeMultiplier::<T>::get(&travel_type) {
+					// Rounds to nearest so the user does not lose a point to truncation
+					Some(multiplier) => {
+						div_round_nearest(amount.saturating_mul(multiplier as u128), 10_000)
+					},
+					None => amount,
+				};
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+				let mut custom_expiration =
+					custom_expiration.or_else(|| TravelTypeExpiry::<T>::get(&travel_type));
+				// The new batch gets at least the expiry the reset gives the others
+				if Self::reset_expiry_on_activity(&recipient) {
+					custom_expiration = custom_expiration
+						.map(|period| period.max(T::DefaultExpirationPeriod::get()));
+				}
+
+				IssuerLastActivePeriod::<T>::insert(&issuer, Self::current_period());
+				let emit_event = !SuppressEvents::<T>::get(&issuer);
+				Self::credit_point_batch(
+					recipient,
+					amount,
+					travel_type,
+					custom_expiration,
+					emit_event,
+				)
+			})
+		}
+
+		/// Add a new point batch to `recipient` and, if `emit_event` is set, emit
+		/// `PointsEarned`. Performs no authorization or minimum amount checks; callers are
+		/// responsible for those. Returns the number of batches the recipient held before the
+		/// award, all of which cleanup, compaction and sorting walk over, and the outcome of
+		/// the award.
+		fn credit_point_batch(
+			recipient: T::AccountId,
+			amount: u128,
+			travel_type: TravelType,
+			custom_expiration: Option<BlockNumberFor<T>>,
+			emit_event: bool,
+		) -> Result<(u32, AwardOutcome<BlockNumberFor<T>>), DispatchError> {
+			// Get current block number
 			let current_block = frame_system::Pallet::<T>::block_number();
-			let blocks_per_period = T::BlocksPerRewardPeriod::get();
-			if blocks_per_period.is_zero() {
-				// Fallback: treat each block as its own period
-				// This is not recommended for production use
-				return current_block;
+
+			// Calculate expiration block
+			let expiration_period = custom_expiration.unwrap_or(T::DefaultExpirationPeriod::get());
+			let expires_at_block = current_block.saturating_add(expiration_period);
+
+			// Create the new point batch
+			let new_batch = PointBatch {
+				earned_at_block: current_block,
+				expires_at_block,
+				remaining_points: amount,
+				travel_type: travel_type.clone(),
+				last_demurrage_block: current_block,
+				expiring_per_step: 0,
+			};
+
+			// Add the batch to the user's batches
+			let mut batch_count: u32 = 0;
+			let batches_held = UserPoints::<T>::try_mutate(&recipient, |batches| {
+				// First, clean up any expired batches to make room
+				let batches_held = batches.len() as u32;
+				Self::remove_expired_batches_internal(&recipient, batches, current_block);
+
+				// Reject a batch that would introduce one travel type too many
+				Self::ensure_travel_type_allowed(batches, &travel_type)?;
+
+				// Merge batches before the user runs into `MaxPointBatches`
+				let used = (batches.len() as u128).saturating_mul(10_000);
+				let max_batches = T::MaxPointBatches::get() as u128;
+				if used >= max_batches.saturating_mul(T::BatchCompactionThreshold::get().into()) {
+					Self::compact_point_batches(&recipient, batches);
+				}
+
+				// Try to add the new batch
+				batches.try_push(new_batch).map_err(|_| Error::<T>::TooManyBatches)?;
+
+				// Sort batches by expiration date (oldest first) for FIFO deduction
+				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+
+				batch_count = batches.len() as u32;
+				Ok::<_, DispatchError>(batches_held)
+			})?;
+
+			// Update total points balance
+			let new_total_balance =
+				TotalPoints::<T>::try_mutate(&recipient, |total| -> Result<u128, DispatchError> {
+					*total = total.checked_add(amount).ok_or(Error::<T>::PointBalanceOverflow)?;
+					Ok(*total)
+				})?;
+			LastActivity::<T>::insert(&recipient, current_block);
+
+			// Emit event
+			if emit_event {
+				Self::deposit_event(Event::PointsEarned {
+					recipient,
+					amount,
+					expires_at_block,
+					travel_type,
+				});
 			}
-			current_block / blocks_per_period
+
+			Ok((
+				batches_held,
+				AwardOutcome { expires_at_block, new_total_balance, batch_count, amount },
+			))
 		}
 
-		/// Internal function to spend points (used by mint_ticket and other internal operations)
-		/// This tracks spending for issuer reward distribution
-		fn spend_points_internal(
+		/// Run `f` while holding the re-entrancy lock shared by the award and spend paths.
+		/// A nested award or spend (e.g. from a contract or hook callback) fails with
+		/// `ReentrancyDetected`. The lock is always released, whatever `f` returns. The lock
+		/// is kept in storage, so every guarded call's weight includes its read and write.
+		fn with_reentrancy_guard<R>(
+			f: impl FnOnce() -> Result<R, DispatchError>,
+		) -> Result<R, DispatchError> {
+			ensure!(!ReentrancyLock::<T>::get(), Error::<T>::ReentrancyDetected);
+
+			ReentrancyLock::<T>::put(true);
+			let result = f();
+			ReentrancyLock::<T>::kill();
+
+			result
+		}
+
+		/// Deduct `amount` from the user's live batches (only those of `travel_type`, if set),
+		/// oldest first, skipping batches still within `MinHoldingPeriod` and enforcing
+		/// `MinRetainedPoints` on the whole balance. Returns the new balance and the part taken
+		/// from every consumed batch, as a copy of the batch holding the points taken. Does no
+		/// issuer tracking and emits no spend events, only `BalanceEmptied` if the balance
+		/// reaches zero.
+		///
+		/// Expired batches are pruned first, but a failed spend does not keep that cleanup:
+		/// dispatchables run in a storage transaction, so every write of the failing call is
+		/// rolled back, even one made in a separate `mutate`. `cleanup_expired` reclaims them.
+		fn deduct_points_fifo(
 			user: &T::AccountId,
 			amount: u128,
-			issuer: &T::AccountId,
-		) -> DispatchResult {
+			travel_type: Option<&TravelType>,
+		) -> Result<(u128, Vec<PointBatch<BlockNumberFor<T>>>), DispatchError> {
 			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let mut remaining_to_spend = amount;
+			let mut consumed = Vec::new();
 
 			UserPoints::<T>::try_mutate(user, |batches| -> DispatchResult {
 				Self::remove_expired_batches_internal(user, batches, current_block);
 
+				// Batches earned less than `MinHoldingPeriod` ago are held but not spendable
+				let seasoned_before = current_block.saturating_sub(T::MinHoldingPeriod::get());
+				let matches = |b: &PointBatch<BlockNumberFor<T>>| {
+					travel_type.is_none_or(|t| &b.travel_type == t)
+				};
+				let spendable = |b: &PointBatch<BlockNumberFor<T>>| {
+					matches(b) && b.earned_at_block <= seasoned_before
+				};
 				let available: u128 = batches.iter().map(|b| b.remaining_points).sum();
-				ensure!(available >= amount, Error::<T>::InsufficientPoints);
-
-				for batch in batches.iter_mut() {
+				let held: u128 =
+					batches.iter().filter(|b| matches(b)).map(|b| b.remaining_points).sum();
+				ensure!(held >= amount, Error::<T>::InsufficientPoints);
+				let seasoned: u128 =
+					batches.iter().filter(|b| spendable(b)).map(|b| b.remaining_points).sum();
+				ensure!(seasoned >= amount, Error::<T>::PointsNotYetSpendable);
+
+				// Either spend everything or keep at least the minimum retained balance
+				let left_over = available.saturating_sub(amount);
+				ensure!(
+					left_over.is_zero() || left_over >= T::MinRetainedPoints::get(),
+					Error::<T>::WouldLeaveDust
+				);
+
+				for batch in batches.iter_mut().filter(|b| spendable(b)) {
 					if remaining_to_spend == 0 {
 						break;
 					}
@@ -2415,10 +6742,11 @@ pub mod pallet {
 					batch.remaining_points = batch
 						.remaining_points
 						.checked_sub(deduction)
-						.ok_or(Error::<T>::ArithmeticUnderflow)?;
+						.ok_or(Error::<T>::BatchPointsUnderflow)?;
 					remaining_to_spend = remaining_to_spend
 						.checked_sub(deduction)
-						.ok_or(Error::<T>::ArithmeticUnderflow)?;
+						.ok_or(Error::<T>::BatchPointsUnderflow)?;
+					consumed.push(PointBatch { remaining_points: deduction, ..batch.clone() });
 				}
 
 				batches.retain(|b| b.remaining_points > 0);
@@ -2427,30 +6755,215 @@ pub mod pallet {
 
 			let new_balance =
 				TotalPoints::<T>::try_mutate(user, |total| -> Result<u128, DispatchError> {
-					*total = total.checked_sub(amount).ok_or(Error::<T>::ArithmeticUnderflow)?;
+					*total = total.checked_sub(amount).ok_or(Error::<T>::PointBalanceUnderflow)?;
 					Ok(*total)
 				})?;
 
-			// Track spending for issuer reward distribution
-			let period = Self::current_period();
-			IssuerDailyRecords::<T>::mutate(period, issuer, |record| {
-				record.points_spent = record.points_spent.saturating_add(amount);
-				record.transaction_count = record.transaction_count.saturating_add(1);
-			});
-			PeriodTotalSpent::<T>::mutate(period, |total| {
-				*total = total.saturating_add(amount);
-			});
+			// `amount` is non-zero, so a zero balance was only just reached
+			if new_balance.is_zero() {
+				Self::deposit_event(Event::BalanceEmptied { user: user.clone() });
+			}
 
-			Self::deposit_event(Event::PointsSpent {
+			Ok((new_balance, consumed))
+		}
+
+		/// Internal function to spend points (used by spend_points, mint_ticket and other
+		/// internal operations). Unless the issuer is in `UntrackedSpends`, this tracks
+		/// spending for issuer reward distribution. Enforces `MinRetainedPoints`. With
+		/// `travel_type` set, only batches of that type are spent.
+		fn spend_points_internal(
+			user: &T::AccountId,
+			amount: u128,
+			issuer: &T::AccountId,
+			travel_type: Option<&TravelType>,
+		) -> DispatchResult {
+			Self::with_reentrancy_guard(|| {
+				Self::ensure_issuer_slot(user, issuer)?;
+				Self::ensure_user_spend_cap(user, amount)?;
+				let (new_balance, consumed) = Self::deduct_points_fifo(user, amount, travel_type)?;
+				Self::record_spend(user, amount, issuer, new_balance, &consumed);
+				Ok(())
+			})
+		}
+
+		/// Put the points of a point lock back into the user's batches, merging each part into
+		/// the batch it was taken from if that is still there, and remove the lock.
+		fn return_locked_points(
+			user: &T::AccountId,
+			lock_id: u64,
+			lock: PointLock<
+				BlockNumberFor<T>,
+				BoundedVec<PointBatch<BlockNumberFor<T>>, T::MaxPointBatches>,
+			>,
+			timed_out: bool,
+		) -> DispatchResult {
+			UserPoints::<T>::try_mutate(user, |batches| -> DispatchResult {
+				for piece in lock.batches.into_iter() {
+					let existing = batches
+						.iter_mut()
+						.filter(|b| b.expires_at_block == piece.expires_at_block)
+						.filter(|b| b.earned_at_block == piece.earned_at_block)
+						.find(|b| b.travel_type == piece.travel_type);
+					if let Some(existing) = existing {
+						existing.remaining_points = existing
+							.remaining_points
+							.checked_add(piece.remaining_points)
+							.ok_or(Error::<T>::BatchPointsOverflow)?;
+					} else {
+						batches.try_push(piece).map_err(|_| Error::<T>::TooManyBatches)?;
+					}
+				}
+
+				// Keep batches sorted by expiration date for FIFO deduction
+				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+				Ok(())
+			})?;
+
+			TotalPoints::<T>::try_mutate(user, |total| -> DispatchResult {
+				*total = total.checked_add(lock.amount).ok_or(Error::<T>::PointBalanceOverflow)?;
+				Ok(())
+			})?;
+
+			LockedPoints::<T>::remove(user, lock_id);
+			PointLockCount::<T>::mutate(user, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::PointLockReleased {
 				user: user.clone(),
-				amount_spent: amount,
-				remaining_balance: new_balance,
-				issuer: issuer.clone(),
+				lock_id,
+				amount: lock.amount,
+				timed_out,
 			});
+			Ok(())
+		}
+
+		/// Release the user's point locks that passed `LockTimeout` by `current_block`.
+		/// A lock whose points do not fit back into the user's batches stays in place.
+		/// Returns the number of locks read.
+		fn release_timed_out_locks(user: &T::AccountId, current_block: BlockNumberFor<T>) -> u32 {
+			let mut locks_read: u32 = 0;
+			let timed_out: Vec<_> = LockedPoints::<T>::iter_prefix(user)
+				.take(T::MaxPointLocks::get() as usize)
+				.inspect(|_| locks_read = locks_read.saturating_add(1))
+				.filter(|(_, lock)| lock.expires_at <= current_block)
+				.collect();
+
+			for (lock_id, lock) in timed_out {
+				let _ = Self::return_locked_points(user, lock_id, lock, true);
+			}
+			locks_read
+		}
+
+		/// Check `MaxIssuersPerUserPerPeriod` before a spend at `issuer`. A first spend at an
+		/// issuer in the period takes one of the user's issuer slots.
+		fn ensure_issuer_slot(user: &T::AccountId, issuer: &T::AccountId) -> DispatchResult {
+			let period = Self::current_period();
+			if UserIssuerSpend::<T>::get((period, user, issuer)).is_zero() {
+				let issuers = UserPeriodIssuerCount::<T>::get(period, user);
+				ensure!(
+					issuers < T::MaxIssuersPerUserPerPeriod::get(),
+					Error::<T>::TooManyIssuersThisPeriod
+				);
+				UserPeriodIssuerCount::<T>::insert(period, user, issuers.saturating_add(1));
+			}
+			Ok(())
+		}
 
+		/// Check `MaxUserSpendPerPeriod` before a spend of `amount` points and count the spend
+		/// towards the user's total for the period
+		fn ensure_user_spend_cap(user: &T::AccountId, amount: u128) -> DispatchResult {
+			let cap = T::MaxUserSpendPerPeriod::get();
+			// Nothing to track while the cap is disabled
+			if cap.is_zero() {
+				return Ok(());
+			}
+
+			let period = Self::current_period();
+			let spent = UserPeriodSpend::<T>::get(period, user).saturating_add(amount);
+			ensure!(spent <= cap, Error::<T>::UserSpendCapExceeded);
+			UserPeriodSpend::<T>::insert(period, user, spent);
 			Ok(())
 		}
 
+		/// With `ResetExpiryOnActivity`, push the expiry of each of the user's live batches out
+		/// to `DefaultExpirationPeriod` from the current block, keeping later expiries, and
+		/// return `true`. Does nothing and returns `false` otherwise.
+		fn reset_expiry_on_activity(user: &T::AccountId) -> bool {
+			if !T::ResetExpiryOnActivity::get() {
+				return false;
+			}
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let reset_to = current_block.saturating_add(T::DefaultExpirationPeriod::get());
+			UserPoints::<T>::mutate(user, |batches| {
+				for batch in batches.iter_mut().filter(|b| b.expires_at_block > current_block) {
+					batch.expires_at_block = batch.expires_at_block.max(reset_to);
+				}
+				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
+			});
+			true
+		}
+
+		/// Record a spend of `amount` points, already taken from the user's batches, at
+		/// `issuer`: issuer reward tracking (unless the issuer is in `UntrackedSpends`),
+		/// per-issuer spend, activity, the spend breakdown and the spend events.
+		fn record_spend(
+			user: &T::AccountId,
+			amount: u128,
+			issuer: &T::AccountId,
+			new_balance: u128,
+			consumed: &[PointBatch<BlockNumberFor<T>>],
+		) {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let period = Self::current_period();
+
+			// Track spending for issuer reward distribution
+			if !UntrackedSpends::<T>::get(issuer) {
+				IssuerDailyRecords::<T>::mutate(period, issuer, |record| {
+					record.points_spent = record.points_spent.saturating_add(amount);
+					record.transaction_count = record.transaction_count.saturating_add(1);
+				});
+				PeriodTotalSpent::<T>::mutate(period, |total| {
+					*total = total.saturating_add(amount);
+				});
+			}
+			UserIssuerSpend::<T>::mutate((period, user, issuer), |spent| {
+				*spent = spent.saturating_add(amount);
+			});
+			IssuerPeriodRedeemers::<T>::insert((period, issuer, user), ());
+			IssuerLastActivePeriod::<T>::insert(issuer, period);
+			LastActivity::<T>::insert(user, current_block);
+			Self::reset_expiry_on_activity(user);
+
+			// Routine events are skipped for issuers tracking spends off-chain
+			let emit_events = !SuppressEvents::<T>::get(issuer);
+
+			// Report the consumed batches unless there are too many to list
+			let consumed: Vec<(BlockNumberFor<T>, u128)> =
+				consumed.iter().map(|b| (b.expires_at_block, b.remaining_points)).collect();
+			match BoundedVec::<_, T::MaxSpendBreakdownEntries>::try_from(consumed) {
+				Ok(breakdown) => {
+					LastSpendBreakdown::<T>::insert(user, &breakdown);
+					if emit_events {
+						Self::deposit_event(Event::SpendBreakdown {
+							user: user.clone(),
+							issuer: issuer.clone(),
+							consumed: breakdown.into_inner(),
+						});
+					}
+				},
+				Err(_) => LastSpendBreakdown::<T>::remove(user),
+			}
+
+			if emit_events {
+				Self::deposit_event(Event::PointsSpent {
+					user: user.clone(),
+					amount_spent: amount,
+					remaining_balance: new_balance,
+					issuer: issuer.clone(),
+				});
+			}
+		}
+
 		// ============================================================================
 		// CONTRACT INTERFACE FUNCTIONS
 		// ============================================================================
@@ -2464,41 +6977,22 @@ pub mod pallet {
 			travel_type: TravelType,
 			custom_expiration: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			ensure!(AuthorizedIssuers::<T>::get(&issuer), Error::<T>::NotAuthorizedIssuer);
-
-			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
-
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let expiration_period = custom_expiration.unwrap_or(T::DefaultExpirationPeriod::get());
-			let expires_at_block = current_block.saturating_add(expiration_period);
-
-			let new_batch = PointBatch {
-				earned_at_block: current_block,
-				expires_at_block,
-				remaining_points: amount,
-				travel_type: travel_type.clone(),
-			};
-
-			UserPoints::<T>::try_mutate(&recipient, |batches| -> DispatchResult {
-				Self::remove_expired_batches_internal(&recipient, batches, current_block);
-				batches.try_push(new_batch).map_err(|_| Error::<T>::TooManyBatches)?;
-				batches.sort_by(|a, b| a.expires_at_block.cmp(&b.expires_at_block));
-				Ok(())
-			})?;
-
-			TotalPoints::<T>::try_mutate(&recipient, |total| -> DispatchResult {
-				*total = total.checked_add(amount).ok_or(Error::<T>::ArithmeticOverflow)?;
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::PointsEarned {
-				recipient,
-				amount,
-				expires_at_block,
-				travel_type,
-			});
+			Self::award_points_internal(issuer, recipient, amount, travel_type, custom_expiration)
+				.map(|_| ())
+		}
 
-			Ok(())
+		/// Contract interface: Award points to a user, returning the awarded batch's expiry
+		/// and the user's resulting balance and batch count so the calling contract need not
+		/// query them afterwards. Fails under the same conditions as `contract_award_points`.
+		pub fn contract_award_points_detailed(
+			issuer: T::AccountId,
+			recipient: T::AccountId,
+			amount: u128,
+			travel_type: TravelType,
+			custom_expiration: Option<BlockNumberFor<T>>,
+		) -> Result<AwardOutcome<BlockNumberFor<T>>, DispatchError> {
+			Self::award_points_internal(issuer, recipient, amount, travel_type, custom_expiration)
+				.map(|(_, outcome)| outcome)
 		}
 
 		/// Contract interface: Check balance for a user
@@ -2534,9 +7028,151 @@ pub mod pallet {
 			UserTickets::<T>::get(user).to_vec()
 		}
 
+		/// Get all ticket IDs minted for a coded route.
+		/// Returns an empty list if either code is invalid or no tickets exist.
+		pub fn tickets_on_route(from: Vec<u8>, to: Vec<u8>) -> Vec<u128> {
+			match (Self::to_location_code(from), Self::to_location_code(to)) {
+				(Ok(from), Ok(to)) => TicketsByRoute::<T>::iter_key_prefix((from, to)).collect(),
+				_ => Vec::new(),
+			}
+		}
+
+		/// Mint a ticket to `owner`, deducting `points_cost` from `payer`.
+		/// The caller is responsible for checking that `issuer` is authorized.
+		/// Returns the new ticket ID.
+		fn do_mint_ticket(
+			issuer: T::AccountId,
+			payer: T::AccountId,
+			owner: T::AccountId,
+			ticket_type: TicketType,
+			points_cost: u128,
+			expires_at: Option<BlockNumberFor<T>>,
+			passenger_name: Vec<u8>,
+			travel_number: Vec<u8>,
+			gate: Vec<u8>,
+			seat: Vec<u8>,
+			departure: Vec<u8>,
+			arrival: Vec<u8>,
+			departure_time: Vec<u8>,
+			metadata: Vec<u8>,
+			departure_code: Option<Vec<u8>>,
+			arrival_code: Option<Vec<u8>>,
+			segments: u8,
+			capacity: u32,
+		) -> Result<u128, DispatchError> {
+			ensure!(segments > 0, Error::<T>::InvalidSegmentCount);
+			ensure!(capacity > 0, Error::<T>::InvalidTicketCapacity);
+
+			let period = Self::current_period();
+			let minted = IssuerPeriodTicketCount::<T>::get(period, &issuer);
+			ensure!(
+				minted < T::MaxTicketsPerIssuerPerPeriod::get(),
+				Error::<T>::IssuerTicketCapExceeded
+			);
+			IssuerPeriodTicketCount::<T>::insert(period, &issuer, minted.saturating_add(1));
+
+			let departure_code = departure_code.map(Self::to_location_code).transpose()?;
+			let arrival_code = arrival_code.map(Self::to_location_code).transpose()?;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			// If points_cost > 0, deduct from payer using internal spend
+			if points_cost > 0 {
+				#[cfg(debug_assertions)]
+				let available_before = Self::get_available_points(&payer);
+
+				Self::spend_points_internal(&payer, points_cost, &issuer, None)?;
+
+				// The stored `points_cost` must be exactly what was taken from the live balance
+				#[cfg(debug_assertions)]
+				debug_assert_eq!(
+					Self::get_available_points(&payer),
+					available_before.saturating_sub(points_cost)
+				);
+			}
+
+			// Get and increment ticket ID
+			let ticket_id = NextTicketId::<T>::get();
+			NextTicketId::<T>::put(ticket_id.saturating_add(1));
+
+			// Create the ticket
+			let ticket = Ticket {
+				id: ticket_id,
+				owner: owner.clone(),
+				issuer: issuer.clone(),
+				ticket_type: ticket_type.clone(),
+				created_at: current_block,
+				expires_at,
+				points_cost,
+				is_redeemed: false,
+				passenger_name: BoundedVec::try_from(passenger_name)
+					.map_err(|_| Error::<T>::StringTooLong)?,
+				travel_number: BoundedVec::try_from(travel_number)
+					.map_err(|_| Error::<T>::StringTooLong)?,
+				gate: BoundedVec::try_from(gate).map_err(|_| Error::<T>::StringTooLong)?,
+				seat: BoundedVec::try_from(seat).map_err(|_| Error::<T>::StringTooLong)?,
+				departure: BoundedVec::try_from(departure)
+					.map_err(|_| Error::<T>::StringTooLong)?,
+				arrival: BoundedVec::try_from(arrival).map_err(|_| Error::<T>::StringTooLong)?,
+				departure_time: BoundedVec::try_from(departure_time)
+					.map_err(|_| Error::<T>::StringTooLong)?,
+				metadata: BoundedVec::try_from(metadata).map_err(|_| Error::<T>::StringTooLong)?,
+				departure_code,
+				arrival_code,
+				segments,
+				segments_redeemed: 0,
+				capacity,
+				used: 0,
+			};
+
+			// Index by route when both ends are coded
+			if let (Some(from), Some(to)) = (&ticket.departure_code, &ticket.arrival_code) {
+				TicketsByRoute::<T>::insert((from.clone(), to.clone()), ticket_id, ());
+			}
+
+			// Store the ticket
+			Tickets::<T>::insert(ticket_id, ticket);
+			Self::record_ticket_owner(ticket_id, &owner, current_block);
+
+			// Add to user's ticket list
+			UserTickets::<T>::try_mutate(&owner, |tickets| -> DispatchResult {
+				tickets.try_push(ticket_id).map_err(|_| Error::<T>::TooManyTickets)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TicketMinted {
+				ticket_id,
+				owner,
+				issuer,
+				ticket_type,
+				points_cost,
+			});
+
+			Ok(ticket_id)
+		}
+
+		/// Validate and convert a raw location code.
+		/// Codes must be 1..=MAX_LOCATION_CODE_LEN ASCII alphanumeric bytes.
+		fn to_location_code(code: Vec<u8>) -> Result<LocationCode, DispatchError> {
+			ensure!(
+				!code.is_empty() && code.iter().all(|c| c.is_ascii_alphanumeric()),
+				Error::<T>::InvalidLocationCode
+			);
+			BoundedVec::try_from(code).map_err(|_| Error::<T>::InvalidLocationCode.into())
+		}
+
+		/// Remove a ticket from the route index (no-op for tickets without both codes)
+		fn remove_from_route_index(ticket: &Ticket<T::AccountId, BlockNumberFor<T>>) {
+			if let (Some(from), Some(to)) = (&ticket.departure_code, &ticket.arrival_code) {
+				TicketsByRoute::<T>::remove((from.clone(), to.clone()), ticket.id);
+			}
+		}
+
 		/// Get stake info for a staker
-		pub fn get_stake_info(staker: &T::AccountId) -> Option<StakeInfo<BlockNumberFor<T>>> {
-			Stakes::<T>::get(staker)
+		pub fn get_stake_info(
+			staker: &T::AccountId,
+		) -> Option<EnhancedStakeInfo<BlockNumberFor<T>>> {
+			EnhancedStakes::<T>::get(staker)
 		}
 
 		/// Get list of all stakers
@@ -2544,10 +7180,194 @@ pub mod pallet {
 			StakerList::<T>::get().to_vec()
 		}
 
+		/// Place a staker in the active set.
+		/// If the set is full, the staker displaces the smallest active stake when its own
+		/// stake is strictly larger (the displaced staker is waitlisted); otherwise the
+		/// staker is waitlisted. Once the waitlist is full as well, whoever would be
+		/// waitlisted takes the place of the smallest waitlisted stake if strictly larger,
+		/// which is then evicted: it keeps its stake but earns no rewards until it is placed
+		/// again. A displaced staker that cannot be waitlisted is evicted itself.
+		fn add_to_staker_set(staker: &T::AccountId, amount: u128) -> DispatchResult {
+			let mut stakers = StakerList::<T>::get();
+
+			if stakers.try_push(staker.clone()).is_ok() {
+				StakerList::<T>::put(stakers);
+				return Ok(());
+			}
+
+			// Active set is full: a larger stake takes the smallest active slot
+			let (to_waitlist, waitlisted_amount) = match Self::smallest_stake(&stakers) {
+				Some((index, smallest_amount)) if amount > smallest_amount => {
+					let displaced = core::mem::replace(&mut stakers[index], staker.clone());
+					(displaced, smallest_amount)
+				},
+				_ => (staker.clone(), amount),
+			};
+
+			let mut waitlist = StakerWaitlist::<T>::get();
+			let mut evicted = None;
+			if waitlist.try_push(to_waitlist.clone()).is_err() {
+				match Self::smallest_stake(&waitlist) {
+					Some((index, smallest_amount)) if waitlisted_amount > smallest_amount => {
+						let dropped = core::mem::replace(&mut waitlist[index], to_waitlist.clone());
+						evicted = Some((dropped, smallest_amount));
+					},
+					_ if to_waitlist != *staker => {
+						evicted = Some((to_waitlist.clone(), waitlisted_amount));
+					},
+					_ => return Err(Error::<T>::TooManyStakers.into()),
+				}
+			}
+
+			StakerList::<T>::put(stakers);
+			StakerWaitlist::<T>::put(waitlist);
+
+			if !matches!(&evicted, Some((dropped, _)) if *dropped == to_waitlist) {
+				Self::deposit_event(Event::StakerWaitlisted {
+					staker: to_waitlist,
+					amount: waitlisted_amount,
+				});
+			}
+			if let Some((staker, amount)) = evicted {
+				Self::deposit_event(Event::StakerEvicted { staker, amount });
+			}
+
+			Ok(())
+		}
+
+		/// Remove a staker from the active set or the waitlist.
+		/// If an active slot is freed, the largest waitlisted stake is promoted into it.
+		fn remove_from_staker_set(staker: &T::AccountId) {
+			let mut stakers = StakerList::<T>::get();
+			let before = stakers.len();
+			stakers.retain(|s| s != staker);
+
+			if stakers.len() == before {
+				StakerWaitlist::<T>::mutate(|waitlist| waitlist.retain(|s| s != staker));
+				return;
+			}
+
+			let mut waitlist = StakerWaitlist::<T>::get();
+			if let Some((index, amount)) = Self::largest_stake(&waitlist) {
+				let promoted = waitlist.remove(index);
+				// A slot was just freed, so this push cannot fail
+				if stakers.try_push(promoted.clone()).is_ok() {
+					StakerWaitlist::<T>::put(waitlist);
+					Self::deposit_event(Event::StakerPromoted { staker: promoted, amount });
+				}
+			}
+
+			StakerList::<T>::put(stakers);
+		}
+
+		/// Keep the active set holding the largest stakes after `staker`'s active stake
+		/// changed. An active staker that fell below the largest waitlisted stake swaps
+		/// places with it, a waitlisted staker that outgrew the smallest active stake takes
+		/// its slot, and an evicted staker is placed again if its stake now ranks.
+		fn rebalance_staker_set(staker: &T::AccountId) {
+			let amount = EnhancedStakes::<T>::get(staker).map(|info| info.active).unwrap_or(0);
+			let mut stakers = StakerList::<T>::get();
+			let mut waitlist = StakerWaitlist::<T>::get();
+
+			let active_index = stakers.iter().position(|s| s == staker);
+			let waitlist_index = waitlist.iter().position(|s| s == staker);
+			let (demoted, promoted) = match (active_index, waitlist_index) {
+				(Some(active_index), _) => match Self::largest_stake(&waitlist) {
+					Some((index, largest)) if largest > amount => {
+						let promoted = core::mem::replace(&mut waitlist[index], staker.clone());
+						stakers[active_index] = promoted.clone();
+						((staker.clone(), amount), (promoted, largest))
+					},
+					_ => return,
+				},
+				(None, Some(waitlist_index)) => match Self::smallest_stake(&stakers) {
+					Some((index, smallest)) if amount > smallest => {
+						let demoted = core::mem::replace(&mut stakers[index], staker.clone());
+						waitlist[waitlist_index] = demoted.clone();
+						((demoted, smallest), (staker.clone(), amount))
+					},
+					_ => return,
+				},
+				(None, None) => {
+					// Evicted earlier: stays out of the staker set unless its stake now ranks,
+					// so failing to place it is not an error
+					if !amount.is_zero() {
+						let _ = Self::add_to_staker_set(staker, amount);
+					}
+					return;
+				},
+			};
+
+			StakerList::<T>::put(stakers);
+			StakerWaitlist::<T>::put(waitlist);
+			Self::deposit_event(Event::StakerWaitlisted { staker: demoted.0, amount: demoted.1 });
+			Self::deposit_event(Event::StakerPromoted { staker: promoted.0, amount: promoted.1 });
+		}
+
+		/// Position and active stake of the smallest stake among `stakers`
+		fn smallest_stake(stakers: &[T::AccountId]) -> Option<(usize, u128)> {
+			stakers
+				.iter()
+				.enumerate()
+				.map(|(i, s)| (i, EnhancedStakes::<T>::get(s).map(|info| info.active).unwrap_or(0)))
+				.min_by_key(|(_, stake)| *stake)
+		}
+
+		/// Position and active stake of the largest stake among `stakers`
+		fn largest_stake(stakers: &[T::AccountId]) -> Option<(usize, u128)> {
+			stakers
+				.iter()
+				.enumerate()
+				.map(|(i, s)| (i, EnhancedStakes::<T>::get(s).map(|info| info.active).unwrap_or(0)))
+				.max_by_key(|(_, stake)| *stake)
+		}
+
 		// ============================================================================
 		// ADVANCED STAKING HELPER FUNCTIONS
 		// ============================================================================
 
+		/// Add `amount` to `TotalStaked`, failing with `TotalStakeOverflow` instead of
+		/// saturating
+		fn increase_total_staked(amount: u128) -> DispatchResult {
+			TotalStaked::<T>::try_mutate(|total| -> DispatchResult {
+				*total = total.checked_add(amount).ok_or(Error::<T>::TotalStakeOverflow)?;
+				Ok(())
+			})
+		}
+
+		/// Add `amount` to `RewardPool`, failing with `RewardPoolOverflow` instead of
+		/// saturating
+		fn increase_reward_pool(amount: u128) -> DispatchResult {
+			RewardPool::<T>::try_mutate(|pool| -> DispatchResult {
+				*pool = pool.checked_add(amount).ok_or(Error::<T>::RewardPoolOverflow)?;
+				Ok(())
+			})
+		}
+
+		/// Active stake an account would be selected with as a verifier, or `None` if its
+		/// stake is below `MinStakeAmount` or `MinVerifierStake`, or it has been slashed in the
+		/// current era. Staker set membership is left to the caller, which usually already
+		/// iterates `StakerList`.
+		fn verifier_stake(account: &T::AccountId) -> Option<u128> {
+			let info = EnhancedStakes::<T>::get(account)?;
+
+			if info.active.is_zero() || info.active < T::MinStakeAmount::get() {
+				return None;
+			}
+			if info.active < T::MinVerifierStake::get() {
+				return None;
+			}
+
+			let era_start = LastEraBlock::<T>::get();
+			if SlashRecords::<T>::get(account)
+				.iter()
+				.any(|record| record.slashed_at >= era_start)
+			{
+				return None;
+			}
+			Some(info.active)
+		}
+
 		/// Select verifiers for a new era using stake-weighted selection.
 		/// Uses a deterministic pseudo-random selection based on block hash and stakes.
 		fn select_verifiers_for_era(era: u32) -> Vec<T::AccountId> {
@@ -2563,11 +7383,9 @@ pub mod pallet {
 			let mut total_stake: u128 = 0;
 
 			for staker in stakers.iter() {
-				if let Some(info) = Stakes::<T>::get(staker) {
-					if info.amount > 0 {
-						candidates.push((staker.clone(), info.amount));
-						total_stake = total_stake.saturating_add(info.amount);
-					}
+				if let Some(active) = Self::verifier_stake(staker) {
+					candidates.push((staker.clone(), active));
+					total_stake = total_stake.saturating_add(active);
 				}
 			}
 
@@ -2595,7 +7413,7 @@ pub mod pallet {
 				selected.push(staker.clone());
 
 				// Mark as verifier
-				Stakes::<T>::mutate(staker, |maybe_info| {
+				EnhancedStakes::<T>::mutate(staker, |maybe_info| {
 					if let Some(info) = maybe_info {
 						info.is_verifier = true;
 					}
@@ -2610,7 +7428,7 @@ pub mod pallet {
 
 			// Clear verifier status for non-selected stakers
 			for (staker, _) in candidates.iter().skip(max_verifiers) {
-				Stakes::<T>::mutate(staker, |maybe_info| {
+				EnhancedStakes::<T>::mutate(staker, |maybe_info| {
 					if let Some(info) = maybe_info {
 						info.is_verifier = false;
 					}
@@ -2651,6 +7469,116 @@ pub mod pallet {
 			SlashRecords::<T>::get(account).to_vec()
 		}
 
+		/// Record the staker's current active stake in `StakeHistory`. Several changes in the
+		/// same block collapse into one entry.
+		fn note_stake_change(staker: &T::AccountId) {
+			let amount =
+				EnhancedStakes::<T>::get(staker).map(|info| info.active).unwrap_or_default();
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			StakeHistory::<T>::mutate(staker, |history| match history.last_mut() {
+				Some(last) if last.0 == current_block => last.1 = amount,
+				_ => {
+					if history.try_push((current_block, amount)).is_err() && !history.is_empty() {
+						history.remove(0);
+						let _ = history.try_push((current_block, amount));
+					}
+				},
+			});
+		}
+
+		/// `staked_at` for a stake once `added` joins its active stake in the current block:
+		/// the stake-weighted average of both blocks, rounded up. A topped-up stake then only
+		/// counts as old as its average age towards `StakeRewardDelay`.
+		fn blended_staked_at(
+			info: &EnhancedStakeInfo<BlockNumberFor<T>>,
+			added: u128,
+		) -> BlockNumberFor<T> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let total = info.active.saturating_add(added);
+			if total.is_zero() {
+				return current_block;
+			}
+			let age: u128 = current_block.saturating_sub(info.staked_at).saturated_into();
+			let shift = age.saturating_mul(added).div_ceil(total);
+			info.staked_at.saturating_add(shift.saturated_into())
+		}
+
+		/// Active stake the staker held at `block`, or `None` if `StakeHistory` does not
+		/// reach back that far
+		fn stake_at(staker: &T::AccountId, block: BlockNumberFor<T>) -> Option<u128> {
+			StakeHistory::<T>::get(staker)
+				.iter()
+				.rev()
+				.find(|(changed_at, _)| *changed_at <= block)
+				.map(|(_, amount)| *amount)
+		}
+
+		/// Reduce a staker's `BondedStake` by `amount` that left staking (withdrawn, returned
+		/// instantly or slashed), dropping the entry once nothing remains bonded
+		fn release_bonded_stake(staker: &T::AccountId, amount: u128) {
+			BondedStake::<T>::mutate_exists(staker, |bonded| {
+				if let Some(remaining) = bonded {
+					*remaining = remaining.saturating_sub(amount);
+					if remaining.is_zero() {
+						*bonded = None;
+					}
+				}
+			});
+		}
+
+		/// Remove an account's matured unbonding requests and return the total amount released.
+		/// Cleans up the staker entirely once no stake and no unbonding requests remain.
+		fn withdraw_matured_unbonding(staker: &T::AccountId) -> Result<u128, DispatchError> {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut total_withdrawn: u128 = 0;
+
+			UnbondingRequests::<T>::try_mutate(staker, |requests| -> DispatchResult {
+				ensure!(!requests.is_empty(), Error::<T>::NoUnbondingRequests);
+
+				// Process all completed unbonding requests
+				let mut remaining = Vec::new();
+				for req in requests.iter() {
+					if req.unlocks_at <= current_block {
+						total_withdrawn = total_withdrawn.saturating_add(req.amount);
+					} else {
+						remaining.push(req.clone());
+					}
+				}
+
+				ensure!(total_withdrawn > 0, Error::<T>::UnbondingNotComplete);
+
+				// Replace with remaining requests
+				*requests = BoundedVec::try_from(remaining)
+					.map_err(|_| Error::<T>::TooManyUnbondingRequests)?;
+
+				Ok(())
+			})?;
+			Self::release_bonded_stake(staker, total_withdrawn);
+			Self::close_stake_if_empty(staker);
+
+			Ok(total_withdrawn)
+		}
+
+		/// Remove a staker's stake entry, staker set membership, history and recovery account
+		/// once it holds no active stake and no unbonding requests remain
+		fn close_stake_if_empty(staker: &T::AccountId) {
+			if !UnbondingRequests::<T>::get(staker).is_empty() {
+				return;
+			}
+			UnbondingRequests::<T>::remove(staker);
+
+			if let Some(info) = EnhancedStakes::<T>::get(staker) {
+				if info.active == 0 {
+					EnhancedStakes::<T>::remove(staker);
+					StakerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+					StakeHistory::<T>::remove(staker);
+					RecoveryAccount::<T>::remove(staker);
+					Self::remove_from_staker_set(staker);
+				}
+			}
+		}
+
 		/// Get current era verifiers
 		pub fn get_current_verifiers() -> Vec<T::AccountId> {
 			let era = CurrentEra::<T>::get();
@@ -2663,6 +7591,249 @@ pub mod pallet {
 			EraVerifiers::<T>::get(era).contains(account)
 		}
 
+		/// Era whose verifiers attest to `period`: the one recorded by its first report, or
+		/// the current era while nobody has reported yet
+		pub fn verification_era(period: BlockNumberFor<T>) -> u32 {
+			PeriodVerificationEra::<T>::get(period).unwrap_or_else(CurrentEra::<T>::get)
+		}
+
+		/// Whether enough of the verifiers attesting `period` have reported for it.
+		/// The required count rounds up, so any non-zero quorum needs at least one report.
+		pub fn verification_quorum_met(period: BlockNumberFor<T>) -> bool {
+			let verifier_count =
+				EraVerifiers::<T>::get(Self::verification_era(period)).len() as u128;
+			let required = (verifier_count * T::VerifierQuorumPercent::get() as u128)
+				.saturating_add(9_999)
+				.saturating_div(10_000);
+			VerificationReportCount::<T>::get(period) as u128 >= required
+		}
+
+		/// Settle an account's pending staker and/or issuer rewards, as selected by `staker`
+		/// and `issuer`: pay them out, or recycle them into the reward pool if they have
+		/// expired. Each reward is paid according to its `RewardKind`; `Points` rewards are
+		/// minted as a `TravelType::Other` batch. Returns `false` if the selected rewards
+		/// were all zero.
+		fn settle_pending_rewards(
+			account: &T::AccountId,
+			staker: bool,
+			issuer: bool,
+		) -> Result<bool, DispatchError> {
+			let staker_reward = if staker { PendingStakerRewards::<T>::get(account) } else { 0 };
+			let issuer_reward = if issuer { PendingIssuerRewards::<T>::get(account) } else { 0 };
+			let total_reward = staker_reward.saturating_add(issuer_reward);
+
+			if total_reward == 0 {
+				return Ok(false);
+			}
+
+			if Self::rewards_expired(account) {
+				Self::store_unsettled_rewards(account, staker.then_some(0), issuer.then_some(0));
+
+				Self::increase_reward_pool(total_reward)?;
+				Self::deposit_event(Event::RewardExpired {
+					account: account.clone(),
+					amount: total_reward,
+				});
+			} else {
+				// Pay staker rewards first, and at most `MaxClaimPerCall` in total
+				let cap = T::MaxClaimPerCall::get();
+				let (staker_paid, issuer_paid) = if cap.is_zero() || total_reward <= cap {
+					(staker_reward, issuer_reward)
+				} else {
+					let staker_paid = staker_reward.min(cap);
+					(staker_paid, issuer_reward.min(cap.saturating_sub(staker_paid)))
+				};
+				let paid = staker_paid.saturating_add(issuer_paid);
+				let remaining = total_reward.saturating_sub(paid);
+
+				Self::store_unsettled_rewards(
+					account,
+					staker.then(|| staker_reward.saturating_sub(staker_paid)),
+					issuer.then(|| issuer_reward.saturating_sub(issuer_paid)),
+				);
+				// Whatever is left after a partial claim gets a fresh claim window
+				if !remaining.is_zero() {
+					PendingRewardsSince::<T>::insert(
+						account,
+						frame_system::Pallet::<T>::block_number(),
+					);
+				}
+
+				let payouts = [
+					(staker_paid, T::StakerRewardKind::get()),
+					(issuer_paid, T::IssuerRewardKind::get()),
+				];
+				let mut currency_reward: u128 = 0;
+				for (amount, kind) in payouts {
+					if kind == RewardKind::Currency {
+						currency_reward = currency_reward.saturating_add(amount);
+					}
+					if kind == RewardKind::Points && amount > 0 {
+						let recipient = account.clone();
+						Self::with_reentrancy_guard(|| {
+							Self::credit_point_batch(
+								recipient,
+								amount,
+								TravelType::Other,
+								None,
+								true,
+							)
+						})?;
+					}
+				}
+
+				if currency_reward > 0 && !T::RewardVestingPeriod::get().is_zero() {
+					Self::start_reward_vesting(account, currency_reward);
+				}
+
+				if remaining.is_zero() {
+					Self::deposit_event(Event::RewardClaimed {
+						account: account.clone(),
+						amount: paid,
+					});
+				} else {
+					Self::deposit_event(Event::PartialRewardClaimed {
+						account: account.clone(),
+						amount: paid,
+						remaining,
+					});
+				}
+			}
+
+			Ok(true)
+		}
+
+		/// Store what is left of the settled reward buckets (`None` leaves a bucket as it is)
+		/// and clear the claim window once nothing is pending in either bucket
+		fn store_unsettled_rewards(
+			account: &T::AccountId,
+			staker_left: Option<u128>,
+			issuer_left: Option<u128>,
+		) {
+			match staker_left {
+				Some(0) => PendingStakerRewards::<T>::remove(account),
+				Some(left) => PendingStakerRewards::<T>::insert(account, left),
+				None => {},
+			}
+			match issuer_left {
+				Some(0) => PendingIssuerRewards::<T>::remove(account),
+				Some(left) => PendingIssuerRewards::<T>::insert(account, left),
+				None => {},
+			}
+			if Self::get_pending_rewards(account).is_zero() {
+				PendingRewardsSince::<T>::remove(account);
+			}
+		}
+
+		/// Lock `amount` of claimed rewards in a vesting schedule ending
+		/// `RewardVestingPeriod` blocks from now. Anything already vested under an existing
+		/// schedule is paid out first; the unvested rest is merged with `amount` into a
+		/// schedule that keeps the existing end block, so earlier rewards are never locked
+		/// for longer than they were.
+		fn start_reward_vesting(account: &T::AccountId, amount: u128) {
+			Self::release_vested_reward(account);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let existing = RewardVestingSchedules::<T>::get(account);
+			let carried = existing
+				.as_ref()
+				.map(|schedule| schedule.total.saturating_sub(schedule.withdrawn))
+				.unwrap_or_default();
+			let total = carried.saturating_add(amount);
+			let ends_at = existing
+				.map(|schedule| schedule.ends_at)
+				.unwrap_or_else(|| current_block.saturating_add(T::RewardVestingPeriod::get()));
+
+			RewardVestingSchedules::<T>::insert(
+				account,
+				RewardVesting { total, withdrawn: 0, starts_at: current_block, ends_at },
+			);
+
+			Self::deposit_event(Event::RewardVestingStarted {
+				account: account.clone(),
+				amount: total,
+				ends_at,
+			});
+		}
+
+		/// Pay out the vested, not yet withdrawn part of an account's vesting rewards and
+		/// return it. The schedule is removed once fully withdrawn.
+		fn release_vested_reward(account: &T::AccountId) -> u128 {
+			let Some(mut schedule) = RewardVestingSchedules::<T>::get(account) else {
+				return 0;
+			};
+
+			let amount = Self::vested_amount(&schedule).saturating_sub(schedule.withdrawn);
+			if amount == 0 {
+				return 0;
+			}
+
+			schedule.withdrawn = schedule.withdrawn.saturating_add(amount);
+			if schedule.withdrawn >= schedule.total {
+				RewardVestingSchedules::<T>::remove(account);
+			} else {
+				RewardVestingSchedules::<T>::insert(account, schedule);
+			}
+
+			Self::deposit_event(Event::VestedRewardWithdrawn { account: account.clone(), amount });
+
+			amount
+		}
+
+		/// Amount of a vesting schedule vested at the current block, withdrawn or not
+		fn vested_amount(schedule: &RewardVesting<BlockNumberFor<T>>) -> u128 {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			if current_block >= schedule.ends_at {
+				return schedule.total;
+			}
+
+			let elapsed: u128 = current_block.saturating_sub(schedule.starts_at).saturated_into();
+			let duration: u128 =
+				schedule.ends_at.saturating_sub(schedule.starts_at).saturated_into();
+			// Rounds down: the remainder is released once the schedule ends
+			schedule.total.saturating_mul(elapsed).saturating_div(duration)
+		}
+
+		/// Rewards vested so far but not yet withdrawn
+		pub fn withdrawable_vested_reward(account: &T::AccountId) -> u128 {
+			RewardVestingSchedules::<T>::get(account)
+				.map(|schedule| Self::vested_amount(&schedule).saturating_sub(schedule.withdrawn))
+				.unwrap_or_default()
+		}
+
+		/// Start a new claim window for an account about to accrue rewards.
+		/// Rewards still pending from a window that has already run out are stale: they are
+		/// taken off the account and returned so the caller can recycle them, rather than
+		/// riding along into the new window.
+		fn note_reward_accrual(account: &T::AccountId) -> u128 {
+			let mut stale = 0u128;
+			if Self::rewards_expired(account) {
+				stale = Self::get_pending_rewards(account);
+				PendingStakerRewards::<T>::remove(account);
+				PendingIssuerRewards::<T>::remove(account);
+				if stale > 0 {
+					Self::deposit_event(Event::RewardExpired {
+						account: account.clone(),
+						amount: stale,
+					});
+				}
+			}
+			PendingRewardsSince::<T>::insert(account, frame_system::Pallet::<T>::block_number());
+			stale
+		}
+
+		/// Whether an account's pending rewards have outlived `RewardClaimWindow`.
+		/// Always false when the window is configured as zero.
+		pub fn rewards_expired(account: &T::AccountId) -> bool {
+			let window = T::RewardClaimWindow::get();
+			if window.is_zero() {
+				return false;
+			}
+			PendingRewardsSince::<T>::get(account).is_some_and(|since| {
+				frame_system::Pallet::<T>::block_number() >= since.saturating_add(window)
+			})
+		}
+
 		/// Get pending rewards for an account (staker + issuer)
 		pub fn get_pending_rewards(account: &T::AccountId) -> u128 {
 			PendingStakerRewards::<T>::get(account)
@@ -0,0 +1,258 @@
+//! Storage migrations for pallet-travel-points
+
+use crate::{
+	AuthorizedIssuers, BondedStake, Config, EnhancedStakeInfo, EnhancedStakes,
+	IssuerLastActivePeriod, Pallet, PointBatch, StakeHistory, StakeInfo, StakerCount, Ticket,
+	TicketType, Tickets, TravelType, UserPoints, MAX_STRING_LEN,
+};
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, storage_alias,
+	traits::UncheckedOnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// Version 1 keeps stakes in `EnhancedStakes` only and drops the legacy `Stakes` map.
+pub mod v1 {
+	use super::*;
+
+	/// The legacy stake map, read by every staking call before version 1
+	#[storage_alias]
+	pub type Stakes<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		StakeInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Move every `Stakes` entry into `EnhancedStakes` (`amount` becomes `active`, no
+	/// slashes recorded yet) and set `StakerCount` to the number of stakers moved. Each
+	/// migrated staker's `BondedStake` is seeded with its amount and its `StakeHistory` with
+	/// one entry at `staked_at`, so the bond checks and offense-block slashing cover it too.
+	///
+	/// `EnhancedStakes` was never written before version 1, so nothing is overwritten.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut migrated: u32 = 0;
+			for (staker, info) in Stakes::<T>::drain() {
+				EnhancedStakes::<T>::insert(
+					&staker,
+					EnhancedStakeInfo {
+						active: info.amount,
+						staked_at: info.staked_at,
+						is_verifier: info.is_verifier,
+						total_slashed: 0,
+					},
+				);
+				BondedStake::<T>::insert(&staker, info.amount);
+				StakeHistory::<T>::insert(
+					&staker,
+					BoundedVec::truncate_from(alloc::vec![(info.staked_at, info.amount)]),
+				);
+				migrated = migrated.saturating_add(1);
+			}
+			StakerCount::<T>::put(migrated);
+
+			// Per staker: the legacy entry is read and removed and the new stake, bond and
+			// history entries inserted
+			let stakers = migrated as u64;
+			let writes = stakers.saturating_mul(4).saturating_add(1);
+			T::DbWeight::get().reads_writes(stakers.saturating_add(1), writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((Stakes::<T>::iter().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let legacy_stakers = u32::decode(&mut &state[..])
+				.map_err(|_| "pre_upgrade state does not decode as a staker count")?;
+			ensure!(Stakes::<T>::iter().next().is_none(), "legacy stakes left behind");
+			ensure!(StakerCount::<T>::get() == legacy_stakers, "StakerCount does not match");
+			ensure!(
+				EnhancedStakes::<T>::iter().count() as u32 == legacy_stakers,
+				"not every legacy stake was moved"
+			);
+			ensure!(
+				BondedStake::<T>::iter().count() as u32 == legacy_stakers,
+				"not every migrated stake has a bond"
+			);
+			ensure!(
+				StakeHistory::<T>::iter().count() as u32 == legacy_stakers,
+				"not every migrated stake has a history entry"
+			);
+			Ok(())
+		}
+	}
+
+	/// `InnerMigrateV0ToV1`, run only while the on-chain storage version is 0
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Version 2 adds demurrage and partial expiry tracking to every stored `PointBatch`, and
+/// route codes, segments and capacity to every stored `Ticket`. It also starts the
+/// inactivity clock of issuers authorized before `IssuerLastActivePeriod` existed.
+pub mod v2 {
+	use super::*;
+
+	/// `PointBatch` as stored before version 2
+	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+	pub struct OldPointBatch<BlockNumber> {
+		pub earned_at_block: BlockNumber,
+		pub expires_at_block: BlockNumber,
+		pub remaining_points: u128,
+		pub travel_type: TravelType,
+	}
+
+	impl<BlockNumber: Clone> From<OldPointBatch<BlockNumber>> for PointBatch<BlockNumber> {
+		/// Demurrage is owed from the block the points were earned; no partial expiry
+		/// step has been fixed yet
+		fn from(old: OldPointBatch<BlockNumber>) -> Self {
+			PointBatch {
+				last_demurrage_block: old.earned_at_block.clone(),
+				earned_at_block: old.earned_at_block,
+				expires_at_block: old.expires_at_block,
+				remaining_points: old.remaining_points,
+				travel_type: old.travel_type,
+				expiring_per_step: 0,
+			}
+		}
+	}
+
+	/// `Ticket` as stored before version 2
+	#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+	pub struct OldTicket<AccountId, BlockNumber> {
+		pub id: u128,
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub ticket_type: TicketType,
+		pub created_at: BlockNumber,
+		pub expires_at: Option<BlockNumber>,
+		pub points_cost: u128,
+		pub is_redeemed: bool,
+		pub passenger_name: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub travel_number: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub gate: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub seat: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub departure: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub arrival: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub departure_time: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+		pub metadata: BoundedVec<u8, ConstU32<MAX_STRING_LEN>>,
+	}
+
+	impl<AccountId, BlockNumber> From<OldTicket<AccountId, BlockNumber>>
+		for Ticket<AccountId, BlockNumber>
+	{
+		/// Tickets minted before version 2 carry no route codes and stay out of
+		/// `TicketsByRoute`
+		fn from(old: OldTicket<AccountId, BlockNumber>) -> Self {
+			Ticket {
+				id: old.id,
+				owner: old.owner,
+				issuer: old.issuer,
+				ticket_type: old.ticket_type,
+				created_at: old.created_at,
+				expires_at: old.expires_at,
+				points_cost: old.points_cost,
+				is_redeemed: old.is_redeemed,
+				passenger_name: old.passenger_name,
+				travel_number: old.travel_number,
+				gate: old.gate,
+				seat: old.seat,
+				departure: old.departure,
+				arrival: old.arrival,
+				departure_time: old.departure_time,
+				// The metadata bound only grew, so nothing is cut off
+				metadata: BoundedVec::truncate_from(old.metadata.into_inner()),
+				departure_code: None,
+				arrival_code: None,
+				segments: 1,
+				segments_redeemed: old.is_redeemed as u8,
+				capacity: 1,
+				used: 0,
+			}
+		}
+	}
+
+	/// Translate every `UserPoints` and `Tickets` entry to the version 2 layouts, and record
+	/// the current period as the last active period of every authorized issuer without one,
+	/// so `prune_inactive_issuer` cannot revoke them right after the upgrade.
+	pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut users: u64 = 0;
+			UserPoints::<T>::translate::<
+				BoundedVec<OldPointBatch<BlockNumberFor<T>>, T::MaxPointBatches>,
+				_,
+			>(|_, old| {
+				users = users.saturating_add(1);
+				Some(BoundedVec::truncate_from(old.into_iter().map(Into::into).collect()))
+			});
+
+			let mut tickets: u64 = 0;
+			Tickets::<T>::translate::<OldTicket<T::AccountId, BlockNumberFor<T>>, _>(|_, old| {
+				tickets = tickets.saturating_add(1);
+				Some(old.into())
+			});
+
+			let period = Pallet::<T>::current_period();
+			let mut issuers: u64 = 0;
+			let mut seeded: u64 = 0;
+			for (issuer, authorized) in AuthorizedIssuers::<T>::iter() {
+				issuers = issuers.saturating_add(1);
+				if authorized && !IssuerLastActivePeriod::<T>::contains_key(&issuer) {
+					IssuerLastActivePeriod::<T>::insert(&issuer, period);
+					seeded = seeded.saturating_add(1);
+				}
+			}
+
+			let entries = users.saturating_add(tickets);
+			T::DbWeight::get().reads_writes(
+				entries.saturating_add(issuers.saturating_mul(2)).saturating_add(1),
+				entries.saturating_add(seeded),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+			let users = UserPoints::<T>::iter_keys().count() as u32;
+			let tickets = Tickets::<T>::iter_keys().count() as u32;
+			Ok((users, tickets).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (users, tickets) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "pre_upgrade state does not decode as entry counts")?;
+			ensure!(
+				UserPoints::<T>::iter_values().count() as u32 == users,
+				"not every UserPoints entry decodes in the new layout"
+			);
+			ensure!(
+				Tickets::<T>::iter_values().count() as u32 == tickets,
+				"not every Tickets entry decodes in the new layout"
+			);
+			Ok(())
+		}
+	}
+
+	/// `InnerMigrateV1ToV2`, run only while the on-chain storage version is 1
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -51,20 +51,35 @@ impl pallet_travel_points::Config for Test {
 	type WeightInfo = ();
 	// Allow up to 100 point batches per user in tests
 	type MaxPointBatches = frame_support::traits::ConstU32<100>;
+	// Compact point batches once 90% of the batch limit is used
+	type BatchCompactionThreshold = frame_support::traits::ConstU32<9000>;
+	// Awards must grant at least 10 points
+	type MinAwardAmount = frame_support::traits::ConstU128<10>;
+	// Users can hold batches of at most 3 distinct travel types in tests
+	type MaxTravelTypesPerUser = frame_support::traits::ConstU32<3>;
 	// Default expiration: 1000 blocks (about 100 minutes with 6 second blocks)
 	// Note: Uses ConstU64 because TestDefaultConfig uses u64 for BlockNumber,
 	// while the real runtime uses ConstU32 (runtime BlockNumber is u32)
 	type DefaultExpirationPeriod = frame_support::traits::ConstU64<1000>;
+	// Whole batches expire at once unless a test sets partial expiry
+	type PartialExpiryPercent = PartialExpiryPercent;
+	type PartialExpiryInterval = PartialExpiryInterval;
 	// Maximum 50 tickets per user in tests
 	type MaxTicketsPerUser = frame_support::traits::ConstU32<50>;
+	// Keep the last 3 owners of each ticket in tests
+	type MaxProvenanceEntries = frame_support::traits::ConstU32<3>;
+	// Issuers may mint 100 tickets per period unless a test lowers the cap
+	type MaxTicketsPerIssuerPerPeriod = MaxTicketsPerIssuerPerPeriod;
 	// Maximum 100 stakers in tests
 	type MaxStakers = frame_support::traits::ConstU32<100>;
+	// Up to 3 stakers can wait for an active slot
+	type MaxStakerWaitlist = frame_support::traits::ConstU32<3>;
 	// Minimum stake amount: 100 tokens
 	type MinStakeAmount = frame_support::traits::ConstU128<100>;
 	// Staker reward percentage: 30% (3000 basis points)
 	type StakerRewardPercent = frame_support::traits::ConstU32<3000>;
-	// Blocks per reward period: 100 blocks (about 10 minutes with 6 second blocks)
-	type BlocksPerRewardPeriod = frame_support::traits::ConstU64<100>;
+	// Blocks per reward period: 100 blocks (about 10 minutes with 6 second blocks) by default
+	type BlocksPerRewardPeriod = BlocksPerRewardPeriod;
 
 	// ============================================================================
 	// ADVANCED STAKING CONFIGURATION
@@ -78,22 +93,181 @@ impl pallet_travel_points::Config for Test {
 	type InvalidVerificationSlashPercent = frame_support::traits::ConstU32<1000>;
 	// Malicious slash: 100% (10000 basis points)
 	type MaliciousSlashPercent = frame_support::traits::ConstU32<10000>;
+	// Slashes are burned unless a test recycles part of them into the reward pool
+	type SlashToRewardPoolBps = SlashToRewardPoolBps;
 	// Maximum 50 pools in tests
 	type MaxPools = frame_support::traits::ConstU32<50>;
 	// Maximum 20 delegators per pool in tests
 	type MaxDelegatorsPerPool = frame_support::traits::ConstU32<20>;
+	// An account may delegate at most 2000 tokens to a single pool
+	type MaxDelegationPerPoolPerAccount = frame_support::traits::ConstU128<2000>;
 	// Minimum pool operator stake: 500 tokens
 	type MinPoolOperatorStake = frame_support::traits::ConstU128<500>;
+	type MinPoolLifetime = MinPoolLifetime;
 	// Maximum pool commission: 50% (5000 basis points)
 	type MaxPoolCommission = frame_support::traits::ConstU32<5000>;
 	// 5 verifiers selected per era in tests
 	type VerifiersPerEra = frame_support::traits::ConstU32<5>;
+	// Any staker may be selected as verifier unless a test sets a minimum
+	type MinVerifierStake = MinVerifierStake;
+	// Half of the era's verifiers must report before rewards are released
+	type VerifierQuorumPercent = frame_support::traits::ConstU32<5000>;
 	// Blocks per era: 200 blocks (~20 minutes in test)
 	type BlocksPerEra = frame_support::traits::ConstU64<200>;
 	// Issuer reward percentage: 20% (2000 basis points)
 	type IssuerRewardPercent = frame_support::traits::ConstU32<2000>;
+	// Issuer rewards follow points spent only, unless a test blends in transaction counts
+	type IssuerRewardSpendWeight = IssuerRewardSpendWeight;
+	type IssuerRewardTransactionWeight = IssuerRewardTransactionWeight;
+	// Each transaction counts as 100 points in the blended issuer reward score
+	type IssuerRewardTransactionScale = frame_support::traits::ConstU128<100>;
 	// Maximum 10 unbonding requests per account
 	type MaxUnbondingRequests = frame_support::traits::ConstU32<10>;
+	// Remember the last 5 stake changes per staker
+	type MaxStakeHistory = frame_support::traits::ConstU32<5>;
+	// Unbonding requests below 50 tokens are returned instantly
+	type InstantUnbondThreshold = frame_support::traits::ConstU128<50>;
+	// At most 80 tokens may be unbonded instantly per period
+	type MaxInstantUnbondPerPeriod = frame_support::traits::ConstU128<80>;
+	// Emergency withdrawals forfeit 10% (1000 basis points) to the reward pool
+	type EmergencyWithdrawPenaltyBps = frame_support::traits::ConstU32<1000>;
+	// A spend may not leave a non-zero balance below 10 points
+	type MinRetainedPoints = frame_support::traits::ConstU128<10>;
+	// Awarded points are spendable right away unless a test sets a holding period
+	type MinHoldingPeriod = MinHoldingPeriod;
+	// Batches expired within the last 100 blocks can be reinstated
+	type ReinstatementWindow = frame_support::traits::ConstU64<100>;
+	// Activity leaves expiries alone unless a test enables the reset
+	type ResetExpiryOnActivity = ResetExpiryOnActivity;
+	// Point locks can be committed for 50 blocks
+	type LockTimeout = frame_support::traits::ConstU64<50>;
+	// A user may hold at most 3 open point locks in tests
+	type MaxPointLocks = frame_support::traits::ConstU32<3>;
+	// Spend breakdowns list at most 3 consumed batches in tests
+	type MaxSpendBreakdownEntries = frame_support::traits::ConstU32<3>;
+	// A user may spend with at most 3 distinct issuers per period in tests
+	type MaxIssuersPerUserPerPeriod = frame_support::traits::ConstU32<3>;
+	// Users may spend any amount per period unless a test sets a cap
+	type MaxUserSpendPerPeriod = MaxUserSpendPerPeriod;
+	// Pruning calls remove at most 2 entries of a past period in tests
+	type MaxPeriodEntriesPruned = frame_support::traits::ConstU32<2>;
+	// A retroactive bonus credits at most 2 users per call in tests
+	type MaxRetroactiveRecipients = frame_support::traits::ConstU32<2>;
+	// Periods can be bonused for 3 periods after they end in tests
+	type RetroactiveBonusWindow = frame_support::traits::ConstU64<3>;
+	// Accounts become dormant after 500 blocks without activity
+	type DormancyPeriod = frame_support::traits::ConstU64<500>;
+	// Issuers can be pruned after 5 periods without activity
+	type IssuerInactivityPeriod = frame_support::traits::ConstU64<5>;
+	// Account 999 receives escheated points
+	type EscheatmentAccount = frame_support::traits::ConstU64<999>;
+	// Points lose 10% (1000 basis points) per 100 blocks held
+	type DemurrageRate = frame_support::traits::ConstU32<1000>;
+	type DemurragePeriod = frame_support::traits::ConstU64<100>;
+	// A single demurrage call removes at most 20% of a batch
+	type MaxDemurragePerCall = frame_support::traits::ConstU32<2000>;
+	// Pending rewards must be claimed within 500 blocks
+	type RewardClaimWindow = frame_support::traits::ConstU64<500>;
+	type MaxClaimPerCall = MaxClaimPerCall;
+	// Every active stake earns unless a test sets a stake reward delay
+	type StakeRewardDelay = StakeRewardDelay;
+	type RequireVerificationForReward = RequireVerificationForReward;
+	// Maximum 5 tickets per batch transfer in tests
+	type MaxTicketsPerBatchTransfer = frame_support::traits::ConstU32<5>;
+	// Ticket transfers are free unless a test sets a fee
+	type TicketTransferFee = TicketTransferFee;
+	// Maximum 5 accounts per batch reward claim in tests
+	type MaxClaimsPerBatch = frame_support::traits::ConstU32<5>;
+	// Maximum 5 ticket ownership checks per query in tests
+	type MaxOwnershipChecks = frame_support::traits::ConstU32<5>;
+	// Maximum 4 tickets per status query in tests
+	type MaxTicketStatusQueries = frame_support::traits::ConstU32<4>;
+	// Reward kinds can be switched per test
+	type StakerRewardKind = StakerRewardKind;
+	type IssuerRewardKind = IssuerRewardKind;
+	// Rewards are paid instantly unless a test sets a vesting period
+	type RewardVestingPeriod = RewardVestingPeriod;
+	// Record redemptions so tests can inspect the hook
+	type OnTicketRedeemed = RecordRedemptions;
+}
+
+// (ticket_id, owner, ticket_type) passed to the `OnTicketRedeemed` hook
+pub type Redemption = (u128, u64, pallet_travel_points::TicketType);
+
+thread_local! {
+	// Ticket redemptions seen by the `OnTicketRedeemed` hook
+	pub static REDEEMED_TICKETS: core::cell::RefCell<Vec<Redemption>> =
+		const { core::cell::RefCell::new(Vec::new()) };
+	// When set, the hook tries to re-enter the pallet by awarding points to the owner
+	pub static REENTER_ON_REDEEM: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+	// Results of the hook's re-entry attempts
+	pub static REENTRY_RESULTS: core::cell::RefCell<Vec<sp_runtime::DispatchResult>> =
+		const { core::cell::RefCell::new(Vec::new()) };
+}
+
+// Config values tests can override for the current test with `X::set(value)`
+frame_support::parameter_types! {
+	// Payout kinds for staker and issuer rewards
+	pub static StakerRewardKind: pallet_travel_points::RewardKind =
+		pallet_travel_points::RewardKind::Currency;
+	pub static IssuerRewardKind: pallet_travel_points::RewardKind =
+		pallet_travel_points::RewardKind::Currency;
+	// Vesting period of claimed currency rewards (instant payout by default)
+	pub static RewardVestingPeriod: u64 = 0;
+	// Maximum reward paid per claim (uncapped by default)
+	pub static MaxClaimPerCall: u128 = 0;
+	// Points burned per ticket transfer (free by default)
+	pub static TicketTransferFee: u128 = 0;
+	// Issuer reward score weights of points spent and transaction count
+	pub static IssuerRewardSpendWeight: u32 = 1;
+	pub static IssuerRewardTransactionWeight: u32 = 0;
+	// Partial expiry share (basis points) and step interval (off by default)
+	pub static PartialExpiryPercent: u32 = 0;
+	pub static PartialExpiryInterval: u64 = 0;
+	// Length of a reward period
+	pub static BlocksPerRewardPeriod: u64 = 100;
+	// Blocks a stake must be held before it earns rewards (none by default)
+	pub static StakeRewardDelay: u64 = 0;
+	// Whether stakers must verify to earn rewards
+	pub static RequireVerificationForReward: bool = false;
+	// Blocks awarded points must be held before they can be spent (none by default)
+	pub static MinHoldingPeriod: u64 = 0;
+	// Blocks a pool must exist before it can be closed (none by default)
+	pub static MinPoolLifetime: u64 = 0;
+	// Tickets an issuer may mint per period
+	pub static MaxTicketsPerIssuerPerPeriod: u32 = 100;
+	// Share of slashes added to the reward pool (none by default)
+	pub static SlashToRewardPoolBps: u32 = 0;
+	// Minimum stake for verifier selection (none by default)
+	pub static MinVerifierStake: u128 = 0;
+	// Points a user may spend per period across issuers (uncapped by default)
+	pub static MaxUserSpendPerPeriod: u128 = 0;
+	// Whether awards and spends reset batch expiries
+	pub static ResetExpiryOnActivity: bool = false;
+}
+
+// Redemption hook that records every invocation in `REDEEMED_TICKETS`
+pub struct RecordRedemptions;
+impl pallet_travel_points::OnTicketRedeemedHandler<u64> for RecordRedemptions {
+	fn on_redeemed(ticket_id: u128, owner: &u64, ticket_type: &pallet_travel_points::TicketType) {
+		REDEEMED_TICKETS.with(|r| r.borrow_mut().push((ticket_id, *owner, ticket_type.clone())));
+
+		if REENTER_ON_REDEEM.with(|r| r.get()) {
+			let result = TravelPoints::contract_award_points(
+				2,
+				*owner,
+				100,
+				pallet_travel_points::TravelType::Other,
+				None,
+			);
+			REENTRY_RESULTS.with(|r| r.borrow_mut().push(result));
+		}
+	}
+}
+
+// Redemptions recorded by the mock hook so far
+pub fn redeemed_tickets() -> Vec<Redemption> {
+	REDEEMED_TICKETS.with(|r| r.borrow().clone())
 }
 
 // Helper function to build the genesis storage for tests
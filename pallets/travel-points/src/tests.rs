@@ -8,7 +8,10 @@
 //! - NFT Tickets
 //! - Staking
 
-use crate::{mock::*, Error, Event, TicketType, TotalPoints, TravelType, UserPoints};
+use crate::{
+	mock::*, weights::WeightInfo, Error, Event, IssuerRevocationReason, TicketStatus, TicketType,
+	TotalPoints, TravelType, UserPoints,
+};
 use frame_support::{assert_noop, assert_ok};
 
 // ============================================================================
@@ -88,6 +91,34 @@ fn award_points_zero_amount_fails() {
 	});
 }
 
+/// Test awards below the minimum award amount fail
+#[test]
+fn award_points_below_minimum_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// MinAwardAmount is 10 in tests
+		assert_noop!(
+			TravelPoints::award_points(RuntimeOrigin::signed(2), 10, 9, TravelType::Bus, None),
+			Error::<Test>::AwardBelowMinimum
+		);
+		assert_noop!(
+			TravelPoints::contract_award_points(2, 10, 1, TravelType::Bus, None),
+			Error::<Test>::AwardBelowMinimum
+		);
+
+		// Exactly the minimum is accepted
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			10,
+			TravelType::Bus,
+			None
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), 10);
+	});
+}
+
 /// Test custom expiration period
 #[test]
 fn award_points_custom_expiration_works() {
@@ -108,6 +139,297 @@ fn award_points_custom_expiration_works() {
 	});
 }
 
+/// Test a zero custom expiration is rejected instead of creating already-expired points
+#[test]
+fn award_points_zero_expiration_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				500,
+				TravelType::Other,
+				Some(0)
+			),
+			Error::<Test>::InvalidExpiration
+		);
+
+		// A single block is the shortest allowed expiration
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Other,
+			Some(1)
+		));
+		assert_eq!(UserPoints::<Test>::get(10)[0].expires_at_block, 11);
+		assert_eq!(TravelPoints::get_available_points(&10), 500);
+	});
+}
+
+/// Test awards are only charged for the expired batches they actually clean up
+#[test]
+fn award_points_refunds_unused_cleanup_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Fresh account: no batches to walk
+		let fresh = TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			None,
+		)
+		.expect("award should succeed");
+		assert_eq!(fresh.actual_weight, Some(<() as WeightInfo>::award_points(0)));
+
+		// Account with five batches that expire before the next award
+		for i in 0..5u64 {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				11,
+				100,
+				TravelType::Airline,
+				Some(5 + i)
+			));
+		}
+		System::set_block_number(20);
+		let cleaned = TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			11,
+			100,
+			TravelType::Airline,
+			None,
+		)
+		.expect("award should succeed");
+		assert_eq!(cleaned.actual_weight, Some(<() as WeightInfo>::award_points(5)));
+
+		// Live batches are walked too, so they are charged even though none expire
+		for _ in 0..5 {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				12,
+				100,
+				TravelType::Airline,
+				None
+			));
+		}
+		let live = TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			12,
+			100,
+			TravelType::Airline,
+			None,
+		)
+		.expect("award should succeed");
+		assert_eq!(live.actual_weight, Some(<() as WeightInfo>::award_points(5)));
+
+		assert!(
+			fresh.actual_weight.unwrap().ref_time() < cleaned.actual_weight.unwrap().ref_time()
+		);
+	});
+}
+
+/// Test a pending award only counts toward the balance once its issuer confirms it
+#[test]
+fn pending_award_confirm_lifecycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points_pending(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			Some(100)
+		));
+		System::assert_last_event(
+			Event::AwardPending { award_id: 0, issuer: 2, recipient: 10, amount: 500 }.into(),
+		);
+		assert!(TravelPoints::pending_awards(0).is_some());
+		assert_eq!(TravelPoints::get_available_points(&10), 0);
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+
+		// Only the issuer that created the award may confirm it
+		assert_noop!(
+			TravelPoints::confirm_award(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NotAwardIssuer
+		);
+
+		// Expiration counts from confirmation, not creation
+		System::set_block_number(50);
+		let confirmed = TravelPoints::confirm_award(RuntimeOrigin::signed(2), 0)
+			.expect("confirmation should succeed");
+		assert_eq!(confirmed.actual_weight, Some(<() as WeightInfo>::confirm_award(0)));
+		System::assert_last_event(
+			Event::AwardConfirmed { award_id: 0, recipient: 10, amount: 500 }.into(),
+		);
+
+		assert!(TravelPoints::pending_awards(0).is_none());
+		assert_eq!(TravelPoints::get_available_points(&10), 500);
+		assert_eq!(UserPoints::<Test>::get(10)[0].expires_at_block, 150);
+
+		// A confirmed award cannot be confirmed again
+		assert_noop!(
+			TravelPoints::confirm_award(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::PendingAwardNotFound
+		);
+	});
+}
+
+/// Test a confirmed award reports the amount credited after the travel type multiplier
+#[test]
+fn pending_award_confirm_reports_multiplied_amount() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = crate::TravelTypeConfig { multiplier: Some(15_000), ..Default::default() };
+		assert_ok!(TravelPoints::configure_travel_type(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			config
+		));
+		assert_ok!(TravelPoints::award_points_pending(
+			RuntimeOrigin::signed(2),
+			10,
+			200,
+			TravelType::Train,
+			None
+		));
+		assert_ok!(TravelPoints::confirm_award(RuntimeOrigin::signed(2), 0));
+
+		System::assert_last_event(
+			Event::AwardConfirmed { award_id: 0, recipient: 10, amount: 300 }.into(),
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 300);
+	});
+}
+
+/// Test a cancelled pending award never credits any points
+#[test]
+fn pending_award_cancel_lifecycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for _ in 0..2 {
+			assert_ok!(TravelPoints::award_points_pending(
+				RuntimeOrigin::signed(2),
+				10,
+				500,
+				TravelType::Train,
+				None
+			));
+		}
+		assert_eq!(TravelPoints::next_pending_award_id(), 2);
+
+		// Neither the recipient nor an unrelated account may cancel
+		assert_noop!(
+			TravelPoints::cancel_pending_award(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::NotAwardIssuer
+		);
+
+		// The issuer and the admin both can
+		assert_ok!(TravelPoints::cancel_pending_award(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::AwardCancelled { award_id: 0 }.into());
+		assert_ok!(TravelPoints::cancel_pending_award(RuntimeOrigin::signed(1), 1));
+
+		assert!(TravelPoints::pending_awards(0).is_none());
+		assert!(TravelPoints::pending_awards(1).is_none());
+		assert_eq!(TravelPoints::get_available_points(&10), 0);
+		assert_noop!(
+			TravelPoints::confirm_award(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::PendingAwardNotFound
+		);
+	});
+}
+
+/// Test pending awards are validated like regular awards when created
+#[test]
+fn award_points_pending_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TravelPoints::award_points_pending(
+				RuntimeOrigin::signed(99),
+				10,
+				500,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::NotAuthorizedIssuer
+		);
+		assert_noop!(
+			TravelPoints::award_points_pending(
+				RuntimeOrigin::signed(2),
+				10,
+				0,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			TravelPoints::award_points_pending(
+				RuntimeOrigin::signed(2),
+				10,
+				5,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::AwardBelowMinimum
+		);
+		assert_noop!(
+			TravelPoints::award_points_pending(
+				RuntimeOrigin::signed(2),
+				10,
+				500,
+				TravelType::Airline,
+				Some(0)
+			),
+			Error::<Test>::InvalidExpiration
+		);
+	});
+}
+
+/// Test an award that would overflow the recipient's balance fails with
+/// `PointBalanceOverflow`
+#[test]
+fn award_points_balance_overflow_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			u128::MAX - 100,
+			TravelType::Airline,
+			None
+		));
+		assert_noop!(
+			TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				101,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::PointBalanceOverflow
+		);
+
+		// Staying within range is still fine
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			None
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), u128::MAX);
+	});
+}
+
 // ============================================================================
 // SPENDING POINTS TESTS
 // ============================================================================
@@ -186,13 +508,13 @@ fn spend_points_fifo_works() {
 	});
 }
 
-/// Test spending more than available fails
+/// Test a spend reports exactly which batches it consumed
 #[test]
-fn spend_points_insufficient_fails() {
+fn spend_points_reports_breakdown() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Award 500 points
+		// Batches expiring at blocks 1001 and 1002
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
@@ -200,42 +522,73 @@ fn spend_points_insufficient_fails() {
 			TravelType::Airline,
 			None
 		));
+		System::set_block_number(2);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Train,
+			None
+		));
 
-		// Try to spend 600
-		assert_noop!(
-			TravelPoints::spend_points(RuntimeOrigin::signed(10), 600, 2),
-			Error::<Test>::InsufficientPoints
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 600, 2));
+
+		System::assert_has_event(
+			Event::SpendBreakdown { user: 10, issuer: 2, consumed: vec![(1001, 500), (1002, 100)] }
+				.into(),
+		);
+		assert_eq!(
+			TravelPoints::last_spend_breakdown(&10).unwrap().into_inner(),
+			vec![(1001, 500), (1002, 100)]
 		);
 	});
 }
 
-/// Test spending zero fails
+/// Test a spend touching more than `MaxSpendBreakdownEntries` batches only reports the total
 #[test]
-fn spend_points_zero_fails() {
+fn spend_points_breakdown_falls_back_to_aggregate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::award_points(
-			RuntimeOrigin::signed(2),
-			10,
-			500,
-			TravelType::Airline,
-			None
-		));
+		// MaxSpendBreakdownEntries is 3 in tests
+		for block in 1..=4 {
+			System::set_block_number(block);
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				TravelType::Airline,
+				None
+			));
+		}
 
-		assert_noop!(
-			TravelPoints::spend_points(RuntimeOrigin::signed(10), 0, 2),
-			Error::<Test>::ZeroAmount
+		// A small spend touches one batch and is listed
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 50, 2));
+		assert!(TravelPoints::last_spend_breakdown(&10).is_some());
+
+		// Spending the rest touches all four batches
+		System::reset_events();
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 350, 2));
+
+		assert!(TravelPoints::last_spend_breakdown(&10).is_none());
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TravelPoints(Event::SpendBreakdown { .. })
+		)));
+		System::assert_last_event(
+			Event::PointsSpent { user: 10, amount_spent: 350, remaining_balance: 0, issuer: 2 }
+				.into(),
 		);
 	});
 }
 
-/// Test spending with unauthorized issuer fails
+/// Test spending more than available fails
 #[test]
-fn spend_points_unauthorized_issuer_fails() {
+fn spend_points_insufficient_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
+		// Award 500 points
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
@@ -244,329 +597,524 @@ fn spend_points_unauthorized_issuer_fails() {
 			None
 		));
 
-		// Try to spend with unauthorized issuer (account 5)
+		// Try to spend 600
 		assert_noop!(
-			TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 5),
-			Error::<Test>::NotAuthorizedIssuer
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 600, 2),
+			Error::<Test>::InsufficientPoints
 		);
 	});
 }
 
-// ============================================================================
-// EXPIRATION TESTS
-// ============================================================================
-
-/// Test that expired points are not counted
+/// Test spending zero fails
 #[test]
-fn expired_points_not_available() {
+fn spend_points_zero_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Award 500 points with short expiration (100 blocks)
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
 			500,
 			TravelType::Airline,
-			Some(100)
+			None
 		));
 
-		// Move to block 200 (past expiration at block 101)
-		System::set_block_number(200);
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 0, 2),
+			Error::<Test>::ZeroAmount
+		);
+	});
+}
+
+/// Test spending with unauthorized issuer fails
+#[test]
+fn spend_points_unauthorized_issuer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 
-		// Award some more points (this triggers cleanup)
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			100,
-			TravelType::Train,
+			500,
+			TravelType::Airline,
 			None
 		));
 
-		// Should only have 100 points (the new ones, old ones expired)
-		// The cleanup happens during award_points
-		// Note: TotalPoints might still show old value until cleanup
-		assert_eq!(TravelPoints::get_available_points(&10), 100);
+		// Try to spend with unauthorized issuer (account 5)
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 5),
+			Error::<Test>::NotAuthorizedIssuer
+		);
 	});
 }
 
-/// Test cleanup_expired function
+/// Test spending that would leave a dust balance fails
 #[test]
-fn cleanup_expired_works() {
+fn spend_points_leaving_dust_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Award points that will expire at block 101
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
 			500,
-			TravelType::Bus,
-			Some(100)
+			TravelType::Airline,
+			None
 		));
 
-		// Move past expiration
-		System::set_block_number(150);
-
-		// Call cleanup
-		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(99), 10));
-
-		// Batches should be empty
-		let batches = UserPoints::<Test>::get(10);
-		assert_eq!(batches.len(), 0);
+		// Leaving 5 points is below MinRetainedPoints (10)
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 495, 2),
+			Error::<Test>::WouldLeaveDust
+		);
 
-		// Total should be 0
-		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		// Leaving exactly the minimum is fine
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 490, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 10);
 	});
 }
 
-// ============================================================================
-// ADMIN AND ISSUER MANAGEMENT TESTS
-// ============================================================================
-
-/// Test authorizing a new issuer
+/// Test a failed spend leaves expired batches in place and `cleanup_expired` still prunes them
 #[test]
-fn authorize_issuer_works() {
+fn failed_spend_keeps_expired_batches_until_cleanup() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-
-		// Account 1 is admin, authorize account 5
-		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 5));
-
-		// Account 5 should now be able to issue points
 		assert_ok!(TravelPoints::award_points(
-			RuntimeOrigin::signed(5),
+			RuntimeOrigin::signed(2),
 			10,
 			100,
-			TravelType::Other,
+			TravelType::Airline,
+			Some(10)
+		));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
 			None
 		));
 
-		// Check event
-		System::assert_has_event(Event::IssuerAuthorized { issuer: 5 }.into());
+		// The first batch has expired; the failing call rolls back its cleanup as well
+		System::set_block_number(20);
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 600, 2),
+			Error::<Test>::InsufficientPoints
+		);
+		assert_eq!(UserPoints::<Test>::get(10).len(), 2);
+
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(99), 10));
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 500);
 	});
 }
 
-/// Test that non-admin cannot authorize issuers
+/// Test points within the minimum holding period cannot be spent yet
 #[test]
-fn authorize_issuer_not_admin_fails() {
+fn spend_points_within_holding_period_fails() {
 	new_test_ext().execute_with(|| {
+		MinHoldingPeriod::set(50);
 		System::set_block_number(1);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
+		System::set_block_number(40);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			300,
+			TravelType::Airline,
+			None
+		));
 
-		// Account 5 is not admin
+		// At block 60 only the first batch has been held for 50 blocks
+		System::set_block_number(60);
 		assert_noop!(
-			TravelPoints::authorize_issuer(RuntimeOrigin::signed(5), 10),
-			Error::<Test>::NotAdmin
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 600, 2),
+			Error::<Test>::PointsNotYetSpendable
+		);
+		// Even waiting would not cover more than the 800 points held
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 900, 2),
+			Error::<Test>::InsufficientPoints
 		);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 400, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 400);
+
+		// Once the second batch is seasoned it can be spent as well
+		System::set_block_number(90);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 390, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 10);
 	});
 }
 
-/// Test revoking an issuer
+/// Test spending the entire balance is always allowed
 #[test]
-fn revoke_issuer_works() {
+fn spend_points_entire_balance_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Account 2 is pre-authorized, revoke them
-		assert_ok!(TravelPoints::revoke_issuer(RuntimeOrigin::signed(1), 2));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
 
-		// Account 2 should no longer be able to issue points
-		assert_noop!(
-			TravelPoints::award_points(
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 500, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		assert!(UserPoints::<Test>::get(10).is_empty());
+	});
+}
+
+/// Test a type-scoped spend only draws on that type and applies its redemption bonus
+#[test]
+fn spend_points_of_type_applies_redemption_bonus() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for (amount, travel_type) in [(1000, TravelType::Airline), (500, TravelType::Train)] {
+			assert_ok!(TravelPoints::award_points(
 				RuntimeOrigin::signed(2),
 				10,
-				100,
-				TravelType::Airline,
+				amount,
+				travel_type,
 				None
+			));
+		}
+
+		// Without a bonus the full value is deducted, from train batches only
+		assert_ok!(TravelPoints::spend_points_of_type(
+			RuntimeOrigin::signed(10),
+			100,
+			2,
+			TravelType::Train
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), 1400);
+		assert_eq!(UserPoints::<Test>::get(10)[0].remaining_points, 1000);
+		assert_eq!(UserPoints::<Test>::get(10)[1].remaining_points, 400);
+
+		// 20% off train redemptions: 250 points of value cost 200
+		assert_ok!(TravelPoints::set_redemption_bonus(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			2000
+		));
+		assert_ok!(TravelPoints::spend_points_of_type(
+			RuntimeOrigin::signed(10),
+			250,
+			2,
+			TravelType::Train
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), 1200);
+		assert_eq!(UserPoints::<Test>::get(10)[1].remaining_points, 200);
+		assert_eq!(TravelPoints::get_period_total_spent(0), 300);
+		System::assert_has_event(
+			Event::RedemptionBonusApplied {
+				user: 10,
+				travel_type: TravelType::Train,
+				value: 250,
+				points_deducted: 200,
+			}
+			.into(),
+		);
+
+		// Airline points can't cover a train redemption
+		assert_noop!(
+			TravelPoints::spend_points_of_type(
+				RuntimeOrigin::signed(10),
+				300,
+				2,
+				TravelType::Train
 			),
-			Error::<Test>::NotAuthorizedIssuer
+			Error::<Test>::InsufficientPoints
 		);
 	});
 }
 
-/// Test changing admin
+/// Test only the admin can set a redemption bonus, and only below 100%
 #[test]
-fn set_admin_works() {
+fn set_redemption_bonus_validates() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-
-		// Account 1 is admin, set account 5 as new admin
-		assert_ok!(TravelPoints::set_admin(RuntimeOrigin::signed(1), 5));
-
-		// Account 1 should no longer be admin
 		assert_noop!(
-			TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 10),
+			TravelPoints::set_redemption_bonus(RuntimeOrigin::signed(10), TravelType::Train, 2000),
 			Error::<Test>::NotAdmin
 		);
-
-		// Account 5 should be admin now
-		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(5), 10));
+		assert_noop!(
+			TravelPoints::set_redemption_bonus(RuntimeOrigin::signed(1), TravelType::Train, 10_000),
+			Error::<Test>::InvalidRedemptionBonus
+		);
 	});
 }
 
-// ============================================================================
-// MULTIPLE BATCHES AND COMPLEX SCENARIOS
-// ============================================================================
-
-/// Test having multiple batches with different travel types
+/// Test a travel type's multiplier and expiry are configured together and both apply
 #[test]
-fn multiple_travel_types_work() {
+fn configure_travel_type_applies_multiplier_and_expiry() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
+		System::set_block_number(10);
 
-		// Award airline points
+		let config = crate::TravelTypeConfig {
+			multiplier: Some(15_000),
+			default_expiry: Some(300),
+			redemption_bonus: None,
+		};
+		assert_ok!(TravelPoints::configure_travel_type(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			config
+		));
+		System::assert_last_event(
+			Event::TravelTypeConfigured { travel_type: TravelType::Train }.into(),
+		);
+
+		// 1.5x points, expiring after 300 blocks instead of the default 1000
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			100,
-			TravelType::Airline,
-			Some(500)
+			1000,
+			TravelType::Train,
+			None
 		));
+		let batch = &UserPoints::<Test>::get(10)[0];
+		assert_eq!((batch.remaining_points, batch.expires_at_block), (1500, 310));
 
-		System::set_block_number(2);
-
-		// Award train points
+		// A custom expiration still wins, and other types are unaffected
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
-			10,
-			200,
+			20,
+			1000,
 			TravelType::Train,
-			Some(600)
+			Some(50)
 		));
-
-		System::set_block_number(3);
-
-		// Award bus points
+		assert_eq!(UserPoints::<Test>::get(20)[0].expires_at_block, 60);
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
-			10,
-			150,
-			TravelType::Bus,
-			Some(700)
+			30,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		let batch = &UserPoints::<Test>::get(30)[0];
+		assert_eq!((batch.remaining_points, batch.expires_at_block), (1000, 1010));
+
+		// 1x and a zero expiry restore the defaults
+		let config = crate::TravelTypeConfig {
+			multiplier: Some(10_000),
+			default_expiry: Some(0),
+			redemption_bonus: None,
+		};
+		assert_ok!(TravelPoints::configure_travel_type(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			config
 		));
+		assert_eq!(TravelPoints::travel_type_multiplier(TravelType::Train), None);
+		assert_eq!(TravelPoints::travel_type_expiry(TravelType::Train), None);
+	});
+}
 
-		// Check total
-		assert_eq!(TotalPoints::<Test>::get(10), 450);
+/// Test `configure_travel_type` validates its fields and leaves unset ones unchanged
+#[test]
+fn configure_travel_type_validates() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TravelPoints::set_redemption_bonus(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			2000
+		));
 
-		// Check batches are sorted by expiration (FIFO order)
-		let batches = UserPoints::<Test>::get(10);
-		assert_eq!(batches.len(), 3);
-		assert_eq!(batches[0].travel_type, TravelType::Airline); // expires first
-		assert_eq!(batches[1].travel_type, TravelType::Train);
-		assert_eq!(batches[2].travel_type, TravelType::Bus); // expires last
+		let config = crate::TravelTypeConfig { multiplier: Some(0), ..Default::default() };
+		assert_noop!(
+			TravelPoints::configure_travel_type(
+				RuntimeOrigin::signed(10),
+				TravelType::Train,
+				config.clone()
+			),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			TravelPoints::configure_travel_type(
+				RuntimeOrigin::signed(1),
+				TravelType::Train,
+				config
+			),
+			Error::<Test>::InvalidMultiplier
+		);
+		let config =
+			crate::TravelTypeConfig { redemption_bonus: Some(10_000), ..Default::default() };
+		assert_noop!(
+			TravelPoints::configure_travel_type(
+				RuntimeOrigin::signed(1),
+				TravelType::Train,
+				config
+			),
+			Error::<Test>::InvalidRedemptionBonus
+		);
+
+		let config = crate::TravelTypeConfig { multiplier: Some(20_000), ..Default::default() };
+		assert_ok!(TravelPoints::configure_travel_type(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			config
+		));
+		assert_eq!(TravelPoints::travel_type_multiplier(TravelType::Train), Some(20_000));
+		assert_eq!(TravelPoints::travel_type_redemption_bonus(TravelType::Train), 2000);
 	});
 }
 
-/// Test spending across multiple batches completely empties some
+/// Number of `BalanceEmptied` events emitted for account 10 so far
+fn balance_emptied_count() -> usize {
+	System::events()
+		.iter()
+		.filter(|record| {
+			matches!(record.event, RuntimeEvent::TravelPoints(Event::BalanceEmptied { user: 10 }))
+		})
+		.count()
+}
+
+/// Test `BalanceEmptied` fires once when a spend takes the balance to zero
 #[test]
-fn spend_across_batches_removes_empty() {
+fn spending_to_zero_emits_balance_emptied_once() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Award 3 batches of 100 each
-		for i in 0..3 {
-			System::set_block_number(1 + i);
-			assert_ok!(TravelPoints::award_points(
-				RuntimeOrigin::signed(2),
-				10,
-				100,
-				TravelType::Airline,
-				None
-			));
-		}
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
 
-		assert_eq!(TotalPoints::<Test>::get(10), 300);
-		assert_eq!(UserPoints::<Test>::get(10).len(), 3);
+		// A partial spend leaves the balance positive
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
+		assert_eq!(balance_emptied_count(), 0);
 
-		// Spend 250 - should empty first 2 batches and take 50 from third
-		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 250, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_eq!(balance_emptied_count(), 1);
 
-		// Only 1 batch left with 50 points
-		let batches = UserPoints::<Test>::get(10);
-		assert_eq!(batches.len(), 1);
-		assert_eq!(batches[0].remaining_points, 50);
-		assert_eq!(TotalPoints::<Test>::get(10), 50);
+		// Cleaning up an already empty account does not repeat the event
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert_eq!(balance_emptied_count(), 1);
 	});
 }
 
-/// Test the helper function for checking available points
+/// Test `BalanceEmptied` fires once when the last points expire
 #[test]
-fn get_available_points_works() {
+fn expiring_to_zero_emits_balance_emptied_once() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			500,
+			100,
 			TravelType::Airline,
-			Some(100)
+			Some(10)
 		));
 
-		// Before expiration
-		assert_eq!(TravelPoints::get_available_points(&10), 500);
+		System::set_block_number(20);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		assert_eq!(balance_emptied_count(), 1);
 
-		// After expiration
-		System::set_block_number(150);
-		assert_eq!(TravelPoints::get_available_points(&10), 0);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert_eq!(balance_emptied_count(), 1);
 	});
 }
 
-/// Test the helper function for point details
+/// Test teleporting burns points FIFO and signals the destination for the XCM adapter
 #[test]
-fn get_point_details_works() {
+fn teleport_points_burns_and_emits() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			500,
+			300,
 			TravelType::Airline,
 			Some(100)
 		));
-
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			300,
+			500,
 			TravelType::Train,
-			Some(200)
+			None
 		));
 
-		let details = TravelPoints::get_point_details(&10);
-		assert_eq!(details.len(), 2);
-		assert_eq!(details[0], (500, 101, TravelType::Airline));
-		assert_eq!(details[1], (300, 201, TravelType::Train));
+		assert_ok!(TravelPoints::teleport_points(
+			RuntimeOrigin::signed(10),
+			2000,
+			b"beneficiary".to_vec(),
+			400
+		));
+
+		// The oldest batch is burned first
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 400);
+		assert_eq!(TotalPoints::<Test>::get(10), 400);
+		System::assert_last_event(
+			Event::PointsTeleported {
+				from: 10,
+				dest_para: 2000,
+				beneficiary: b"beneficiary".to_vec().try_into().unwrap(),
+				amount: 400,
+			}
+			.into(),
+		);
 	});
 }
 
-// ============================================================================
-// CONTRACT INTERFACE TESTS
-// ============================================================================
-
-/// Test the contract interface for awarding points
+/// Test teleports are rejected without enough points or a valid beneficiary
 #[test]
-fn contract_award_points_works() {
+fn teleport_points_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Use the contract interface
-		assert_ok!(TravelPoints::contract_award_points(
-			2,   // issuer (pre-authorized)
-			10,  // recipient
-			500, // amount
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			300,
 			TravelType::Airline,
 			None
 		));
 
-		assert_eq!(TotalPoints::<Test>::get(10), 500);
+		assert_noop!(
+			TravelPoints::teleport_points(RuntimeOrigin::signed(10), 2000, b"dest".to_vec(), 301),
+			Error::<Test>::InsufficientPoints
+		);
+		assert_noop!(
+			TravelPoints::teleport_points(RuntimeOrigin::signed(10), 2000, b"dest".to_vec(), 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			TravelPoints::teleport_points(RuntimeOrigin::signed(10), 2000, Vec::new(), 100),
+			Error::<Test>::InvalidBeneficiary
+		);
+		assert_noop!(
+			TravelPoints::teleport_points(
+				RuntimeOrigin::signed(10),
+				2000,
+				vec![1u8; crate::MAX_BENEFICIARY_LEN as usize + 1],
+				100
+			),
+			Error::<Test>::InvalidBeneficiary
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 300);
 	});
 }
 
-/// Test the contract balance check interface
+/// Test a committed point lock is spent at the issuer with the usual tracking
 #[test]
-fn contract_check_balance_works() {
+fn point_lock_commit_spends_at_issuer() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
@@ -578,1073 +1126,1753 @@ fn contract_check_balance_works() {
 			None
 		));
 
-		// Use contract interface to check balance
-		assert_eq!(TravelPoints::contract_check_balance(&10), 1000);
-	});
-}
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 400, 7));
+		System::assert_last_event(
+			Event::PointsLocked { user: 10, lock_id: 7, amount: 400, expires_at: 51 }.into(),
+		);
+		// Locked points are not part of the balance
+		assert_eq!(TotalPoints::<Test>::get(10), 600);
+		assert_eq!(TravelPoints::get_available_points(&10), 600);
+		assert_eq!(TravelPoints::point_lock_count(10), 1);
+		assert_noop!(
+			TravelPoints::lock_points(RuntimeOrigin::signed(10), 100, 7),
+			Error::<Test>::PointLockExists
+		);
 
-/// Test the contract issuer check interface
-#[test]
-fn contract_is_authorized_issuer_works() {
-	new_test_ext().execute_with(|| {
-		// Account 2 is pre-authorized
-		assert!(TravelPoints::contract_is_authorized_issuer(&2));
+		System::set_block_number(50);
+		assert_noop!(
+			TravelPoints::commit_lock(RuntimeOrigin::signed(10), 7, 99),
+			Error::<Test>::NotAuthorizedIssuer
+		);
+		assert_ok!(TravelPoints::commit_lock(RuntimeOrigin::signed(10), 7, 2));
 
-		// Account 5 is not authorized
-		assert!(!TravelPoints::contract_is_authorized_issuer(&5));
+		System::assert_has_event(
+			Event::PointsSpent { user: 10, amount_spent: 400, remaining_balance: 600, issuer: 2 }
+				.into(),
+		);
+		System::assert_last_event(
+			Event::PointLockCommitted { user: 10, lock_id: 7, issuer: 2, amount: 400 }.into(),
+		);
+		let record = TravelPoints::issuer_daily_records(TravelPoints::current_period(), 2);
+		assert_eq!(record.points_spent, 400);
+		assert_eq!(TotalPoints::<Test>::get(10), 600);
+		assert!(TravelPoints::locked_points(10, 7).is_none());
+		assert_eq!(TravelPoints::point_lock_count(10), 0);
+		assert_noop!(
+			TravelPoints::commit_lock(RuntimeOrigin::signed(10), 7, 2),
+			Error::<Test>::PointLockNotFound
+		);
 	});
 }
 
-// ============================================================================
-// NFT TICKET TESTS
-// ============================================================================
-
-/// Test minting a ticket NFT
+/// Test a released point lock returns its points to the batches they came from
 #[test]
-fn mint_ticket_works() {
+fn point_lock_release_returns_points() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// First award some points to the user
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			1000,
+			300,
 			TravelType::Airline,
-			None
+			Some(100)
 		));
-
-		// Mint a ticket (costs 500 points)
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2), // issuer
-			10,                       // owner
-			TicketType::PlaneTicket,
-			500,                          // points cost
-			Some(2000),                   // expires at
-			b"John Doe".to_vec(),         // passenger_name
-			b"AB123".to_vec(),            // travel_number
-			b"A12".to_vec(),              // gate
-			b"15A".to_vec(),              // seat
-			b"New York".to_vec(),         // departure
-			b"Los Angeles".to_vec(),      // arrival
-			b"2024-03-15 10:00".to_vec(), // departure_time
-			b"Business Class".to_vec(),   // metadata
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Train,
+			None
 		));
+		let before = UserPoints::<Test>::get(10);
 
-		// Check points were deducted
-		assert_eq!(TotalPoints::<Test>::get(10), 500);
+		// Taking all of the first batch removes it until the lock is released
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 400, 1));
+		assert_eq!(UserPoints::<Test>::get(10).len(), 1);
+		assert_eq!(TotalPoints::<Test>::get(10), 400);
 
-		// Check ticket was created
-		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
-		assert_eq!(ticket.owner, 10);
-		assert_eq!(ticket.issuer, 2);
-		assert_eq!(ticket.ticket_type, TicketType::PlaneTicket);
-		assert_eq!(ticket.points_cost, 500);
-		assert!(!ticket.is_redeemed);
+		assert_noop!(
+			TravelPoints::release_lock(RuntimeOrigin::signed(11), 1),
+			Error::<Test>::PointLockNotFound
+		);
+		assert_ok!(TravelPoints::release_lock(RuntimeOrigin::signed(10), 1));
+		System::assert_last_event(
+			Event::PointLockReleased { user: 10, lock_id: 1, amount: 400, timed_out: false }.into(),
+		);
+		assert_eq!(UserPoints::<Test>::get(10), before);
+		assert_eq!(TotalPoints::<Test>::get(10), 800);
+		assert_eq!(TravelPoints::point_lock_count(10), 0);
 
-		// Check user owns the ticket
-		let user_tickets = TravelPoints::get_user_tickets(&10);
-		assert_eq!(user_tickets.len(), 1);
-		assert_eq!(user_tickets[0], 0);
+		// The lock ID can be used again and the number of open locks is capped
+		for lock_id in 1..=3 {
+			assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 100, lock_id));
+		}
+		assert_noop!(
+			TravelPoints::lock_points(RuntimeOrigin::signed(10), 100, 4),
+			Error::<Test>::TooManyPointLocks
+		);
 	});
 }
 
-/// Test minting a ticket with no point cost (free ticket)
+/// Test a point lock past `LockTimeout` cannot be committed and is released by cleanup
 #[test]
-fn mint_free_ticket_works() {
+fn point_lock_times_out_and_is_released() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a free bonus ticket
-		assert_ok!(TravelPoints::mint_ticket(
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::Bonus,
-			0,    // free
-			None, // no expiration
-			b"Jane Doe".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"Lounge Access".to_vec(),
+			1000,
+			TravelType::Airline,
+			None
 		));
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 400, 1));
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 100, 2));
 
-		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
-		assert_eq!(ticket.ticket_type, TicketType::Bonus);
-		assert_eq!(ticket.points_cost, 0);
+		// LockTimeout is 50 blocks
+		System::set_block_number(51);
+		assert_noop!(
+			TravelPoints::commit_lock(RuntimeOrigin::signed(10), 1, 2),
+			Error::<Test>::PointLockTimedOut
+		);
+
+		let result = TravelPoints::cleanup_expired(RuntimeOrigin::signed(99), 10);
+		assert_ok!(result);
+		assert_eq!(result.unwrap().actual_weight, Some(<() as WeightInfo>::cleanup_expired(2)));
+		System::assert_has_event(
+			Event::PointLockReleased { user: 10, lock_id: 1, amount: 400, timed_out: true }.into(),
+		);
+		System::assert_has_event(
+			Event::PointLockReleased { user: 10, lock_id: 2, amount: 100, timed_out: true }.into(),
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 1000);
+		assert_eq!(UserPoints::<Test>::get(10)[0].remaining_points, 1000);
+		assert_eq!(TravelPoints::point_lock_count(10), 0);
+		assert!(TravelPoints::locked_points(10, 1).is_none());
 	});
 }
 
-/// Test redeeming a ticket
+// ============================================================================
+// EXPIRATION TESTS
+// ============================================================================
+
+/// Test that expired points are not counted
 #[test]
-fn redeem_ticket_works() {
+fn expired_points_not_available() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket
-		assert_ok!(TravelPoints::mint_ticket(
+		// Award 500 points with short expiration (100 blocks)
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::TrainTicket,
-			0,
-			None,
-			b"Test User".to_vec(),
-			b"TR456".to_vec(),
-			b"".to_vec(),
-			b"22B".to_vec(),
-			b"Chicago".to_vec(),
-			b"Detroit".to_vec(),
-			b"2024-04-01 14:00".to_vec(),
-			b"".to_vec(),
+			500,
+			TravelType::Airline,
+			Some(100)
 		));
 
-		// Redeem the ticket
-		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		// Move to block 200 (past expiration at block 101)
+		System::set_block_number(200);
 
-		// Check ticket is redeemed
-		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
-		assert!(ticket.is_redeemed);
+		// Award some more points (this triggers cleanup)
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Train,
+			None
+		));
 
-		// Cannot redeem again
-		assert_noop!(
-			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0),
-			Error::<Test>::TicketAlreadyRedeemed
-		);
+		// Should only have 100 points (the new ones, old ones expired)
+		// The cleanup happens during award_points
+		// Note: TotalPoints might still show old value until cleanup
+		assert_eq!(TravelPoints::get_available_points(&10), 100);
 	});
 }
 
-/// Test transfer ticket
+/// Test cleanup_expired function
 #[test]
-fn transfer_ticket_works() {
+fn cleanup_expired_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket for user 10
-		assert_ok!(TravelPoints::mint_ticket(
+		// Award points that will expire at block 101
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::BusTicket,
-			0,
-			None,
-			b"Original Owner".to_vec(),
-			b"BUS001".to_vec(),
-			b"".to_vec(),
-			b"5".to_vec(),
-			b"City A".to_vec(),
-			b"City B".to_vec(),
-			b"2024-05-01 09:00".to_vec(),
-			b"".to_vec(),
+			500,
+			TravelType::Bus,
+			Some(100)
 		));
 
-		// Transfer to user 20
-		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20));
+		// Move past expiration
+		System::set_block_number(150);
 
-		// Check new ownership
-		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
-		assert_eq!(ticket.owner, 20);
+		// Call cleanup
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(99), 10));
 
-		// Check user ticket lists updated
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
-		assert_eq!(TravelPoints::get_user_tickets(&20).len(), 1);
+		// Batches should be empty
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 0);
+
+		// Total should be 0
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
 	});
 }
 
-/// Test unauthorized issuer cannot mint ticket
+/// Test demurrage reduces held points in proportion to blocks elapsed
 #[test]
-fn mint_ticket_unauthorized_fails() {
+fn apply_demurrage_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_noop!(
-			TravelPoints::mint_ticket(
-				RuntimeOrigin::signed(5), // unauthorized
-				10,
-				TicketType::PlaneTicket,
-				0,
-				None,
-				b"Test".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-				b"".to_vec(),
-			),
-			Error::<Test>::NotAuthorizedIssuer
-		);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		// Half a period: 5% of 1000
+		System::set_block_number(51);
+		assert_ok!(TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 950);
+		assert_eq!(UserPoints::<Test>::get(10)[0].remaining_points, 950);
+		assert_eq!(UserPoints::<Test>::get(10)[0].last_demurrage_block, 51);
+		System::assert_last_event(Event::DemurrageApplied { user: 10, amount_reduced: 50 }.into());
+
+		// Five periods would be 50%, but a single call removes at most 20%
+		System::set_block_number(551);
+		assert_ok!(TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 760);
+		System::assert_last_event(Event::DemurrageApplied { user: 10, amount_reduced: 190 }.into());
 	});
 }
 
-// ============================================================================
-// STAKING TESTS
-// ============================================================================
-
-/// Test basic staking
+/// Test demurrage is not applied twice in the same block
 #[test]
-fn stake_works() {
+fn apply_demurrage_same_block_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Stake 500 tokens
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
 
-		// Check stake info
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 500);
-		assert_eq!(stake_info.staked_at, 1);
-		assert!(!stake_info.is_verifier);
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 900);
 
-		// Check total staked
-		assert_eq!(TravelPoints::total_staked(), 500);
+		assert_noop!(
+			TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 10),
+			Error::<Test>::NoDemurrageDue
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 900);
 
-		// Check staker is in list
-		let stakers = TravelPoints::get_all_stakers();
-		assert!(stakers.contains(&10));
+		// An account without points has nothing to decay
+		assert_noop!(
+			TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 20),
+			Error::<Test>::NoDemurrageDue
+		);
 	});
 }
 
-/// Test staking below minimum fails
+/// Test the balance cache matches the recalculated balance in the block it was taken and
+/// is bypassed in later blocks
 #[test]
-fn stake_below_minimum_fails() {
+fn balance_cache_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Try to stake 50 tokens (below minimum of 100)
-		assert_noop!(
-			TravelPoints::stake(RuntimeOrigin::signed(10), 50),
-			Error::<Test>::StakeBelowMinimum
+		// Nothing cached yet: falls back to the batches
+		assert_eq!(TravelPoints::cached_available_points(&10), 0);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			Some(50)
+		));
+		// Awards do not touch the cache
+		assert_eq!(TravelPoints::available_cache(10), None);
+
+		assert_ok!(TravelPoints::refresh_balance_cache(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TravelPoints::available_cache(10), Some((1, 1000)));
+		assert_eq!(
+			TravelPoints::cached_available_points(&10),
+			TravelPoints::get_available_points(&10)
 		);
-	});
-}
 
-/// Test cannot stake twice
-#[test]
-fn stake_twice_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
+		// A spend in a later block is not hidden behind the old snapshot
+		System::set_block_number(2);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_eq!(TravelPoints::available_cache(10), Some((1, 1000)));
+		assert_eq!(TravelPoints::cached_available_points(&10), 700);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+		// Once the batch expires the stale snapshot is ignored as well
+		System::set_block_number(60);
+		assert_eq!(TravelPoints::cached_available_points(&10), 0);
 
-		assert_noop!(
-			TravelPoints::stake(RuntimeOrigin::signed(10), 300),
-			Error::<Test>::AlreadyStaking
+		assert_ok!(TravelPoints::refresh_balance_cache(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TravelPoints::available_cache(10), Some((60, 0)));
+		assert_eq!(
+			TravelPoints::cached_available_points(&10),
+			TravelPoints::get_available_points(&10)
 		);
 	});
 }
 
-/// Test unstaking
+/// Test partial expiry steps a batch down each interval until it is gone
 #[test]
-fn unstake_works() {
+fn partial_expiry_steps_batch_down() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		// 25% of the batch expires every 100 blocks
+		PartialExpiryPercent::set(2500);
+		PartialExpiryInterval::set(100);
 
-		// First stake
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
-		assert_eq!(TravelPoints::total_staked(), 500);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			Some(100)
+		));
 
-		// Then unstake
-		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(10)));
+		// First step at the expiry block
+		System::set_block_number(101);
+		assert_eq!(TravelPoints::get_available_points(&10), 750);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 750);
+		assert_eq!(batches[0].expires_at_block, 201);
+		assert_eq!(batches[0].expiring_per_step, 250);
+		assert_eq!(TotalPoints::<Test>::get(10), 750);
+		System::assert_last_event(
+			Event::PointsExpired { user: 10, amount_expired: 250, batches_removed: 0 }.into(),
+		);
 
-		// Check stake removed
-		assert!(TravelPoints::get_stake_info(&10).is_none());
-		assert_eq!(TravelPoints::total_staked(), 0);
+		// Nothing more expires before the next step
+		System::set_block_number(200);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 750);
 
-		// Check removed from staker list
-		let stakers = TravelPoints::get_all_stakers();
-		assert!(!stakers.contains(&10));
+		// The step is a share of the original batch, not of what is left
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert_eq!(UserPoints::<Test>::get(10)[0].remaining_points, 500);
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+
+		// Two missed steps are applied together and remove the rest of the batch
+		System::set_block_number(450);
+		assert_eq!(TravelPoints::get_available_points(&10), 0);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		assert!(UserPoints::<Test>::get(10).is_empty());
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		System::assert_has_event(
+			Event::PointsExpired { user: 10, amount_expired: 500, batches_removed: 1 }.into(),
+		);
 	});
 }
 
-/// Test unstaking without stake fails
+/// Test stepped batches keep the batch list ordered by expiry and spends shorten them
 #[test]
-fn unstake_not_staker_fails() {
+fn partial_expiry_keeps_fifo_order() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		PartialExpiryPercent::set(5000);
+		PartialExpiryInterval::set(100);
 
-		assert_noop!(TravelPoints::unstake(RuntimeOrigin::signed(10)), Error::<Test>::NotStaker);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			400,
+			TravelType::Airline,
+			Some(100)
+		));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			300,
+			TravelType::Train,
+			Some(150)
+		));
+
+		// The airline batch halves and moves behind the train batch
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches[0].travel_type, TravelType::Train);
+		assert_eq!(batches[1].travel_type, TravelType::Airline);
+		assert_eq!(batches[1].remaining_points, 200);
+		assert_eq!(batches[1].expires_at_block, 201);
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+
+		// Spending part of the stepped batch leaves less for its last step
+		assert_ok!(TravelPoints::spend_points_of_type(
+			RuntimeOrigin::signed(10),
+			50,
+			2,
+			TravelType::Airline
+		));
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(3), 10));
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].travel_type, TravelType::Train);
+		assert_eq!(TotalPoints::<Test>::get(10), 150);
 	});
 }
 
-/// Test add to reward pool
+/// Award a short, a default and a long lived batch to account 10 at block 1, expiring at
+/// blocks 201, 1001 and 5001
+fn award_batches_with_mixed_expiry() {
+	for (amount, travel_type, expiration) in [
+		(300, TravelType::Train, Some(200)),
+		(500, TravelType::Airline, None),
+		(200, TravelType::Bus, Some(5000)),
+	] {
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			amount,
+			travel_type,
+			expiration
+		));
+	}
+}
+
+/// Expiry of each of account 10's batches, in FIFO order
+fn batch_expiries() -> Vec<(TravelType, u64)> {
+	UserPoints::<Test>::get(10)
+		.iter()
+		.map(|b| (b.travel_type.clone(), b.expires_at_block))
+		.collect()
+}
+
+/// Test with `ResetExpiryOnActivity` spends and awards push live batches out to the default
+/// expiry, keeping longer ones
 #[test]
-fn add_to_reward_pool_works() {
+fn activity_resets_expiry_when_enabled() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		award_batches_with_mixed_expiry();
+		ResetExpiryOnActivity::set(true);
 
-		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(10), 1000));
-		assert_eq!(TravelPoints::reward_pool(), 1000);
+		// DefaultExpirationPeriod is 1000 in tests
+		System::set_block_number(150);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_eq!(
+			batch_expiries(),
+			vec![(TravelType::Train, 1150), (TravelType::Airline, 1150), (TravelType::Bus, 5001)]
+		);
+		assert_eq!(UserPoints::<Test>::get(10)[0].remaining_points, 200);
 
-		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(20), 500));
-		assert_eq!(TravelPoints::reward_pool(), 1500);
+		// An award resets them again, and its own short expiry is raised to the default
+		System::set_block_number(300);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Train,
+			Some(100)
+		));
+		assert_eq!(
+			batch_expiries(),
+			vec![
+				(TravelType::Train, 1300),
+				(TravelType::Airline, 1300),
+				(TravelType::Train, 1300),
+				(TravelType::Bus, 5001),
+			]
+		);
 	});
 }
 
-// ============================================================================
-// ISSUER TRACKING TESTS
-// ============================================================================
-
-/// Test that spending points tracks issuer spending
+/// Test activity leaves batch expiries alone under the default config
 #[test]
-fn issuer_spending_tracked() {
+fn activity_keeps_expiry_by_default() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		award_batches_with_mixed_expiry();
 
-		// Award points
+		System::set_block_number(150);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			1000,
-			TravelType::Airline,
-			None
+			100,
+			TravelType::Train,
+			Some(100)
 		));
-
-		// Spend with issuer 2
-		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
-
-		// Check issuer record
-		let period = TravelPoints::current_period();
-		let record = TravelPoints::get_issuer_period_record(period, &2);
-		assert_eq!(record.points_spent, 300);
-		assert_eq!(record.transaction_count, 1);
-
-		// Check period total
-		assert_eq!(TravelPoints::get_period_total_spent(period), 300);
-
-		// Spend more
-		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
-
-		let record = TravelPoints::get_issuer_period_record(period, &2);
-		assert_eq!(record.points_spent, 500);
-		assert_eq!(record.transaction_count, 2);
+		assert_eq!(
+			batch_expiries(),
+			vec![
+				(TravelType::Train, 201),
+				(TravelType::Train, 250),
+				(TravelType::Airline, 1001),
+				(TravelType::Bus, 5001),
+			]
+		);
 	});
 }
 
 // ============================================================================
-// ADVANCED STAKING TESTS - SLASHING
+// ADMIN AND ISSUER MANAGEMENT TESTS
 // ============================================================================
 
-/// Test slashing a staker for offline behavior
+/// Test authorizing a new issuer
 #[test]
-fn slash_staker_offline_works() {
+fn authorize_issuer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Stake 1000 tokens
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-		assert_eq!(TravelPoints::total_staked(), 1000);
+		// Account 1 is admin, authorize account 5
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 5));
 
-		// Admin slashes for offline (5% = 50 tokens)
-		assert_ok!(TravelPoints::slash_staker(
-			RuntimeOrigin::signed(1),
+		// Account 5 should now be able to issue points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(5),
 			10,
-			crate::SlashReason::Offline
+			100,
+			TravelType::Other,
+			None
 		));
 
-		// Check stake was reduced
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 950); // 1000 - 50
-
-		// Check total slashed updated
-		assert_eq!(TravelPoints::total_slashed(), 50);
+		// Check event
+		System::assert_has_event(Event::IssuerAuthorized { issuer: 5 }.into());
+	});
+}
 
-		// Check slash record exists
-		let records = TravelPoints::get_slash_records(&10);
-		assert_eq!(records.len(), 1);
-		assert_eq!(records[0].amount, 50);
+/// Test that non-admin cannot authorize issuers
+#[test]
+fn authorize_issuer_not_admin_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Account 5 is not admin
+		assert_noop!(
+			TravelPoints::authorize_issuer(RuntimeOrigin::signed(5), 10),
+			Error::<Test>::NotAdmin
+		);
 	});
 }
 
-/// Test slashing for invalid verification (10%)
+/// Test revoking an issuer
 #[test]
-fn slash_staker_invalid_verification_works() {
+fn revoke_issuer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-
-		assert_ok!(TravelPoints::slash_staker(
-			RuntimeOrigin::signed(1),
-			10,
-			crate::SlashReason::InvalidVerification
-		));
+		// Account 2 is pre-authorized, revoke them
+		assert_ok!(TravelPoints::revoke_issuer(RuntimeOrigin::signed(1), 2));
 
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 900); // 1000 - 100 (10%)
+		// Account 2 should no longer be able to issue points
+		assert_noop!(
+			TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::NotAuthorizedIssuer
+		);
 	});
 }
 
-/// Test slashing for malicious behavior (100%)
+/// Test a suppressed issuer's awards update storage without emitting `PointsEarned`
 #[test]
-fn slash_staker_malicious_works() {
+fn suppressed_issuer_skips_routine_events() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		assert_ok!(TravelPoints::set_suppress_events(RuntimeOrigin::signed(1), 3, true));
+		System::assert_last_event(
+			Event::IssuerEventsSuppressionSet { issuer: 3, suppressed: true }.into(),
+		);
 
-		assert_ok!(TravelPoints::slash_staker(
-			RuntimeOrigin::signed(1),
+		System::reset_events();
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(3),
 			10,
-			crate::SlashReason::Malicious
+			500,
+			TravelType::Airline,
+			None
 		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 3));
 
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 0); // 1000 - 1000 (100%)
+		// Balances move as usual, but no routine events were deposited
+		assert_eq!(TravelPoints::total_points(10), 400);
+		assert!(System::events().is_empty());
+
+		// A normal issuer still emits `PointsEarned`
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			200,
+			TravelType::Train,
+			None
+		));
+		System::assert_last_event(
+			Event::PointsEarned {
+				recipient: 10,
+				amount: 200,
+				expires_at_block: 1001,
+				travel_type: TravelType::Train,
+			}
+			.into(),
+		);
 	});
 }
 
-/// Test that non-admin cannot slash
+/// Test only the admin can suppress issuer events
 #[test]
-fn slash_staker_not_admin_fails() {
+fn set_suppress_events_requires_admin() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-
 		assert_noop!(
-			TravelPoints::slash_staker(RuntimeOrigin::signed(5), 10, crate::SlashReason::Offline),
+			TravelPoints::set_suppress_events(RuntimeOrigin::signed(2), 2, true),
 			Error::<Test>::NotAdmin
 		);
 	});
 }
 
-// ============================================================================
-// ADVANCED STAKING TESTS - UNBONDING
-// ============================================================================
-
-/// Test requesting unbonding
+/// Test an issuer renouncing its own authorization keeps its accrued rewards
 #[test]
-fn request_unbond_works() {
+fn renounce_issuer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// First stake
+		// Issuer 2 accrues a reward from a redemption
 		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+		let issuer_reward = TravelPoints::pending_issuer_rewards(&2);
+		assert!(issuer_reward > 0);
 
-		// Request unbonding of 500
-		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+		assert_ok!(TravelPoints::renounce_issuer(RuntimeOrigin::signed(2)));
+		System::assert_last_event(
+			Event::IssuerRevoked { issuer: 2, reason: IssuerRevocationReason::Renounced }.into(),
+		);
 
-		// Check stake reduced
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 500);
+		// No longer able to award points
+		assert_noop!(
+			TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				30,
+				100,
+				TravelType::Airline,
+				None
+			),
+			Error::<Test>::NotAuthorizedIssuer
+		);
 
-		// Check unbonding request created
-		let requests = TravelPoints::get_unbonding_requests(&10);
-		assert_eq!(requests.len(), 1);
-		assert_eq!(requests[0].amount, 500);
-		assert_eq!(requests[0].requested_at, 1);
-		assert_eq!(requests[0].unlocks_at, 51); // 1 + 50 (unbonding period)
+		// The accrued reward can still be claimed
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(2)));
+		System::assert_last_event(
+			Event::RewardClaimed { account: 2, amount: issuer_reward }.into(),
+		);
+		assert_eq!(TravelPoints::pending_issuer_rewards(&2), 0);
 	});
 }
 
-/// Test withdrawing unbonded tokens after period ends
+/// Test active issuers are protected from pruning and inactive ones can be pruned by anyone
 #[test]
-fn withdraw_unbonded_works() {
+fn prune_inactive_issuer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
-
-		// Cannot withdraw before unbonding period ends
-		System::set_block_number(40);
+		// Period 4: the inactivity period is 5 periods in tests
+		System::set_block_number(450);
 		assert_noop!(
-			TravelPoints::withdraw_unbonded(RuntimeOrigin::signed(10)),
-			Error::<Test>::UnbondingNotComplete
+			TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 3),
+			Error::<Test>::IssuerStillActive
 		);
+		// A spend at issuer 2 keeps it active
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_eq!(TravelPoints::issuer_last_active_period(2), 4);
 
-		// Move past unbonding period
-		System::set_block_number(60);
-
-		// Now can withdraw
-		assert_ok!(TravelPoints::withdraw_unbonded(RuntimeOrigin::signed(10)));
+		// Period 5: issuer 3 has been idle since its authorization in period 0
+		System::set_block_number(500);
+		assert_noop!(
+			TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 2),
+			Error::<Test>::IssuerStillActive
+		);
+		assert_ok!(TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 3));
+		System::assert_last_event(
+			Event::IssuerRevoked { issuer: 3, reason: IssuerRevocationReason::Inactive }.into(),
+		);
+		assert!(!TravelPoints::authorized_issuers(3));
+		assert_noop!(
+			TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 3),
+			Error::<Test>::NotAuthorized
+		);
 
-		// Check unbonding requests cleared
-		let requests = TravelPoints::get_unbonding_requests(&10);
-		assert_eq!(requests.len(), 0);
+		// Issuer 2 becomes prunable 5 periods after its last spend
+		System::set_block_number(900);
+		assert_ok!(TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 2));
 	});
 }
 
-/// Test cancelling unbonding
+/// Test the v2 migration starts the inactivity clock of issuers authorized before it, so
+/// they cannot be pruned right after the upgrade
 #[test]
-fn cancel_unbonding_works() {
+fn migration_v2_protects_existing_issuers_from_pruning() {
+	use crate::{migrations::v2, IssuerLastActivePeriod};
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		// Issuers authorized before version 2 have no activity record
+		IssuerLastActivePeriod::<Test>::remove(3);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
-
-		// Verify stake reduced
-		let stake_before = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_before.amount, 500);
-
-		// Cancel unbonding
-		assert_ok!(TravelPoints::cancel_unbonding(RuntimeOrigin::signed(10)));
+		System::set_block_number(1000);
+		StorageVersion::new(1).put::<TravelPoints>();
+		v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
 
-		// Verify stake restored
-		let stake_after = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_after.amount, 1000);
+		assert_eq!(TravelPoints::issuer_last_active_period(3), 10);
+		assert_noop!(
+			TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 3),
+			Error::<Test>::IssuerStillActive
+		);
 
-		// Verify requests cleared
-		let requests = TravelPoints::get_unbonding_requests(&10);
-		assert_eq!(requests.len(), 0);
+		// Idle for the full inactivity period after the upgrade
+		System::set_block_number(1500);
+		assert_ok!(TravelPoints::prune_inactive_issuer(RuntimeOrigin::signed(99), 3));
 	});
 }
 
-// ============================================================================
-// ADVANCED STAKING TESTS - DELEGATION AND POOLS
-// ============================================================================
-
-/// Test creating a staking pool
+/// Test only authorized issuers can renounce
 #[test]
-fn create_pool_works() {
+fn renounce_issuer_not_authorized_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Create pool with 1000 stake and 10% commission
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
-
-		// Check pool created
-		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
-		assert_eq!(pool.operator, 10);
-		assert_eq!(pool.total_stake, 1000);
-		assert_eq!(pool.operator_stake, 1000);
-		assert_eq!(pool.commission, 1000);
-		assert!(pool.is_active);
-		assert_eq!(pool.delegator_count, 0);
-
-		// Check next pool ID incremented
-		assert_eq!(TravelPoints::next_pool_id(), 1);
-
-		// Check total staked updated
-		assert_eq!(TravelPoints::total_staked(), 1000);
+		assert_noop!(
+			TravelPoints::renounce_issuer(RuntimeOrigin::signed(10)),
+			Error::<Test>::NotAuthorized
+		);
 	});
 }
 
-/// Test creating pool with insufficient stake fails
+/// Test changing admin
 #[test]
-fn create_pool_insufficient_stake_fails() {
+fn set_admin_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Min pool operator stake is 500 in tests
+		// Account 1 is admin, set account 5 as new admin
+		assert_ok!(TravelPoints::set_admin(RuntimeOrigin::signed(1), 5));
+
+		// Account 1 should no longer be admin
 		assert_noop!(
-			TravelPoints::create_pool(RuntimeOrigin::signed(10), 100, 1000),
-			Error::<Test>::InsufficientOperatorStake
+			TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 10),
+			Error::<Test>::NotAdmin
 		);
+
+		// Account 5 should be admin now
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(5), 10));
 	});
 }
 
-/// Test creating pool with excessive commission fails
+/// Test per-user spend is tracked per issuer and period
 #[test]
-fn create_pool_excessive_commission_fails() {
+fn user_issuer_period_spend_tracks_spending() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Max commission is 5000 (50%) in tests
-		assert_noop!(
-			TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 6000),
-			Error::<Test>::CommissionTooHigh
-		);
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 50, 3));
+
+		// Block 1 falls in period 0
+		assert_eq!(TravelPoints::user_issuer_spend((0, 10, 2)), 300);
+		assert_eq!(TravelPoints::user_issuer_spend((0, 10, 3)), 50);
+		assert_eq!(TravelPoints::user_issuer_spend((1, 10, 2)), 0);
+
+		// Spending in the next period is recorded separately
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_eq!(TravelPoints::user_issuer_spend((1, 10, 2)), 100);
+		assert_eq!(TravelPoints::user_issuer_spend((0, 10, 2)), 300);
 	});
 }
 
-/// Test delegating to a pool
+/// Test admin can reset a user's spend record for an issuer
 #[test]
-fn delegate_works() {
+fn reset_user_issuer_spend_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Create pool first
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_eq!(TravelPoints::user_issuer_spend((0, 10, 2)), 300);
 
-		// Delegate to pool
-		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+		// Only the admin can reset
+		assert_noop!(
+			TravelPoints::reset_user_issuer_spend(RuntimeOrigin::signed(10), 10, 2, 0),
+			Error::<Test>::NotAdmin
+		);
 
-		// Check delegation recorded
-		let delegation = TravelPoints::get_delegation(&20).expect("Delegation should exist");
-		assert_eq!(delegation.pool_id, 0);
-		assert_eq!(delegation.amount, 500);
+		assert_ok!(TravelPoints::reset_user_issuer_spend(RuntimeOrigin::signed(1), 10, 2, 0));
+		assert_eq!(TravelPoints::user_issuer_spend((0, 10, 2)), 0);
 
-		// Check pool updated
-		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
-		assert_eq!(pool.total_stake, 1500);
-		assert_eq!(pool.delegator_count, 1);
+		System::assert_last_event(
+			Event::UserIssuerSpendReset { user: 10, issuer: 2, period: 0, previous_amount: 300 }
+				.into(),
+		);
 
-		// Check delegator list
-		let delegators = TravelPoints::get_pool_delegators(0);
-		assert!(delegators.contains(&20));
+		// Balance is unaffected by the reset
+		assert_eq!(TotalPoints::<Test>::get(10), 700);
 	});
 }
 
-/// Test cannot delegate below minimum
+/// Test a user may spend with at most `MaxIssuersPerUserPerPeriod` distinct issuers per period
 #[test]
-fn delegate_below_minimum_fails() {
+fn spending_with_too_many_issuers_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
-
-		// Min stake is 100 in tests
-		assert_noop!(
-			TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 50),
-			Error::<Test>::DelegationBelowMinimum
-		);
+		for issuer in [3, 4, 5] {
+			assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), issuer));
+		}
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		// MaxIssuersPerUserPerPeriod is 3 in tests; repeat spends at an issuer don't count
+		for issuer in [2, 3, 4, 2] {
+			assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 50, issuer));
+		}
+		assert_noop!(
+			TravelPoints::spend_points(RuntimeOrigin::signed(10), 50, 5),
+			Error::<Test>::TooManyIssuersThisPeriod
+		);
+
+		// The limit starts over in the next period
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 50, 5));
+		assert_eq!(TotalPoints::<Test>::get(10), 750);
 	});
 }
 
-/// Test undelegating from a pool
+/// Test `MaxUserSpendPerPeriod` caps a user's spends across all issuers per period
 #[test]
-fn undelegate_works() {
+fn user_spend_cap_applies_across_issuers() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		MaxUserSpendPerPeriod::set(500);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
-		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
-
-		// Undelegate
-		assert_ok!(TravelPoints::undelegate(RuntimeOrigin::signed(20)));
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
 
-		// Check delegation removed
-		assert!(TravelPoints::get_delegation(&20).is_none());
+		// Spending up to the cap is fine, split over two issuers
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 3));
+		assert_eq!(crate::UserPeriodSpend::<Test>::get(0, 10), 500);
+
+		// One more point at either issuer goes past it
+		for issuer in [2, 3] {
+			assert_noop!(
+				TravelPoints::spend_points(RuntimeOrigin::signed(10), 1, issuer),
+				Error::<Test>::UserSpendCapExceeded
+			);
+		}
 
-		// Check pool updated
-		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
-		assert_eq!(pool.total_stake, 1000);
-		assert_eq!(pool.delegator_count, 0);
+		// The cap starts over in the next period
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 200);
 	});
 }
 
-/// Test updating pool commission
+/// Test per-user spend totals are only tracked while the cap is enabled
 #[test]
-fn set_pool_commission_works() {
+fn user_period_spend_untracked_without_cap() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
-
-		// Update commission
-		assert_ok!(TravelPoints::set_pool_commission(RuntimeOrigin::signed(10), 0, 2000));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert!(!crate::UserPeriodSpend::<Test>::contains_key(0, 10));
 
-		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
-		assert_eq!(pool.commission, 2000);
+		// Resetting an untracked spend does not start tracking it either
+		assert_ok!(TravelPoints::reset_user_issuer_spend(RuntimeOrigin::signed(1), 10, 2, 0));
+		assert!(!crate::UserPeriodSpend::<Test>::contains_key(0, 10));
 	});
 }
 
-/// Test non-operator cannot update commission
+/// Test the spend totals of an ended period are pruned in chunks
 #[test]
-fn set_pool_commission_not_operator_fails() {
+fn prune_user_period_spend_after_period_ends() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		MaxUserSpendPerPeriod::set(500);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		for user in [10, 11, 12] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				user,
+				1000,
+				TravelType::Airline,
+				None
+			));
+			assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(user), 100, 2));
+		}
 
+		// Period 0 is still running
 		assert_noop!(
-			TravelPoints::set_pool_commission(RuntimeOrigin::signed(20), 0, 2000),
-			Error::<Test>::NotPoolOperator
+			TravelPoints::prune_user_period_spend(RuntimeOrigin::signed(99), 0),
+			Error::<Test>::PeriodNotEnded
 		);
+
+		// MaxPeriodEntriesPruned = 2
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::prune_user_period_spend(RuntimeOrigin::signed(99), 0));
+		System::assert_last_event(Event::UserPeriodSpendPruned { period: 0, removed: 2 }.into());
+		assert_ok!(TravelPoints::prune_user_period_spend(RuntimeOrigin::signed(99), 0));
+		System::assert_last_event(Event::UserPeriodSpendPruned { period: 0, removed: 1 }.into());
+		assert_eq!(crate::UserPeriodSpend::<Test>::iter_prefix(0).count(), 0);
 	});
 }
 
-/// Test closing a pool
+/// Test a retroactive bonus credits each of a past period's redeemers in proportion to
+/// their spend, once
 #[test]
-fn close_pool_works() {
+fn retroactive_bonus_rewards_past_redeemers() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		for user in [10, 11, 12] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				user,
+				1000,
+				TravelType::Airline,
+				None
+			));
+		}
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(11), 500, 2));
+		// Spending at another issuer does not count towards issuer 2's bonus
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(12), 400, 3));
 
-		// Close pool
-		assert_ok!(TravelPoints::close_pool(RuntimeOrigin::signed(10), 0));
+		// Period 0 is still running
+		assert_noop!(
+			TravelPoints::retroactive_bonus(RuntimeOrigin::signed(2), 0, 1000),
+			Error::<Test>::PeriodNotEnded
+		);
 
-		// Check pool removed
-		assert!(TravelPoints::get_pool(0).is_none());
+		System::set_block_number(101);
+		assert_noop!(
+			TravelPoints::retroactive_bonus(RuntimeOrigin::signed(2), 0, 0),
+			Error::<Test>::InvalidBonusRate
+		);
+		assert_noop!(
+			TravelPoints::retroactive_bonus(RuntimeOrigin::signed(99), 0, 1000),
+			Error::<Test>::NotAuthorizedIssuer
+		);
 
-		// Check total staked reduced
-		assert_eq!(TravelPoints::total_staked(), 0);
+		// A 10% bonus on 300 and 500 points spent
+		assert_ok!(TravelPoints::retroactive_bonus(RuntimeOrigin::signed(2), 0, 1000));
+		System::assert_last_event(
+			Event::RetroactiveBonusAwarded { issuer: 2, period: 0, recipients: 2, total: 80 }
+				.into(),
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 730);
+		assert_eq!(TotalPoints::<Test>::get(11), 550);
+		assert_eq!(TotalPoints::<Test>::get(12), 600);
+
+		// Redeemers are only credited once
+		assert_ok!(TravelPoints::retroactive_bonus(RuntimeOrigin::signed(2), 0, 1000));
+		System::assert_last_event(
+			Event::RetroactiveBonusAwarded { issuer: 2, period: 0, recipients: 0, total: 0 }.into(),
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 730);
 	});
 }
 
-/// Test cannot close pool with delegators
+/// Test the redeemers of a period past its bonus window can no longer be bonused and are
+/// pruned in chunks
 #[test]
-fn close_pool_with_delegators_fails() {
+fn prune_period_redeemers_after_bonus_window() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
-		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+		for user in [10, 11, 12] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				user,
+				1000,
+				TravelType::Airline,
+				None
+			));
+			assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(user), 100, 2));
+		}
 
+		// RetroactiveBonusWindow = 3: period 0 can be bonused until period 3
+		System::set_block_number(301);
 		assert_noop!(
-			TravelPoints::close_pool(RuntimeOrigin::signed(10), 0),
-			Error::<Test>::PoolHasDelegators
+			TravelPoints::prune_period_redeemers(RuntimeOrigin::signed(99), 2, 0),
+			Error::<Test>::RetroactiveBonusWindowOpen
+		);
+
+		System::set_block_number(401);
+		assert_noop!(
+			TravelPoints::retroactive_bonus(RuntimeOrigin::signed(2), 0, 1000),
+			Error::<Test>::RetroactiveBonusWindowClosed
+		);
+
+		// MaxRetroactiveRecipients = 2 per call
+		assert_ok!(TravelPoints::prune_period_redeemers(RuntimeOrigin::signed(99), 2, 0));
+		System::assert_last_event(
+			Event::PeriodRedeemersPruned { issuer: 2, period: 0, removed: 2 }.into(),
+		);
+		assert_ok!(TravelPoints::prune_period_redeemers(RuntimeOrigin::signed(99), 2, 0));
+		System::assert_last_event(
+			Event::PeriodRedeemersPruned { issuer: 2, period: 0, removed: 1 }.into(),
 		);
+		assert_eq!(crate::IssuerPeriodRedeemers::<Test>::iter_prefix((0u64, 2u64)).count(), 0);
 	});
 }
 
 // ============================================================================
-// ADVANCED STAKING TESTS - ERA ROTATION AND VERIFIERS
+// MULTIPLE BATCHES AND COMPLEX SCENARIOS
 // ============================================================================
 
-/// Test era rotation and verifier selection
+/// Test having multiple batches with different travel types
 #[test]
-fn rotate_era_works() {
+fn multiple_travel_types_work() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Create multiple stakers with different stakes
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 500));
-
-		// Move past blocks per era (200 in tests)
-		System::set_block_number(201);
+		// Award airline points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			Some(500)
+		));
 
-		// Rotate era
-		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		System::set_block_number(2);
 
-		// Check era incremented
-		assert_eq!(TravelPoints::current_era(), 1);
+		// Award train points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			200,
+			TravelType::Train,
+			Some(600)
+		));
 
-		// Check verifiers selected (should select by highest stake)
-		let verifiers = TravelPoints::get_current_verifiers();
-		assert!(!verifiers.is_empty());
+		System::set_block_number(3);
 
-		// Account 20 should be a verifier (highest stake)
-		assert!(TravelPoints::is_current_verifier(&20));
-	});
-}
+		// Award bus points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			150,
+			TravelType::Bus,
+			Some(700)
+		));
 
-/// Test era rotation not due yet
-#[test]
-fn rotate_era_not_due_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(100); // Less than 200 blocks per era
+		// Check total
+		assert_eq!(TotalPoints::<Test>::get(10), 450);
 
-		assert_noop!(
-			TravelPoints::rotate_era(RuntimeOrigin::signed(99)),
-			Error::<Test>::EraRotationNotDue
-		);
+		// Check batches are sorted by expiration (FIFO order)
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 3);
+		assert_eq!(batches[0].travel_type, TravelType::Airline); // expires first
+		assert_eq!(batches[1].travel_type, TravelType::Train);
+		assert_eq!(batches[2].travel_type, TravelType::Bus); // expires last
 	});
 }
 
-// ============================================================================
-// ADVANCED STAKING TESTS - REWARDS
-// ============================================================================
-
-/// Test distributing rewards
+/// Test a user cannot accumulate more distinct travel types than allowed
 #[test]
-fn distribute_rewards_works() {
+fn award_points_too_many_travel_types_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Setup: Add staker and issuer spending
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		// MaxTravelTypesPerUser is 3 in tests
+		for travel_type in [TravelType::Airline, TravelType::Train, TravelType::Bus] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				travel_type,
+				None
+			));
+		}
 
-		// Add to reward pool
-		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		// A fourth distinct type is rejected
+		assert_noop!(
+			TravelPoints::award_points(RuntimeOrigin::signed(2), 10, 100, TravelType::Other, None),
+			Error::<Test>::TooManyTravelTypes
+		);
 
-		// Award and spend points to create issuer tracking
+		// More batches of an existing type are still fine
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
-			30,
-			1000,
-			crate::TravelType::Airline,
+			10,
+			100,
+			TravelType::Train,
 			None
 		));
-		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+		assert_eq!(TotalPoints::<Test>::get(10), 400);
+	});
+}
 
-		let period = TravelPoints::current_period();
+/// Test spending across multiple batches completely empties some
+#[test]
+fn spend_across_batches_removes_empty() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 
-		// Distribute rewards
-		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+		// Award 3 batches of 100 each
+		for i in 0..3 {
+			System::set_block_number(1 + i);
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				TravelType::Airline,
+				None
+			));
+		}
 
-		// Check reward pool emptied
-		assert_eq!(TravelPoints::reward_pool(), 0);
+		assert_eq!(TotalPoints::<Test>::get(10), 300);
+		assert_eq!(UserPoints::<Test>::get(10).len(), 3);
 
-		// Check pending rewards created (staker gets 80%, issuer gets 20%)
-		let staker_rewards = TravelPoints::pending_staker_rewards(&10);
-		assert!(staker_rewards > 0);
+		// Spend 250 - should empty first 2 batches and take 50 from third
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 250, 2));
+
+		// Only 1 batch left with 50 points
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 50);
+		assert_eq!(TotalPoints::<Test>::get(10), 50);
 	});
 }
 
-/// Test claiming rewards
+/// Test the helper function for checking available points
 #[test]
-fn claim_rewards_works() {
+fn get_available_points_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
-		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
-
-		// Award and spend points
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
-			30,
-			1000,
-			crate::TravelType::Airline,
-			None
-		));
-		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+			10,
+			500,
+			TravelType::Airline,
+			Some(100)
+		));
 
-		let period = TravelPoints::current_period();
-		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+		// Before expiration
+		assert_eq!(TravelPoints::get_available_points(&10), 500);
 
-		// Claim rewards
-		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		// After expiration
+		System::set_block_number(150);
+		assert_eq!(TravelPoints::get_available_points(&10), 0);
+	});
+}
 
-		// Check pending rewards cleared
-		assert_eq!(TravelPoints::pending_staker_rewards(&10), 0);
+/// Test the helper function for point details
+#[test]
+fn get_point_details_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			Some(100)
+		));
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			300,
+			TravelType::Train,
+			Some(200)
+		));
+
+		let details = TravelPoints::get_point_details(&10);
+		assert_eq!(details.len(), 2);
+		assert_eq!(details[0], (500, 101, TravelType::Airline));
+		assert_eq!(details[1], (300, 201, TravelType::Train));
 	});
 }
 
-/// Test claim rewards with no pending fails
+/// Test point details are only readable by the user and authorized issuers
 #[test]
-fn claim_rewards_none_pending_fails() {
+fn get_point_details_authorized_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			Some(100)
+		));
+		let expected = vec![(500, 101, TravelType::Airline)];
+
+		// Authorized issuer
+		assert_eq!(TravelPoints::get_point_details_authorized(&2, &10), Ok(expected.clone()));
+
+		// The user themselves
+		assert_eq!(TravelPoints::get_point_details_authorized(&10, &10), Ok(expected));
+
+		// A stranger, even the admin, is rejected
 		assert_noop!(
-			TravelPoints::claim_rewards(RuntimeOrigin::signed(10)),
-			Error::<Test>::NoRewardsToClaim
+			TravelPoints::get_point_details_authorized(&20, &10),
+			Error::<Test>::PointDetailsNotVisible
+		);
+		assert_noop!(
+			TravelPoints::get_point_details_authorized(&1, &10),
+			Error::<Test>::PointDetailsNotVisible
 		);
+
+		// The public balance stays readable by anyone
+		assert_eq!(TravelPoints::get_available_points(&10), 500);
 	});
 }
 
-// ============================================================================
-// ADVANCED STAKING TESTS - INCREASE STAKE
-// ============================================================================
-
-/// Test increasing stake
+/// Test live points are summed per travel type
 #[test]
-fn increase_stake_works() {
+fn points_by_travel_type_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
-		assert_eq!(TravelPoints::total_staked(), 500);
+		assert_eq!(TravelPoints::points_by_travel_type(10), vec![]);
 
-		// Increase stake
-		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(10), 300));
+		// Airline points in two batches, bus points expiring early, train points
+		for (amount, travel_type, expiration) in [
+			(2000, TravelType::Airline, Some(300)),
+			(1000, TravelType::Airline, None),
+			(500, TravelType::Bus, Some(100)),
+			(400, TravelType::Train, None),
+		] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				amount,
+				travel_type,
+				expiration
+			));
+		}
 
-		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
-		assert_eq!(stake_info.amount, 800);
-		assert_eq!(TravelPoints::total_staked(), 800);
+		// FIFO spend takes 200 from the bus batch, which expires first
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
+		assert_eq!(
+			TravelPoints::points_by_travel_type(10),
+			vec![(TravelType::Bus, 300), (TravelType::Airline, 3000), (TravelType::Train, 400)]
+		);
+
+		// Once the bus batch expires, bus points are no longer listed
+		System::set_block_number(101);
+		assert_eq!(
+			TravelPoints::points_by_travel_type(10),
+			vec![(TravelType::Airline, 3000), (TravelType::Train, 400)]
+		);
 	});
 }
 
-/// Test increasing stake without existing stake fails
+/// Test awards compact same-type, same-expiry batches at the high-water mark
 #[test]
-fn increase_stake_not_staker_fails() {
+fn award_compacts_batches_at_high_water_mark() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_noop!(
-			TravelPoints::increase_stake(RuntimeOrigin::signed(10), 300),
-			Error::<Test>::NotStaker
+		// 90 awards, two per expiry block, fill the user to the 90% mark
+		for i in 0..90u64 {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				TravelType::Airline,
+				Some(1000 + i / 2)
+			));
+		}
+		assert_eq!(TravelPoints::batch_utilization(10), (90, 100));
+
+		// The next award merges the pairs before adding its own batch
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			Some(2000)
+		));
+		System::assert_has_event(
+			Event::PointBatchesCompacted { user: 10, batches_before: 90, batches_after: 45 }.into(),
 		);
+		assert_eq!(TravelPoints::batch_utilization(10), (46, 100));
+		assert_eq!(TotalPoints::<Test>::get(10), 9100);
+
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches[0].remaining_points, 200);
+		assert_eq!(batches[0].expires_at_block, 1001);
+		assert_eq!(batches[45].remaining_points, 100);
+		assert_eq!(batches[45].expires_at_block, 2001);
 	});
 }
 
-// ============================================================================
-// TICKET UNMINT (BURN) TESTS
-// ============================================================================
-
-/// Test unminting a ticket by owner
+/// Test compaction leaves batches with demurrage still owed apart, and a merged batch keeps
+/// the later earning block for the holding period
 #[test]
-fn unmint_ticket_works() {
+fn compaction_keeps_owed_demurrage_and_holding_period() {
 	new_test_ext().execute_with(|| {
+		// Pairs of batches sharing an expiry block, earned 10 blocks apart
 		System::set_block_number(1);
+		for i in 0..45u64 {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				1000,
+				TravelType::Airline,
+				Some(1000 + i)
+			));
+		}
+		System::set_block_number(11);
+		for i in 0..45u64 {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				1000,
+				TravelType::Airline,
+				Some(990 + i)
+			));
+		}
 
-		// Mint a ticket for user 10
-		assert_ok!(TravelPoints::mint_ticket(
+		// The pairs owe different demurrage, so nothing merges
+		System::set_block_number(20);
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::PlaneTicket,
-			0,
-			None,
-			b"Test User".to_vec(),
-			b"AB123".to_vec(),
-			b"A12".to_vec(),
-			b"15A".to_vec(),
-			b"New York".to_vec(),
-			b"Los Angeles".to_vec(),
-			b"2024-03-15 10:00".to_vec(),
-			b"Business Class".to_vec(),
+			1000,
+			TravelType::Airline,
+			Some(2000)
 		));
+		assert_eq!(TravelPoints::batch_utilization(10), (91, 100));
 
-		// Verify ticket exists
-		assert!(TravelPoints::get_ticket(0).is_some());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
-
-		// Unmint the ticket
-		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
-
-		// Verify ticket was removed
-		assert!(TravelPoints::get_ticket(0).is_none());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+		// Once demurrage has been charged on every batch, the pairs merge
+		assert_ok!(TravelPoints::apply_demurrage(RuntimeOrigin::signed(99), 10));
+		System::set_block_number(21);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			Some(2000)
+		));
+		assert_eq!(TravelPoints::batch_utilization(10), (47, 100));
 
-		// Check event
-		System::assert_last_event(Event::TicketUnminted { ticket_id: 0, owner: 10 }.into());
+		let merged = &UserPoints::<Test>::get(10)[0];
+		assert_eq!(merged.expires_at_block, 1001);
+		assert_eq!(merged.earned_at_block, 11);
+		assert_eq!(merged.last_demurrage_block, 20);
 	});
 }
 
-/// Test unminting a redeemed ticket works
+/// Test the soonest-expiring live batch is reported
 #[test]
-fn unmint_redeemed_ticket_works() {
+fn next_expiring_batch_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint and redeem a ticket
-		assert_ok!(TravelPoints::mint_ticket(
+		// A user without points has nothing expiring
+		assert_eq!(TravelPoints::next_expiring_batch(10), None);
+
+		// Awarded out of expiry order
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::TrainTicket,
-			0,
-			None,
-			b"Test User".to_vec(),
-			b"TR456".to_vec(),
-			b"".to_vec(),
-			b"22B".to_vec(),
-			b"Chicago".to_vec(),
-			b"Detroit".to_vec(),
-			b"2024-04-01 14:00".to_vec(),
-			b"".to_vec(),
+			300,
+			TravelType::Train,
+			Some(200)
+		));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			Some(100)
 		));
 
-		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		assert_eq!(TravelPoints::next_expiring_batch(10), Some((500, 101, TravelType::Airline)));
 
-		// Can still unmint the redeemed ticket
-		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+		// Once the earliest batch expires, the next one is reported
+		System::set_block_number(101);
+		assert_eq!(TravelPoints::next_expiring_batch(10), Some((300, 201, TravelType::Train)));
 
-		assert!(TravelPoints::get_ticket(0).is_none());
+		System::set_block_number(201);
+		assert_eq!(TravelPoints::next_expiring_batch(10), None);
 	});
 }
 
-/// Test unminting non-existent ticket fails
+// ============================================================================
+// CONTRACT INTERFACE TESTS
+// ============================================================================
+
+/// Test the contract interface for awarding points
 #[test]
-fn unmint_ticket_not_found_fails() {
+fn contract_award_points_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		assert_noop!(
-			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 999),
-			Error::<Test>::TicketNotFound
-		);
+		// Use the contract interface
+		assert_ok!(TravelPoints::contract_award_points(
+			2,   // issuer (pre-authorized)
+			10,  // recipient
+			500, // amount
+			TravelType::Airline,
+			None
+		));
+
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
 	});
 }
 
-/// Test unminting ticket by non-owner fails
+/// Test the detailed contract award reports the resulting state
 #[test]
-fn unmint_ticket_not_owner_fails() {
+fn contract_award_points_detailed_matches_storage() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		assert_ok!(TravelPoints::contract_award_points(2, 10, 300, TravelType::Train, None));
 
-		// Mint a ticket for user 10
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
+		let outcome = TravelPoints::contract_award_points_detailed(
+			2,
 			10,
-			TicketType::BusTicket,
-			0,
-			None,
-			b"Test".to_vec(),
-			b"BUS001".to_vec(),
-			b"".to_vec(),
-			b"5".to_vec(),
-			b"City A".to_vec(),
-			b"City B".to_vec(),
-			b"2024-05-01 09:00".to_vec(),
-			b"".to_vec(),
-		));
+			500,
+			TravelType::Airline,
+			Some(200),
+		)
+		.unwrap();
 
-		// User 20 tries to unmint (not owner)
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(
+			outcome,
+			crate::AwardOutcome {
+				expires_at_block: 201,
+				new_total_balance: 800,
+				batch_count: 2,
+				amount: 500
+			}
+		);
+		assert_eq!(outcome.new_total_balance, TotalPoints::<Test>::get(10));
+		assert_eq!(outcome.batch_count, batches.len() as u32);
+		assert!(batches.iter().any(|b| b.expires_at_block == outcome.expires_at_block));
+
+		// Failures are reported like `contract_award_points`
 		assert_noop!(
-			TravelPoints::unmint_ticket(RuntimeOrigin::signed(20), 0),
-			Error::<Test>::NotTicketOwner
+			TravelPoints::contract_award_points_detailed(10, 20, 500, TravelType::Airline, None),
+			Error::<Test>::NotAuthorizedIssuer
 		);
 	});
 }
 
-/// Test double unmint fails
+/// Test the contract balance check interface
 #[test]
-fn unmint_ticket_double_unmint_fails() {
+fn contract_check_balance_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket
-		assert_ok!(TravelPoints::mint_ticket(
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::Bonus,
-			0,
-			None,
-			b"Test".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"Lounge Access".to_vec(),
+			1000,
+			TravelType::Airline,
+			None
 		));
 
-		// First unmint succeeds
-		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+		// Use contract interface to check balance
+		assert_eq!(TravelPoints::contract_check_balance(&10), 1000);
+	});
+}
 
-		// Second unmint fails (ticket already removed)
-		assert_noop!(
-			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0),
-			Error::<Test>::TicketNotFound
-		);
+/// Test the contract issuer check interface
+#[test]
+fn contract_is_authorized_issuer_works() {
+	new_test_ext().execute_with(|| {
+		// Account 2 is pre-authorized
+		assert!(TravelPoints::contract_is_authorized_issuer(&2));
+
+		// Account 5 is not authorized
+		assert!(!TravelPoints::contract_is_authorized_issuer(&5));
 	});
 }
 
+/// Test that error indices released before new errors were added keep their values, so
+/// clients decoding `ModuleError` are not broken by appended variants
+#[test]
+fn error_indices_are_stable() {
+	use sp_runtime::DispatchError;
+
+	let index_of = |error: Error<Test>| match DispatchError::from(error) {
+		DispatchError::Module(module_error) => module_error.error[0],
+		other => panic!("unexpected dispatch error {other:?}"),
+	};
+
+	assert_eq!(index_of(Error::<Test>::NotAuthorizedIssuer), 0);
+	assert_eq!(index_of(Error::<Test>::InsufficientPoints), 2);
+	assert_eq!(index_of(Error::<Test>::TooManyBatches), 3);
+	assert_eq!(index_of(Error::<Test>::ArithmeticOverflow), 4);
+	assert_eq!(index_of(Error::<Test>::TicketNotFound), 10);
+	assert_eq!(index_of(Error::<Test>::StakeBelowMinimum), 15);
+	assert_eq!(index_of(Error::<Test>::UnbondingNotComplete), 22);
+	assert_eq!(index_of(Error::<Test>::InsufficientBalance), 39);
+}
+
 // ============================================================================
-// FORCE UNMINT (ADMIN) TESTS
+// NFT TICKET TESTS
 // ============================================================================
 
-/// Test force unminting by admin
+/// Mint a free bus ticket with placeholder details for `owner` using pre-authorized issuer 2
+fn mint_basic_ticket(owner: u64, expires_at: Option<u64>) {
+	assert_ok!(TravelPoints::mint_ticket(
+		RuntimeOrigin::signed(2),
+		owner,
+		TicketType::BusTicket,
+		0,
+		expires_at,
+		b"Passenger".to_vec(),
+		b"BUS001".to_vec(),
+		b"".to_vec(),
+		b"1".to_vec(),
+		b"City A".to_vec(),
+		b"City B".to_vec(),
+		b"2024-05-01 09:00".to_vec(),
+		b"".to_vec(),
+		None,
+		None,
+		1,
+		1,
+	));
+}
+
+/// Test minting a ticket NFT
 #[test]
-fn force_unmint_ticket_works() {
+fn mint_ticket_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket for user 10
-		assert_ok!(TravelPoints::mint_ticket(
+		// First award some points to the user
+		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		// Mint a ticket (costs 500 points)
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2), // issuer
+			10,                       // owner
 			TicketType::PlaneTicket,
-			0,
+			500,                          // points cost
+			Some(2000),                   // expires at
+			b"John Doe".to_vec(),         // passenger_name
+			b"AB123".to_vec(),            // travel_number
+			b"A12".to_vec(),              // gate
+			b"15A".to_vec(),              // seat
+			b"New York".to_vec(),         // departure
+			b"Los Angeles".to_vec(),      // arrival
+			b"2024-03-15 10:00".to_vec(), // departure_time
+			b"Business Class".to_vec(),   // metadata
 			None,
-			b"Test User".to_vec(),
-			b"AB123".to_vec(),
-			b"A12".to_vec(),
-			b"15A".to_vec(),
-			b"New York".to_vec(),
-			b"Los Angeles".to_vec(),
-			b"2024-03-15 10:00".to_vec(),
-			b"".to_vec(),
+			None,
+			1,
+			1,
 		));
 
-		// Admin (account 1) force unmints the ticket
-		assert_ok!(TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(1), 0));
+		// Check points were deducted
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
 
-		// Verify ticket was removed
-		assert!(TravelPoints::get_ticket(0).is_none());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+		// Check ticket was created
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.owner, 10);
+		assert_eq!(ticket.issuer, 2);
+		assert_eq!(ticket.ticket_type, TicketType::PlaneTicket);
+		assert_eq!(ticket.points_cost, 500);
+		assert!(!ticket.is_redeemed);
 
-		// Check event
-		System::assert_last_event(
-			Event::TicketForceUnminted { ticket_id: 0, owner: 10, admin: 1 }.into(),
-		);
+		// Check user owns the ticket
+		let user_tickets = TravelPoints::get_user_tickets(&10);
+		assert_eq!(user_tickets.len(), 1);
+		assert_eq!(user_tickets[0], 0);
 	});
 }
 
-/// Test force unminting by non-admin fails
+/// Test the deduction for a paid ticket always equals its stored `points_cost`, however the
+/// balance is spread over batches and with an expired batch still in storage
 #[test]
-fn force_unmint_ticket_not_admin_fails() {
+fn mint_ticket_deducts_exactly_points_cost() {
+	// (points_cost, remaining live batch amounts)
+	let cases: [(u128, Vec<u128>); 4] =
+		[(100, vec![200, 300]), (250, vec![50, 300]), (450, vec![150]), (600, vec![])];
+
+	for (points_cost, remaining) in cases {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			// 50 points expiring at block 6, then live batches of 100, 200 and 300
+			for (amount, travel_type, expiration) in [
+				(50, TravelType::Airline, Some(5)),
+				(100, TravelType::Airline, Some(100)),
+				(200, TravelType::Train, Some(200)),
+				(300, TravelType::Bus, Some(300)),
+			] {
+				assert_ok!(TravelPoints::award_points(
+					RuntimeOrigin::signed(2),
+					10,
+					amount,
+					travel_type,
+					expiration
+				));
+			}
+
+			System::set_block_number(10);
+			let available_before = TravelPoints::get_available_points(&10);
+			assert_eq!(available_before, 600);
+
+			assert_ok!(TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				TicketType::PlaneTicket,
+				points_cost,
+				None,
+				b"John Doe".to_vec(),
+				b"AB123".to_vec(),
+				b"A12".to_vec(),
+				b"15A".to_vec(),
+				b"New York".to_vec(),
+				b"Los Angeles".to_vec(),
+				b"2024-03-15 10:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			));
+
+			let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+			assert_eq!(ticket.points_cost, points_cost);
+			assert_eq!(TotalPoints::<Test>::get(10), available_before - ticket.points_cost);
+			assert_eq!(TravelPoints::get_available_points(&10), TotalPoints::<Test>::get(10));
+
+			// FIFO deduction leaves exactly the expected batches behind
+			let batches: Vec<u128> =
+				UserPoints::<Test>::get(10).iter().map(|b| b.remaining_points).collect();
+			assert_eq!(batches, remaining);
+		});
+	}
+}
+
+/// Test minting a ticket with no point cost (free ticket)
+#[test]
+fn mint_free_ticket_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a free bonus ticket
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::Bonus,
+			0,    // free
+			None, // no expiration
+			b"Jane Doe".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"Lounge Access".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.ticket_type, TicketType::Bonus);
+		assert_eq!(ticket.points_cost, 0);
+	});
+}
+
+/// Test redeeming a ticket
+#[test]
+fn redeem_ticket_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
@@ -1655,7 +2883,7 @@ fn force_unmint_ticket_not_admin_fails() {
 			TicketType::TrainTicket,
 			0,
 			None,
-			b"Test".to_vec(),
+			b"Test User".to_vec(),
 			b"TR456".to_vec(),
 			b"".to_vec(),
 			b"22B".to_vec(),
@@ -1663,322 +2891,5313 @@ fn force_unmint_ticket_not_admin_fails() {
 			b"Detroit".to_vec(),
 			b"2024-04-01 14:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		));
 
-		// Non-admin (account 5) tries to force unmint
+		// Redeem the ticket
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+
+		// Check ticket is redeemed
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert!(ticket.is_redeemed);
+
+		// Cannot redeem again
 		assert_noop!(
-			TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(5), 0),
-			Error::<Test>::NotAdmin
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::TicketAlreadyRedeemed
 		);
 	});
 }
 
-/// Test force unminting non-existent ticket fails
+/// Test batch ownership verification reports each pair positionally
 #[test]
-fn force_unmint_ticket_not_found_fails() {
+fn verify_ticket_ownership_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Tickets 0 and 1 never expire, ticket 2 expires at block 50
+		for expires_at in [None, None, Some(50)] {
+			assert_ok!(TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				TicketType::TrainTicket,
+				0,
+				expires_at,
+				b"Test User".to_vec(),
+				b"TR456".to_vec(),
+				b"".to_vec(),
+				b"22B".to_vec(),
+				b"Chicago".to_vec(),
+				b"Detroit".to_vec(),
+				b"2024-04-01 14:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			));
+		}
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 1));
+
+		// Valid, wrong owner, non-existent, redeemed, not yet expired
+		let pairs = vec![(0, 10), (0, 11), (99, 10), (1, 10), (2, 10)];
+		assert_eq!(
+			TravelPoints::verify_ticket_ownership(pairs),
+			vec![true, false, false, false, true]
+		);
+
+		// Expired tickets are no longer valid
+		System::set_block_number(50);
+		assert_eq!(
+			TravelPoints::verify_ticket_ownership(vec![(0, 10), (2, 10)]),
+			vec![true, false]
+		);
+
+		// Only the first MaxOwnershipChecks (5) pairs are checked, the rest report false
+		assert_eq!(
+			TravelPoints::verify_ticket_ownership(vec![(0, 10); 7]),
+			vec![true, true, true, true, true, false, false]
+		);
+	});
+}
+
+/// Test batch status checks report each ticket positionally
+#[test]
+fn ticket_statuses_reports_each_ticket() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Ticket 0 never expires, ticket 1 is redeemed, ticket 2 expires at block 50
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(10, Some(50));
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 1));
+
+		assert_eq!(
+			TravelPoints::ticket_statuses(vec![0, 1, 2, 99]),
+			vec![
+				TicketStatus::Valid,
+				TicketStatus::Redeemed,
+				TicketStatus::Valid,
+				TicketStatus::NotFound
+			]
+		);
+
+		System::set_block_number(50);
+		assert_eq!(
+			TravelPoints::ticket_statuses(vec![99, 2, 1, 0]),
+			vec![
+				TicketStatus::NotFound,
+				TicketStatus::Expired,
+				TicketStatus::Redeemed,
+				TicketStatus::Valid
+			]
+		);
+
+		// Only the first MaxTicketStatusQueries (4) IDs are looked up
+		assert_eq!(
+			TravelPoints::ticket_statuses(vec![0; 6]),
+			vec![
+				TicketStatus::Valid,
+				TicketStatus::Valid,
+				TicketStatus::Valid,
+				TicketStatus::Valid,
+				TicketStatus::NotChecked,
+				TicketStatus::NotChecked
+			]
+		);
+	});
+}
+
+/// Test the redemption hook fires exactly once per redemption
+#[test]
+fn redeem_ticket_notifies_handler_once() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(20, None);
+		assert!(redeemed_tickets().is_empty());
+
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		assert_eq!(redeemed_tickets(), vec![(0, 10, TicketType::BusTicket)]);
+
+		// Failed redemptions do not notify the handler
 		assert_noop!(
-			TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(1), 999),
-			Error::<Test>::TicketNotFound
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::TicketAlreadyRedeemed
+		);
+		assert_noop!(
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::NotTicketOwner
+		);
+		assert_eq!(redeemed_tickets().len(), 1);
+
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(20), 1));
+		assert_eq!(
+			redeemed_tickets(),
+			vec![(0, 10, TicketType::BusTicket), (1, 20, TicketType::BusTicket)]
 		);
 	});
 }
 
-// ============================================================================
-// EXPIRED TICKET CLEANUP TESTS
-// ============================================================================
+/// Test a redemption hook cannot re-enter the pallet to award points
+#[test]
+fn redeem_ticket_hook_reentry_is_blocked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 
-/// Test cleanup of expired tickets
+		mint_basic_ticket(10, None);
+		REENTER_ON_REDEEM.with(|r| r.set(true));
+
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+
+		// The nested award was rejected and left no trace
+		REENTRY_RESULTS.with(|r| {
+			assert_eq!(*r.borrow(), vec![Err(Error::<Test>::ReentrancyDetected.into())]);
+		});
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+
+		// The lock is released once the outer call completes
+		assert!(!crate::ReentrancyLock::<Test>::get());
+		assert_ok!(TravelPoints::contract_award_points(2, 10, 100, TravelType::Other, None));
+		assert_eq!(TotalPoints::<Test>::get(10), 100);
+	});
+}
+
+/// Test a round-trip ticket is redeemed one segment at a time
 #[test]
-fn cleanup_expired_tickets_works() {
+fn redeem_multi_segment_ticket() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket that expires at block 100
 		assert_ok!(TravelPoints::mint_ticket(
 			RuntimeOrigin::signed(2),
 			10,
 			TicketType::PlaneTicket,
 			0,
-			Some(100), // Expires at block 100
-			b"Test User".to_vec(),
+			None,
+			b"Passenger".to_vec(),
 			b"AB123".to_vec(),
 			b"A12".to_vec(),
 			b"15A".to_vec(),
 			b"New York".to_vec(),
 			b"Los Angeles".to_vec(),
 			b"2024-03-15 10:00".to_vec(),
-			b"".to_vec(),
+			b"Round trip".to_vec(),
+			None,
+			None,
+			2,
+			1,
 		));
 
-		// Verify ticket exists
-		assert!(TravelPoints::get_ticket(0).is_some());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
-
-		// Move past expiration
-		System::set_block_number(150);
-
-		// Cleanup expired tickets
-		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+		// Outbound leg
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		System::assert_last_event(Event::TicketSegmentRedeemed { ticket_id: 0, segment: 1 }.into());
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.segments_redeemed, 1);
+		assert!(!ticket.is_redeemed);
+		assert!(redeemed_tickets().is_empty());
 
-		// Verify ticket was removed
-		assert!(TravelPoints::get_ticket(0).is_none());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+		// Return leg completes the ticket
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		System::assert_has_event(Event::TicketSegmentRedeemed { ticket_id: 0, segment: 2 }.into());
+		System::assert_last_event(Event::TicketRedeemed { ticket_id: 0, owner: 10 }.into());
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.segments_redeemed, 2);
+		assert!(ticket.is_redeemed);
+		assert_eq!(redeemed_tickets(), vec![(0, 10, TicketType::PlaneTicket)]);
 
-		// Check event
-		System::assert_last_event(
-			Event::ExpiredTicketsCleaned { user: 10, tickets_removed: 1 }.into(),
+		assert_noop!(
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::TicketAlreadyRedeemed
 		);
 	});
 }
 
-/// Test cleanup with no expired tickets does nothing
+/// Test a ticket cannot be minted without segments
 #[test]
-fn cleanup_expired_tickets_no_expired() {
+fn mint_ticket_zero_segments_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket that expires at block 1000
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
-			10,
-			TicketType::TrainTicket,
-			0,
-			Some(1000), // Expires at block 1000
-			b"Test".to_vec(),
-			b"TR456".to_vec(),
-			b"".to_vec(),
-			b"22B".to_vec(),
-			b"Chicago".to_vec(),
-			b"Detroit".to_vec(),
-			b"2024-04-01 14:00".to_vec(),
-			b"".to_vec(),
-		));
-
-		// Still before expiration
-		System::set_block_number(500);
-
-		// Cleanup - but nothing to clean
-		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
-
-		// Ticket should still exist
-		assert!(TravelPoints::get_ticket(0).is_some());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+		assert_noop!(
+			TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				TicketType::BusTicket,
+				0,
+				None,
+				b"Passenger".to_vec(),
+				b"BUS001".to_vec(),
+				b"".to_vec(),
+				b"1".to_vec(),
+				b"City A".to_vec(),
+				b"City B".to_vec(),
+				b"2024-05-01 09:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				0,
+				1,
+			),
+			Error::<Test>::InvalidSegmentCount
+		);
 	});
 }
 
-/// Test cleanup with tickets that have no expiration
+/// Test a group ticket is only redeemed once every seat has been used
 #[test]
-fn cleanup_expired_tickets_no_expiration_date() {
+fn redeem_group_ticket() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint a ticket with no expiration
 		assert_ok!(TravelPoints::mint_ticket(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::Bonus,
+			TicketType::TrainTicket,
 			0,
-			None, // No expiration
-			b"Test".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
+			None,
+			b"Family".to_vec(),
+			b"TR456".to_vec(),
 			b"".to_vec(),
 			b"".to_vec(),
-			b"Lounge Access".to_vec(),
+			b"Chicago".to_vec(),
+			b"Detroit".to_vec(),
+			b"2024-04-01 14:00".to_vec(),
+			b"Family pass".to_vec(),
+			None,
+			None,
+			1,
+			3,
 		));
 
-		// Move far into the future
-		System::set_block_number(1000000);
+		for used in 1..=2 {
+			assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+			System::assert_last_event(
+				Event::TicketSeatUsed { ticket_id: 0, used, capacity: 3 }.into(),
+			);
+			let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+			assert_eq!(ticket.used, used);
+			assert!(!ticket.is_redeemed);
+		}
+		assert!(redeemed_tickets().is_empty());
 
-		// Cleanup - should not remove ticket without expiration
-		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+		// The third traveler fully redeems the ticket
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		System::assert_has_event(
+			Event::TicketSeatUsed { ticket_id: 0, used: 3, capacity: 3 }.into(),
+		);
+		System::assert_last_event(Event::TicketRedeemed { ticket_id: 0, owner: 10 }.into());
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.used, 3);
+		assert!(ticket.is_redeemed);
+		assert_eq!(redeemed_tickets(), vec![(0, 10, TicketType::TrainTicket)]);
 
-		// Ticket should still exist
-		assert!(TravelPoints::get_ticket(0).is_some());
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+		assert_noop!(
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::TicketAlreadyRedeemed
+		);
 	});
 }
 
-/// Test cleanup removes only expired tickets (partial cleanup)
+/// Test a ticket cannot be minted without capacity
 #[test]
-fn cleanup_expired_tickets_partial() {
+fn mint_ticket_zero_capacity_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint ticket that expires at block 50
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
-			10,
-			TicketType::PlaneTicket,
-			0,
-			Some(50), // Expires at block 50
-			b"Early Ticket".to_vec(),
-			b"AB123".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-		));
+		assert_noop!(
+			TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				TicketType::BusTicket,
+				0,
+				None,
+				b"Passenger".to_vec(),
+				b"BUS001".to_vec(),
+				b"".to_vec(),
+				b"1".to_vec(),
+				b"City A".to_vec(),
+				b"City B".to_vec(),
+				b"2024-05-01 09:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				0,
+			),
+			Error::<Test>::InvalidTicketCapacity
+		);
+	});
+}
 
-		// Mint ticket that expires at block 200
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
-			10,
-			TicketType::TrainTicket,
-			0,
-			Some(200), // Expires at block 200
-			b"Late Ticket".to_vec(),
-			b"TR456".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-		));
+/// Test an issuer cannot mint more than `MaxTicketsPerIssuerPerPeriod` tickets in a period
+#[test]
+fn mint_ticket_issuer_cap_per_period() {
+	new_test_ext().execute_with(|| {
+		MaxTicketsPerIssuerPerPeriod::set(2);
+		System::set_block_number(1);
 
-		// Mint ticket with no expiration
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
-			10,
-			TicketType::Bonus,
-			0,
-			None, // No expiration
-			b"Bonus".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-			b"".to_vec(),
-		));
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(20, None);
+		assert_eq!(crate::IssuerPeriodTicketCount::<Test>::get(0, 2), 2);
 
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 3);
+		assert_noop!(
+			TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				30,
+				TicketType::BusTicket,
+				0,
+				None,
+				b"Passenger".to_vec(),
+				b"BUS001".to_vec(),
+				b"".to_vec(),
+				b"1".to_vec(),
+				b"City A".to_vec(),
+				b"City B".to_vec(),
+				b"2024-05-01 09:00".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			),
+			Error::<Test>::IssuerTicketCapExceeded
+		);
 
-		// Move to block 100 (only first ticket expired)
+		// The count starts over in the next reward period (100 blocks in tests)
 		System::set_block_number(100);
-
-		// Cleanup
-		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
-
-		// Only 2 tickets should remain
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 2);
-		assert!(TravelPoints::get_ticket(0).is_none()); // First ticket removed
-		assert!(TravelPoints::get_ticket(1).is_some()); // Second ticket still exists
-		assert!(TravelPoints::get_ticket(2).is_some()); // Bonus ticket still exists
+		mint_basic_ticket(30, None);
+		assert_eq!(crate::IssuerPeriodTicketCount::<Test>::get(1, 2), 1);
 	});
 }
 
-/// Test cleanup for user with no tickets
+/// Test the ticket counts of an ended period are pruned in chunks
 #[test]
-fn cleanup_expired_tickets_no_tickets() {
+fn prune_issuer_ticket_counts_after_period_ends() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Cleanup for user with no tickets - should succeed without error
-		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+		mint_basic_ticket(10, None);
+		// Counts left by two other issuers
+		crate::IssuerPeriodTicketCount::<Test>::insert(0, 3, 1);
+		crate::IssuerPeriodTicketCount::<Test>::insert(0, 4, 5);
 
-		// No event should be emitted (no tickets cleaned)
-		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+		// Period 0 is still running
+		assert_noop!(
+			TravelPoints::prune_issuer_ticket_counts(RuntimeOrigin::signed(99), 0),
+			Error::<Test>::PeriodNotEnded
+		);
+
+		// MaxPeriodEntriesPruned = 2
+		System::set_block_number(100);
+		mint_basic_ticket(20, None);
+		assert_ok!(TravelPoints::prune_issuer_ticket_counts(RuntimeOrigin::signed(99), 0));
+		System::assert_last_event(Event::IssuerTicketCountsPruned { period: 0, removed: 2 }.into());
+		assert_ok!(TravelPoints::prune_issuer_ticket_counts(RuntimeOrigin::signed(99), 0));
+		System::assert_last_event(Event::IssuerTicketCountsPruned { period: 0, removed: 1 }.into());
+		assert_eq!(crate::IssuerPeriodTicketCount::<Test>::iter_prefix(0).count(), 0);
+
+		// The running period keeps its count
+		assert_eq!(crate::IssuerPeriodTicketCount::<Test>::get(1, 2), 1);
 	});
 }
 
-// ============================================================================
-// COMPLETE TICKET LIFECYCLE TESTS
-// ============================================================================
-
-/// Test full ticket lifecycle: mint -> transfer -> unmint
+/// Test transfer ticket
 #[test]
-fn ticket_lifecycle_mint_transfer_unmint() {
+fn transfer_ticket_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Mint ticket for user 10
+		// Mint a ticket for user 10
 		assert_ok!(TravelPoints::mint_ticket(
 			RuntimeOrigin::signed(2),
 			10,
-			TicketType::PlaneTicket,
+			TicketType::BusTicket,
 			0,
 			None,
 			b"Original Owner".to_vec(),
-			b"AB123".to_vec(),
-			b"A12".to_vec(),
-			b"15A".to_vec(),
-			b"New York".to_vec(),
-			b"Los Angeles".to_vec(),
-			b"2024-03-15 10:00".to_vec(),
+			b"BUS001".to_vec(),
+			b"".to_vec(),
+			b"5".to_vec(),
+			b"City A".to_vec(),
+			b"City B".to_vec(),
+			b"2024-05-01 09:00".to_vec(),
 			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
 		));
 
 		// Transfer to user 20
 		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20));
 
-		// User 10 cannot unmint (no longer owner)
-		assert_noop!(
-			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0),
-			Error::<Test>::NotTicketOwner
-		);
-
-		// User 20 can unmint
-		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(20), 0));
+		// Check new ownership
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.owner, 20);
 
-		// Ticket is gone
-		assert!(TravelPoints::get_ticket(0).is_none());
+		// Check user ticket lists updated
 		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
-		assert_eq!(TravelPoints::get_user_tickets(&20).len(), 0);
+		assert_eq!(TravelPoints::get_user_tickets(&20).len(), 1);
 	});
 }
 
-/// Test ticket lifecycle with points: mint with cost -> unmint (no refund)
+/// Test transfer_ticket is charged its own benchmarked weight, not spend_points'
 #[test]
-fn ticket_lifecycle_mint_with_points_unmint() {
+fn transfer_ticket_uses_its_own_weight() {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let call = crate::Call::<Test>::transfer_ticket { ticket_id: 0, to: 20 };
+	let weight = call.get_dispatch_info().call_weight;
+
+	assert_eq!(weight, <() as WeightInfo>::transfer_ticket());
+	assert_ne!(weight, <() as WeightInfo>::spend_points());
+	// Two ticket lists plus the fee burn cost more than a single spend
+	assert!(weight.ref_time() > <() as WeightInfo>::spend_points().ref_time());
+	assert!(weight.proof_size() > <() as WeightInfo>::spend_points().proof_size());
+}
+
+/// Test the ticket transfer fee is burned from the sender's points
+#[test]
+fn ticket_transfer_fee_is_burned() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
-		// Award points
 		assert_ok!(TravelPoints::award_points(
 			RuntimeOrigin::signed(2),
 			10,
-			1000,
+			200,
 			TravelType::Airline,
 			None
 		));
+		for _ in 0..4 {
+			mint_basic_ticket(10, None);
+		}
 
-		// Mint ticket with points cost
-		assert_ok!(TravelPoints::mint_ticket(
-			RuntimeOrigin::signed(2),
-			10,
-			TicketType::PlaneTicket,
-			500, // Points cost
-			None,
-			b"Test User".to_vec(),
-			b"AB123".to_vec(),
-			b"A12".to_vec(),
-			b"15A".to_vec(),
-			b"New York".to_vec(),
-			b"Los Angeles".to_vec(),
-			b"2024-03-15 10:00".to_vec(),
-			b"".to_vec(),
-		));
+		// Without a fee, transfers leave the sender's points alone
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20));
+		assert_eq!(TotalPoints::<Test>::get(10), 200);
 
-		// Points were deducted
-		assert_eq!(TotalPoints::<Test>::get(10), 500);
+		// With a fee, the sender pays it for every ticket
+		TicketTransferFee::set(25);
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 1, 20));
+		assert_eq!(TotalPoints::<Test>::get(10), 175);
+		System::assert_last_event(Event::TicketTransferFeeBurned { account: 10, fee: 25 }.into());
 
-		// Unmint the ticket
-		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+		assert_ok!(TravelPoints::transfer_tickets_batch(RuntimeOrigin::signed(10), vec![2, 3], 20));
+		assert_eq!(TotalPoints::<Test>::get(10), 125);
+		System::assert_last_event(Event::TicketTransferFeeBurned { account: 10, fee: 50 }.into());
 
-		// Points are NOT refunded (unmint doesn't restore points)
-		assert_eq!(TotalPoints::<Test>::get(10), 500);
+		// A sender that cannot pay the fee keeps the ticket
+		assert_noop!(
+			TravelPoints::transfer_ticket(RuntimeOrigin::signed(20), 0, 10),
+			Error::<Test>::InsufficientPoints
+		);
+	});
+}
 
-		// Ticket is gone
-		assert!(TravelPoints::get_ticket(0).is_none());
+/// Test a ticket's provenance records every owner with the block it was acquired
+#[test]
+fn ticket_provenance_tracks_owners() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		mint_basic_ticket(10, None);
+
+		System::set_block_number(5);
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 11));
+		System::set_block_number(9);
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(11), 0, 12));
+
+		assert_eq!(TravelPoints::get_ticket_provenance(0), vec![(10, 1), (11, 5), (12, 9)]);
+
+		// MaxProvenanceEntries is 3 in tests: the minting owner is dropped
+		System::set_block_number(12);
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(12), 0, 13));
+		assert_eq!(TravelPoints::get_ticket_provenance(0), vec![(11, 5), (12, 9), (13, 12)]);
+
+		// Burning the ticket clears its history
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(13), 0));
+		assert!(TravelPoints::get_ticket_provenance(0).is_empty());
+	});
+}
+
+/// Test transferring several tickets in one call
+#[test]
+fn transfer_tickets_batch_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint three tickets for user 10 and one for user 20
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(20, None);
+
+		assert_ok!(TravelPoints::transfer_tickets_batch(
+			RuntimeOrigin::signed(10),
+			vec![0, 1, 2],
+			20
+		));
+
+		// All three tickets now belong to user 20
+		for ticket_id in 0..3 {
+			let ticket = TravelPoints::get_ticket(ticket_id).expect("Ticket should exist");
+			assert_eq!(ticket.owner, 20);
+			System::assert_has_event(
+				Event::TicketTransferred { ticket_id, from: 10, to: 20 }.into(),
+			);
+		}
+
+		// Both ticket lists were updated
+		assert!(TravelPoints::get_user_tickets(&10).is_empty());
+		assert_eq!(TravelPoints::get_user_tickets(&20), vec![3, 0, 1, 2]);
+	});
+}
+
+/// Test a batch transfer is rolled back entirely if any ticket isn't owned by the caller
+#[test]
+fn transfer_tickets_batch_rolls_back_on_foreign_ticket() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(10, None);
+		mint_basic_ticket(30, None);
+
+		// Ticket 2 belongs to user 30, so nothing is transferred
+		assert_noop!(
+			TravelPoints::transfer_tickets_batch(RuntimeOrigin::signed(10), vec![0, 1, 2], 20),
+			Error::<Test>::NotTicketOwner
+		);
+		assert_eq!(TravelPoints::get_user_tickets(&10), vec![0, 1]);
+		assert!(TravelPoints::get_user_tickets(&20).is_empty());
+	});
+}
+
+/// Test batch transfer input length limits
+#[test]
+fn transfer_tickets_batch_size_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::transfer_tickets_batch(RuntimeOrigin::signed(10), vec![], 20),
+			Error::<Test>::EmptyTicketBatch
+		);
+
+		// Max batch size is 5 in tests
+		assert_noop!(
+			TravelPoints::transfer_tickets_batch(
+				RuntimeOrigin::signed(10),
+				vec![0, 1, 2, 3, 4, 5],
+				20
+			),
+			Error::<Test>::TooManyTicketsInBatch
+		);
+	});
+}
+
+/// Mint a free plane ticket for `owner` on a coded route
+fn mint_route_ticket(owner: u64, departure_code: &[u8], arrival_code: &[u8]) {
+	assert_ok!(TravelPoints::mint_ticket(
+		RuntimeOrigin::signed(2),
+		owner,
+		TicketType::PlaneTicket,
+		0,
+		None,
+		b"Passenger".to_vec(),
+		b"AB123".to_vec(),
+		b"".to_vec(),
+		b"1A".to_vec(),
+		b"New York".to_vec(),
+		b"Los Angeles".to_vec(),
+		b"2024-03-15 10:00".to_vec(),
+		b"".to_vec(),
+		Some(departure_code.to_vec()),
+		Some(arrival_code.to_vec()),
+		1,
+		1,
+	));
+}
+
+/// Test tickets minted on the same coded route can be queried together
+#[test]
+fn tickets_on_route_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		mint_route_ticket(10, b"JFK", b"LAX");
+		mint_route_ticket(20, b"JFK", b"LAX");
+		mint_route_ticket(30, b"LAX", b"JFK");
+		// Uncoded ticket is not indexed
+		mint_basic_ticket(40, None);
+
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.departure_code.map(|c| c.to_vec()), Some(b"JFK".to_vec()));
+		assert_eq!(ticket.arrival_code.map(|c| c.to_vec()), Some(b"LAX".to_vec()));
+
+		let mut outbound = TravelPoints::tickets_on_route(b"JFK".to_vec(), b"LAX".to_vec());
+		outbound.sort();
+		assert_eq!(outbound, vec![0, 1]);
+		assert_eq!(TravelPoints::tickets_on_route(b"LAX".to_vec(), b"JFK".to_vec()), vec![2]);
+		assert!(TravelPoints::tickets_on_route(b"SFO".to_vec(), b"JFK".to_vec()).is_empty());
+
+		// Burning a ticket removes it from the route index
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+		assert_eq!(TravelPoints::tickets_on_route(b"JFK".to_vec(), b"LAX".to_vec()), vec![1]);
+	});
+}
+
+/// Test invalid location codes are rejected at mint time
+#[test]
+fn mint_ticket_invalid_location_code_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for bad_code in [b"".to_vec(), b"J K".to_vec(), b"TOOLONGCODE".to_vec()] {
+			assert_noop!(
+				TravelPoints::mint_ticket(
+					RuntimeOrigin::signed(2),
+					10,
+					TicketType::PlaneTicket,
+					0,
+					None,
+					b"Passenger".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					b"".to_vec(),
+					Some(bad_code),
+					Some(b"LAX".to_vec()),
+					1,
+					1,
+				),
+				Error::<Test>::InvalidLocationCode
+			);
+		}
+	});
+}
+
+/// Test ticket metadata may exceed `MAX_STRING_LEN` up to `MAX_METADATA_LEN`, while the other
+/// string fields keep the shorter limit
+#[test]
+fn mint_ticket_metadata_length_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let mint = |passenger_name: Vec<u8>, metadata: Vec<u8>| {
+			TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				TicketType::PlaneTicket,
+				0,
+				None,
+				passenger_name,
+				b"AB123".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				metadata,
+				None,
+				None,
+				1,
+				1,
+			)
+		};
+		let long_metadata = vec![b'x'; crate::MAX_METADATA_LEN as usize];
+
+		assert_ok!(mint(b"John Doe".to_vec(), long_metadata.clone()));
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.metadata.into_inner(), long_metadata);
+
+		assert_noop!(
+			mint(b"John Doe".to_vec(), vec![b'x'; crate::MAX_METADATA_LEN as usize + 1]),
+			Error::<Test>::StringTooLong
+		);
+		assert_noop!(
+			mint(vec![b'x'; crate::MAX_STRING_LEN as usize + 1], b"notes".to_vec()),
+			Error::<Test>::StringTooLong
+		);
+	});
+}
+
+/// Test unauthorized issuer cannot mint ticket
+#[test]
+fn mint_ticket_unauthorized_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::mint_ticket(
+				RuntimeOrigin::signed(5), // unauthorized
+				10,
+				TicketType::PlaneTicket,
+				0,
+				None,
+				b"Test".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			),
+			Error::<Test>::NotAuthorizedIssuer
+		);
+	});
+}
+
+/// Test gifting a ticket spends the payer's points and mints to the recipient
+#[test]
+fn gift_ticket_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		assert_ok!(TravelPoints::gift_ticket(
+			RuntimeOrigin::signed(2),
+			10, // payer
+			11, // recipient
+			TicketType::PlaneTicket,
+			400,
+			None,
+			b"Jane Doe".to_vec(),
+			b"AB123".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Payer's points were spent, recipient's untouched
+		assert_eq!(TotalPoints::<Test>::get(10), 600);
+		assert_eq!(TotalPoints::<Test>::get(11), 0);
+
+		// The recipient owns the ticket
+		let ticket = TravelPoints::get_ticket(0).expect("Ticket should exist");
+		assert_eq!(ticket.owner, 11);
+		assert_eq!(ticket.points_cost, 400);
+		assert_eq!(TravelPoints::get_user_tickets(&11), vec![0]);
+		assert!(TravelPoints::get_user_tickets(&10).is_empty());
+
+		System::assert_has_event(
+			Event::TicketGifted { ticket_id: 0, payer: 10, recipient: 11 }.into(),
+		);
+	});
+}
+
+/// Test recipients that opted out of gifts reject gifted and transferred tickets
+#[test]
+fn gifts_rejected_by_opted_out_recipient() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert!(TravelPoints::accepts_gifts(20));
+
+		assert_ok!(TravelPoints::set_accepts_gifts(RuntimeOrigin::signed(20), false));
+		System::assert_last_event(Event::GiftAcceptanceSet { account: 20, accepts: false }.into());
+
+		assert_noop!(
+			TravelPoints::gift_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				20,
+				TicketType::PlaneTicket,
+				0,
+				None,
+				b"Jane Doe".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1
+			),
+			Error::<Test>::RecipientRejectsGifts
+		);
+
+		mint_basic_ticket(10, None);
+		assert_noop!(
+			TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20),
+			Error::<Test>::RecipientRejectsGifts
+		);
+		assert_noop!(
+			TravelPoints::transfer_tickets_batch(RuntimeOrigin::signed(10), vec![0], 20),
+			Error::<Test>::RecipientRejectsGifts
+		);
+
+		// Issuers minting to the account directly are not gifts
+		mint_basic_ticket(20, None);
+		assert_eq!(TravelPoints::get_user_tickets(&20), vec![1]);
+
+		// A recipient with the default setting accepts the transfer
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 30));
+		assert_eq!(TravelPoints::get_ticket(0).unwrap().owner, 30);
+
+		// Opting back in accepts transfers again
+		assert_ok!(TravelPoints::set_accepts_gifts(RuntimeOrigin::signed(20), true));
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(30), 0, 20));
+		assert_eq!(TravelPoints::get_user_tickets(&20), vec![1, 0]);
+	});
+}
+
+/// Test gifting fails when the payer cannot cover the points cost
+#[test]
+fn gift_ticket_insufficient_payer_points_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			None
+		));
+		// The recipient's balance does not count towards the cost
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			11,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		assert_noop!(
+			TravelPoints::gift_ticket(
+				RuntimeOrigin::signed(2),
+				10,
+				11,
+				TicketType::PlaneTicket,
+				500,
+				None,
+				b"Test".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				b"".to_vec(),
+				None,
+				None,
+				1,
+				1,
+			),
+			Error::<Test>::InsufficientPoints
+		);
+	});
+}
+
+// ============================================================================
+// STAKING TESTS
+// ============================================================================
+
+/// Test basic staking
+#[test]
+fn stake_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Stake 500 tokens
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+
+		// Check stake info
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 500);
+		assert_eq!(stake_info.staked_at, 1);
+		assert!(!stake_info.is_verifier);
+
+		// Check total staked
+		assert_eq!(TravelPoints::total_staked(), 500);
+
+		// Check staker is in list
+		let stakers = TravelPoints::get_all_stakers();
+		assert!(stakers.contains(&10));
+	});
+}
+
+/// Test staking below minimum fails
+#[test]
+fn stake_below_minimum_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Try to stake 50 tokens (below minimum of 100)
+		assert_noop!(
+			TravelPoints::stake(RuntimeOrigin::signed(10), 50),
+			Error::<Test>::StakeBelowMinimum
+		);
+	});
+}
+
+/// Test cannot stake twice
+#[test]
+fn stake_twice_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+
+		assert_noop!(
+			TravelPoints::stake(RuntimeOrigin::signed(10), 300),
+			Error::<Test>::AlreadyStaking
+		);
+	});
+}
+
+/// Fill the active staker set (MaxStakers = 100) with accounts 1000..1100.
+/// Account 1000 holds the smallest stake (150), everyone else stakes 200.
+fn fill_staker_set() {
+	assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(1000), 150));
+	for who in 1001..1100u64 {
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(who), 200));
+	}
+	assert_eq!(TravelPoints::staker_list().len(), 100);
+}
+
+/// Test a larger stake displaces the smallest active staker when the set is full
+#[test]
+fn stake_displaces_smallest_when_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5000), 300));
+
+		let stakers = TravelPoints::staker_list();
+		assert_eq!(stakers.len(), 100);
+		assert!(stakers.contains(&5000));
+		assert!(!stakers.contains(&1000));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![1000]);
+
+		// Displaced staker keeps its stake while waitlisted
+		assert_eq!(TravelPoints::enhanced_stakes(&1000).unwrap().active, 150);
+		System::assert_has_event(Event::StakerWaitlisted { staker: 1000, amount: 150 }.into());
+	});
+}
+
+/// Test a smaller stake is waitlisted and promoted when a slot frees up
+#[test]
+fn stake_waitlisted_then_promoted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+
+		// Not larger than the smallest active stake, so waitlisted
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5000), 120));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5001), 140));
+		assert!(!TravelPoints::staker_list().contains(&5000));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![5000, 5001]);
+
+		// Freeing a slot promotes the largest waitlisted stake
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(1050)));
+
+		assert!(TravelPoints::staker_list().contains(&5001));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![5000]);
+		System::assert_has_event(Event::StakerPromoted { staker: 5001, amount: 140 }.into());
+
+		// Unstaking from the waitlist just removes the entry
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(5000)));
+		assert!(TravelPoints::staker_waitlist().is_empty());
+	});
+}
+
+/// Test staking fails once both the active set and the waitlist are full
+#[test]
+fn stake_fails_when_waitlist_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+
+		// MaxStakerWaitlist = 3
+		for who in 5000..5003u64 {
+			assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(who), 100));
+		}
+
+		assert_noop!(
+			TravelPoints::stake(RuntimeOrigin::signed(5003), 100),
+			Error::<Test>::TooManyStakers
+		);
+	});
+}
+
+/// Test a larger stake evicts the smallest waitlisted staker once both sets are full
+#[test]
+fn stake_evicts_smallest_waitlisted_when_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+
+		// MaxStakerWaitlist = 3
+		for who in 5000..5003u64 {
+			assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(who), 100));
+		}
+
+		// Displaces account 1000 (150) from the active set, which evicts a 100 stake
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5003), 300));
+		assert!(TravelPoints::staker_list().contains(&5003));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![1000, 5001, 5002]);
+		System::assert_has_event(Event::StakerEvicted { staker: 5000, amount: 100 }.into());
+
+		// The evicted staker keeps its stake and is placed again once it ranks
+		assert_eq!(TravelPoints::enhanced_stakes(&5000).unwrap().active, 100);
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(5000), 20));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![1000, 5000, 5002]);
+		System::assert_has_event(Event::StakerEvicted { staker: 5001, amount: 100 }.into());
+	});
+}
+
+/// Test waitlisted stakers earn no staker rewards
+#[test]
+fn waitlisted_staker_earns_no_rewards() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5000), 120));
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(TravelPoints::pending_staker_rewards(&5000), 0);
+		assert!(TravelPoints::pending_staker_rewards(&1000) > 0);
+	});
+}
+
+/// Test the staker set is rebalanced when stakes grow or shrink
+#[test]
+fn stake_changes_rebalance_staker_set() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(5000), 120));
+
+		// Outgrowing the smallest active stake swaps places with it
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(5000), 100));
+		assert!(TravelPoints::staker_list().contains(&5000));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![1000]);
+		System::assert_has_event(Event::StakerPromoted { staker: 5000, amount: 220 }.into());
+		System::assert_has_event(Event::StakerWaitlisted { staker: 1000, amount: 150 }.into());
+
+		// Unbonding below the largest waitlisted stake gives the slot back
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(1050), 100));
+		assert!(TravelPoints::staker_list().contains(&1000));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![1050]);
+		System::assert_has_event(Event::StakerPromoted { staker: 1000, amount: 150 }.into());
+		System::assert_has_event(Event::StakerWaitlisted { staker: 1050, amount: 100 }.into());
+	});
+}
+
+/// Test unstaking
+#[test]
+fn unstake_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// First stake
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+		assert_eq!(TravelPoints::total_staked(), 500);
+
+		// Then unstake
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(10)));
+
+		// Check stake removed
+		assert!(TravelPoints::get_stake_info(&10).is_none());
+		assert_eq!(TravelPoints::total_staked(), 0);
+
+		// Check removed from staker list
+		let stakers = TravelPoints::get_all_stakers();
+		assert!(!stakers.contains(&10));
+	});
+}
+
+/// Test unstaking without stake fails
+#[test]
+fn unstake_not_staker_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(TravelPoints::unstake(RuntimeOrigin::signed(10)), Error::<Test>::NotStaker);
+	});
+}
+
+/// Test add to reward pool
+#[test]
+fn add_to_reward_pool_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(10), 1000));
+		assert_eq!(TravelPoints::reward_pool(), 1000);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(20), 500));
+		assert_eq!(TravelPoints::reward_pool(), 1500);
+	});
+}
+
+/// Test the admin or root can set the reward pool balance directly
+#[test]
+fn set_reward_pool_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::set_reward_pool(RuntimeOrigin::signed(1), 5000));
+		assert_eq!(TravelPoints::reward_pool(), 5000);
+		System::assert_has_event(Event::RewardPoolSet { amount: 5000 }.into());
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::SetRewardPool,
+				target: None,
+			}
+			.into(),
+		);
+
+		assert_ok!(TravelPoints::set_reward_pool(RuntimeOrigin::root(), 0));
+		assert_eq!(TravelPoints::reward_pool(), 0);
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: None,
+				action: crate::AdminAction::SetRewardPool,
+				target: None,
+			}
+			.into(),
+		);
+	});
+}
+
+/// Test a non-admin cannot set the reward pool balance
+#[test]
+fn set_reward_pool_non_admin_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::set_reward_pool(RuntimeOrigin::signed(10), 5000),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+// ============================================================================
+// ISSUER TRACKING TESTS
+// ============================================================================
+
+/// Test that spending points tracks issuer spending
+#[test]
+fn issuer_spending_tracked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Award points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		// Spend with issuer 2
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+
+		// Check issuer record
+		let period = TravelPoints::current_period();
+		let record = TravelPoints::get_issuer_period_record(period, &2);
+		assert_eq!(record.points_spent, 300);
+		assert_eq!(record.transaction_count, 1);
+
+		// Check period total
+		assert_eq!(TravelPoints::get_period_total_spent(period), 300);
+
+		// Spend more
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
+
+		let record = TravelPoints::get_issuer_period_record(period, &2);
+		assert_eq!(record.points_spent, 500);
+		assert_eq!(record.transaction_count, 2);
+	});
+}
+
+/// Test spends at an untracked issuer leave the issuer reward records unchanged
+#[test]
+fn spend_at_untracked_issuer_leaves_records_unchanged() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		let period = TravelPoints::current_period();
+
+		// Tracked spend updates the issuer records
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 300, 2));
+		assert_eq!(TravelPoints::get_issuer_period_record(period, &2).points_spent, 300);
+		assert_eq!(TravelPoints::get_period_total_spent(period), 300);
+
+		// Only the admin decides which issuers are tracked
+		assert_noop!(
+			TravelPoints::set_spend_tracking(RuntimeOrigin::signed(2), 2, false),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(TravelPoints::set_spend_tracking(RuntimeOrigin::signed(1), 2, false));
+		assert!(TravelPoints::untracked_spends(2));
+		System::assert_last_event(
+			Event::IssuerSpendTrackingSet { issuer: 2, tracked: false }.into(),
+		);
+
+		// Untracked spend deducts points but leaves the records alone
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 200, 2));
+		assert_eq!(TravelPoints::total_points(10), 500);
+
+		let record = TravelPoints::get_issuer_period_record(period, &2);
+		assert_eq!(record.points_spent, 300);
+		assert_eq!(record.transaction_count, 1);
+		assert_eq!(TravelPoints::get_period_total_spent(period), 300);
+
+		// Tracking can be turned back on
+		assert_ok!(TravelPoints::set_spend_tracking(RuntimeOrigin::signed(1), 2, true));
+		assert!(!TravelPoints::untracked_spends(2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_eq!(TravelPoints::get_period_total_spent(period), 400);
+	});
+}
+
+/// Test the v1 migration moves legacy stakes into `EnhancedStakes` and staking keeps
+/// working on the migrated entries
+#[test]
+fn migration_v1_moves_legacy_stakes() {
+	use crate::{migrations::v1, EnhancedStakeInfo, EnhancedStakes, StakeInfo};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		System::set_block_number(3);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 500));
+
+		// Recreate the version 0 layout, with the stakes in the legacy map
+		for (staker, info) in EnhancedStakes::<Test>::drain() {
+			let is_verifier = staker == 10;
+			v1::Stakes::<Test>::insert(
+				staker,
+				StakeInfo { amount: info.active, staked_at: info.staked_at, is_verifier },
+			);
+		}
+		crate::StakerCount::<Test>::kill();
+		let _ = crate::BondedStake::<Test>::clear(u32::MAX, None);
+		let _ = crate::StakeHistory::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<TravelPoints>();
+
+		v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TravelPoints::on_chain_storage_version(), 1);
+		assert_eq!(v1::Stakes::<Test>::iter().count(), 0);
+		assert_eq!(TravelPoints::staker_count(), 2);
+
+		// Bonds and stake history are seeded from the legacy stakes
+		assert_eq!(TravelPoints::bonded_stake(10), Some(1000));
+		assert_eq!(TravelPoints::bonded_stake(20), Some(500));
+		assert_eq!(TravelPoints::stake_history(10).to_vec(), vec![(1, 1000)]);
+		assert_eq!(TravelPoints::stake_history(20).to_vec(), vec![(3, 500)]);
+		assert_eq!(
+			TravelPoints::get_stake_info(&10),
+			Some(EnhancedStakeInfo {
+				active: 1000,
+				staked_at: 1,
+				is_verifier: true,
+				total_slashed: 0
+			})
+		);
+		assert_eq!(
+			TravelPoints::get_stake_info(&20),
+			Some(EnhancedStakeInfo {
+				active: 500,
+				staked_at: 3,
+				is_verifier: false,
+				total_slashed: 0
+			})
+		);
+
+		// Staking calls read and write the migrated entries
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(10), 200));
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		let info = TravelPoints::get_stake_info(&10).unwrap();
+		assert_eq!(info.active, 1140); // 1200 - 5%
+		assert_eq!(info.total_slashed, 60);
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(20)));
+		assert!(TravelPoints::get_stake_info(&20).is_none());
+		assert_eq!(TravelPoints::staker_count(), 1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 300));
+		assert_eq!(TravelPoints::staker_count(), 2);
+		assert_eq!(TravelPoints::total_staked(), 1440);
+
+		// The migration only runs once
+		v1::Stakes::<Test>::insert(40, StakeInfo { amount: 100, staked_at: 1, is_verifier: false });
+		v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert!(v1::Stakes::<Test>::contains_key(40));
+		assert_eq!(TravelPoints::staker_count(), 2);
+	});
+}
+
+/// Test the v2 migration translates stored point batches, starting demurrage at the earning
+/// block and leaving partial expiry unset
+#[test]
+fn migration_v2_translates_point_batches() {
+	use crate::{migrations::v2, PointBatch};
+	use codec::Encode;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		// Write batches in the version 1 layout
+		let old = vec![
+			v2::OldPointBatch {
+				earned_at_block: 5u64,
+				expires_at_block: 1005,
+				remaining_points: 300,
+				travel_type: TravelType::Airline,
+			},
+			v2::OldPointBatch {
+				earned_at_block: 40,
+				expires_at_block: 1040,
+				remaining_points: 200,
+				travel_type: TravelType::Bus,
+			},
+		];
+		frame_support::storage::unhashed::put_raw(
+			&UserPoints::<Test>::hashed_key_for(10),
+			&old.encode(),
+		);
+		StorageVersion::new(1).put::<TravelPoints>();
+
+		v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TravelPoints::on_chain_storage_version(), 2);
+		assert_eq!(
+			UserPoints::<Test>::get(10).into_inner(),
+			vec![
+				PointBatch {
+					earned_at_block: 5,
+					expires_at_block: 1005,
+					remaining_points: 300,
+					travel_type: TravelType::Airline,
+					last_demurrage_block: 5,
+					expiring_per_step: 0,
+				},
+				PointBatch {
+					earned_at_block: 40,
+					expires_at_block: 1040,
+					remaining_points: 200,
+					travel_type: TravelType::Bus,
+					last_demurrage_block: 40,
+					expiring_per_step: 0,
+				},
+			]
+		);
+
+		// The migrated batches can be spent
+		System::set_block_number(100);
+		TotalPoints::<Test>::insert(10, 500);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 350, 2));
+		assert_eq!(TravelPoints::get_available_points(&10), 150);
+	});
+}
+
+/// A plane ticket in the version 1 layout, before route codes, segments and capacity
+fn v1_ticket(
+	id: u128,
+	owner: u64,
+	is_redeemed: bool,
+) -> crate::migrations::v2::OldTicket<u64, u64> {
+	crate::migrations::v2::OldTicket {
+		id,
+		owner,
+		issuer: 2,
+		ticket_type: TicketType::PlaneTicket,
+		created_at: 1,
+		expires_at: None,
+		points_cost: 0,
+		is_redeemed,
+		passenger_name: b"Passenger".to_vec().try_into().unwrap(),
+		travel_number: b"AA100".to_vec().try_into().unwrap(),
+		gate: Default::default(),
+		seat: Default::default(),
+		departure: b"New York".to_vec().try_into().unwrap(),
+		arrival: b"Los Angeles".to_vec().try_into().unwrap(),
+		departure_time: Default::default(),
+		metadata: b"legacy".to_vec().try_into().unwrap(),
+	}
+}
+
+/// Test the v2 migration translates stored tickets without route codes, so they stay out of
+/// the route index and can still change hands
+#[test]
+fn migration_v2_translates_tickets() {
+	use crate::migrations::v2;
+	use codec::Encode;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (id, owner, is_redeemed) in [(0, 10, false), (1, 10, true)] {
+			frame_support::storage::unhashed::put_raw(
+				&crate::Tickets::<Test>::hashed_key_for(id),
+				&v1_ticket(id, owner, is_redeemed).encode(),
+			);
+		}
+		crate::UserTickets::<Test>::insert(
+			10,
+			frame_support::BoundedVec::truncate_from(vec![0, 1]),
+		);
+		StorageVersion::new(1).put::<TravelPoints>();
+
+		v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TravelPoints::on_chain_storage_version(), 2);
+		let ticket = TravelPoints::get_ticket(0).expect("ticket should decode");
+		assert_eq!(ticket.owner, 10);
+		assert_eq!(ticket.metadata.to_vec(), b"legacy".to_vec());
+		assert_eq!(ticket.departure_code, None);
+		assert_eq!(ticket.arrival_code, None);
+		assert_eq!(crate::TicketsByRoute::<Test>::iter().count(), 0);
+
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20));
+		assert_eq!(TravelPoints::get_ticket(0).unwrap().owner, 20);
+	});
+}
+
+/// Test migrated tickets cover a single segment, already redeemed for redeemed tickets
+#[test]
+fn migration_v2_tickets_cover_one_segment() {
+	use crate::migrations::v2;
+	use codec::Encode;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (id, is_redeemed) in [(0, false), (1, true)] {
+			frame_support::storage::unhashed::put_raw(
+				&crate::Tickets::<Test>::hashed_key_for(id),
+				&v1_ticket(id, 10, is_redeemed).encode(),
+			);
+		}
+		StorageVersion::new(1).put::<TravelPoints>();
+
+		v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		let open = TravelPoints::get_ticket(0).unwrap();
+		assert_eq!((open.segments, open.segments_redeemed), (1, 0));
+		let redeemed = TravelPoints::get_ticket(1).unwrap();
+		assert_eq!((redeemed.segments, redeemed.segments_redeemed), (1, 1));
+
+		// A single redemption uses up the migrated ticket
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+		let open = TravelPoints::get_ticket(0).unwrap();
+		assert!(open.is_redeemed);
+		assert_eq!(open.segments_redeemed, 1);
+		assert_noop!(
+			TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::TicketAlreadyRedeemed
+		);
+	});
+}
+
+/// Test migrated tickets seat a single traveler, with no seat used yet
+#[test]
+fn migration_v2_tickets_seat_one_traveler() {
+	use crate::migrations::v2;
+	use codec::Encode;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		frame_support::storage::unhashed::put_raw(
+			&crate::Tickets::<Test>::hashed_key_for(0),
+			&v1_ticket(0, 10, false).encode(),
+		);
+		StorageVersion::new(1).put::<TravelPoints>();
+
+		v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		let ticket = TravelPoints::get_ticket(0).unwrap();
+		assert_eq!((ticket.capacity, ticket.used), (1, 0));
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - SLASHING
+// ============================================================================
+
+/// Test `SlashToRewardPoolBps` of a slash is added to the reward pool and the rest burned
+#[test]
+fn slash_staker_recycles_share_into_reward_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		SlashToRewardPoolBps::set(4000);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// InvalidVerification slashes 10% = 100, of which 40% is recycled
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::InvalidVerification,
+			None
+		));
+
+		assert_eq!(TravelPoints::reward_pool(), 140);
+		assert_eq!(TravelPoints::total_slashed(), 100);
+		System::assert_has_event(Event::SlashRecycled { staker: 10, amount: 40 }.into());
+	});
+}
+
+/// Test a slash whose recycled share would overflow the reward pool fails instead of
+/// saturating
+#[test]
+fn slash_staker_reward_pool_overflow_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		SlashToRewardPoolBps::set(4000);
+
+		assert_ok!(TravelPoints::set_reward_pool(RuntimeOrigin::signed(1), u128::MAX));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		assert_noop!(
+			TravelPoints::slash_staker(
+				RuntimeOrigin::signed(1),
+				10,
+				crate::SlashReason::InvalidVerification,
+				None
+			),
+			Error::<Test>::RewardPoolOverflow
+		);
+	});
+}
+
+/// Test slashing a staker for offline behavior
+#[test]
+fn slash_staker_offline_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Stake 1000 tokens
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_eq!(TravelPoints::total_staked(), 1000);
+
+		// Admin slashes for offline (5% = 50 tokens)
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+
+		// Check stake was reduced
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 950); // 1000 - 50
+
+		// Check total slashed updated
+		assert_eq!(TravelPoints::total_slashed(), 50);
+
+		// Check slash record exists
+		let records = TravelPoints::get_slash_records(&10);
+		assert_eq!(records.len(), 1);
+		assert_eq!(records[0].amount, 50);
+	});
+}
+
+/// Test slash statistics are tracked per reason
+#[test]
+fn slash_stats_track_each_reason() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(11), 2000));
+
+		// Offline twice (5% of 1000, then 5% of 950), invalid verification once (10% of 2000)
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			11,
+			crate::SlashReason::InvalidVerification,
+			None
+		));
+
+		assert_eq!(
+			TravelPoints::slash_stats_by_reason(),
+			vec![
+				(crate::SlashReason::Offline, 2, 97),
+				(crate::SlashReason::InvalidVerification, 1, 200),
+				(crate::SlashReason::Malicious, 0, 0),
+				(crate::SlashReason::Other, 0, 0),
+			]
+		);
+		assert_eq!(TravelPoints::total_slashed(), 297);
+	});
+}
+
+/// Test slashing for invalid verification (10%)
+#[test]
+fn slash_staker_invalid_verification_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::InvalidVerification,
+			None
+		));
+
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 900); // 1000 - 100 (10%)
+	});
+}
+
+/// Test slashing for malicious behavior (100%)
+#[test]
+fn slash_staker_malicious_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Malicious,
+			None
+		));
+
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 0); // 1000 - 1000 (100%)
+	});
+}
+
+/// Test that non-admin cannot slash
+#[test]
+fn slash_staker_not_admin_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		assert_noop!(
+			TravelPoints::slash_staker(
+				RuntimeOrigin::signed(5),
+				10,
+				crate::SlashReason::Offline,
+				None
+			),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+/// Test rebuilding a drifted staker list from `EnhancedStakes`
+#[test]
+fn rebuild_staker_list_restores_consistency() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 3000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 2000));
+
+		// Desync: account 20 is missing and account 99 never staked
+		crate::StakerList::<Test>::put(
+			frame_support::BoundedVec::try_from(vec![10, 99]).expect("Within MaxStakers"),
+		);
+
+		assert_noop!(
+			TravelPoints::rebuild_staker_list(RuntimeOrigin::signed(5), 3),
+			Error::<Test>::NotAdmin
+		);
+
+		// The witness must cover every stake
+		assert_noop!(
+			TravelPoints::rebuild_staker_list(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::InvalidStakeWitness
+		);
+
+		assert_ok!(TravelPoints::rebuild_staker_list(RuntimeOrigin::signed(1), 3));
+		System::assert_has_event(Event::StakerListRebuilt { count: 3, waitlisted: 0 }.into());
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::RebuildStakerList,
+				target: None,
+			}
+			.into(),
+		);
+		// Largest stakes first
+		assert_eq!(TravelPoints::staker_list().to_vec(), vec![20, 30, 10]);
+	});
+}
+
+/// Test a rebuild waitlists the stakes beyond `MaxStakers` and evicts the rest
+#[test]
+fn rebuild_staker_list_waitlists_overflow() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_staker_set();
+
+		// MaxStakerWaitlist = 3
+		for (who, amount) in [(5000u64, 120), (5001, 140), (5002, 130)] {
+			assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(who), amount));
+		}
+
+		// Desync: both sets lose track of everyone
+		crate::StakerList::<Test>::kill();
+		crate::StakerWaitlist::<Test>::kill();
+
+		// A stake that skipped placement, too small for either set
+		crate::EnhancedStakes::<Test>::insert(
+			6000,
+			crate::EnhancedStakeInfo {
+				active: 110,
+				staked_at: 1,
+				is_verifier: false,
+				total_slashed: 0,
+			},
+		);
+
+		let stakes = crate::EnhancedStakes::<Test>::iter_keys().count() as u32;
+		assert_ok!(TravelPoints::rebuild_staker_list(RuntimeOrigin::signed(1), stakes));
+
+		let stakers = TravelPoints::staker_list();
+		assert_eq!(stakers.len(), 100);
+		assert!((1000..1100u64).all(|who| stakers.contains(&who)));
+		assert_eq!(TravelPoints::staker_waitlist().to_vec(), vec![5001, 5002, 5000]);
+		System::assert_has_event(Event::StakerEvicted { staker: 6000, amount: 110 }.into());
+		System::assert_has_event(Event::StakerListRebuilt { count: 100, waitlisted: 3 }.into());
+	});
+}
+
+/// Test a slash is computed on the stake held at the offense block
+#[test]
+fn slash_staker_uses_stake_at_offense_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Offense at block 5, then most of the stake is unbonded
+		System::set_block_number(10);
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 960));
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 40);
+
+		// Invalid verification (10%) of the 1000 held at block 5, not of the current 40
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::InvalidVerification,
+			Some(5)
+		));
+		assert_eq!(TravelPoints::get_slash_records(&10)[0].amount, 100);
+		assert_eq!(TravelPoints::total_slashed(), 100);
+
+		// Active stake covers 40, the pending unbonding the remaining 60
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 0);
+		assert_eq!(TravelPoints::get_unbonding_requests(&10)[0].amount, 900);
+		assert_eq!(TravelPoints::total_staked(), 0);
+
+		// Without an offense block, the current stake is used
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(10), 500));
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::InvalidVerification,
+			None
+		));
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 450);
+	});
+}
+
+/// Test a slash for an offense older than the recorded stake history uses the largest stake
+/// still on record rather than the current one
+#[test]
+fn slash_staker_beyond_stake_history_uses_largest_recorded_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 2000));
+
+		// Offense at block 2, then five unbonds roll the block 1 entry out of the history
+		// (MaxStakeHistory = 5)
+		for block in 3..8u64 {
+			System::set_block_number(block);
+			assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 200));
+		}
+		assert_eq!(TravelPoints::stake_history(10).first(), Some(&(3, 1800)));
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 1000);
+
+		// Invalid verification (10%) of the 1800 still on record, not of the current 1000
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::InvalidVerification,
+			Some(2)
+		));
+		assert_eq!(TravelPoints::get_slash_records(&10)[0].amount, 180);
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - UNBONDING
+// ============================================================================
+
+/// Test requesting unbonding
+#[test]
+fn request_unbond_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// First stake
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Request unbonding of 500
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+
+		// Check stake reduced
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 500);
+
+		// Check unbonding request created
+		let requests = TravelPoints::get_unbonding_requests(&10);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].amount, 500);
+		assert_eq!(requests[0].requested_at, 1);
+		assert_eq!(requests[0].unlocks_at, 51); // 1 + 50 (unbonding period)
+	});
+}
+
+/// Test unbonding can never exceed the bonded stake across requests
+#[test]
+fn request_unbond_cannot_exceed_bonded_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_eq!(TravelPoints::bonded_stake(10), Some(1000));
+
+		// The second request would unbond more than was ever staked
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 600));
+		assert_noop!(
+			TravelPoints::request_unbond(RuntimeOrigin::signed(10), 600),
+			Error::<Test>::InsufficientBalance
+		);
+
+		// Even if the active stake drifted upwards, the drift cannot be unbonded
+		crate::EnhancedStakes::<Test>::mutate(10, |info| info.as_mut().unwrap().active = 600);
+		assert_noop!(
+			TravelPoints::request_unbond(RuntimeOrigin::signed(10), 100),
+			Error::<Test>::InsufficientBalance
+		);
+		crate::EnhancedStakes::<Test>::mutate(10, |info| info.as_mut().unwrap().active = 400);
+
+		// Withdrawing releases the bonded amount
+		System::set_block_number(51);
+		assert_ok!(TravelPoints::withdraw_unbonded(RuntimeOrigin::signed(10)));
+		assert_eq!(TravelPoints::bonded_stake(10), Some(400));
+	});
+}
+
+/// Test an emergency withdrawal releases unbonding stake early and forfeits the penalty to the
+/// reward pool
+#[test]
+fn emergency_withdraw_applies_penalty() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		// Only unbonding stake can be withdrawn in an emergency
+		assert_noop!(
+			TravelPoints::emergency_withdraw(RuntimeOrigin::signed(10), 100),
+			Error::<Test>::NoUnbondingRequests
+		);
+
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 200));
+		System::set_block_number(10);
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 300));
+		assert_ok!(TravelPoints::emergency_withdraw(RuntimeOrigin::signed(10), 333));
+
+		// EmergencyWithdrawPenaltyBps is 10% in tests: 299.7 is returned as 300
+		System::assert_last_event(
+			Event::EmergencyWithdrawn { staker: 10, amount: 333, returned: 300, penalty: 33 }
+				.into(),
+		);
+		assert_eq!(TravelPoints::reward_pool(), 33);
+		assert_eq!(TravelPoints::bonded_stake(10), Some(667));
+		// Active stake is untouched; the last request is used up first
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 500);
+		assert_eq!(TravelPoints::total_staked(), 500);
+		let requests = TravelPoints::unbonding_requests(&10);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].amount, 167);
+
+		assert_noop!(
+			TravelPoints::emergency_withdraw(RuntimeOrigin::signed(10), 168),
+			Error::<Test>::InsufficientBalance
+		);
+
+		// Stake that has already unlocked is withdrawn without a penalty instead
+		System::set_block_number(51);
+		assert_noop!(
+			TravelPoints::emergency_withdraw(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+/// Test withdrawing a staker's last unbonding stake in an emergency closes the stake
+#[test]
+fn emergency_withdraw_closes_empty_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 1000));
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 30));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 1000));
+		assert_eq!(TravelPoints::staker_count(), 2);
+
+		assert_ok!(TravelPoints::emergency_withdraw(RuntimeOrigin::signed(10), 1000));
+
+		assert!(TravelPoints::get_stake_info(&10).is_none());
+		assert_eq!(TravelPoints::staker_count(), 1);
+		assert!(!TravelPoints::staker_list().contains(&10));
+		assert!(!crate::UnbondingRequests::<Test>::contains_key(10));
+		assert_eq!(TravelPoints::recovery_account(10), None);
+		assert_eq!(TravelPoints::bonded_stake(10), None);
+	});
+}
+
+/// Test a verifier of the current era cannot withdraw in an emergency
+#[test]
+fn emergency_withdraw_rejects_current_verifier() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert!(TravelPoints::is_current_verifier(&20));
+
+		assert_noop!(
+			TravelPoints::emergency_withdraw(RuntimeOrigin::signed(20), 500),
+			Error::<Test>::VerifierCannotEmergencyWithdraw
+		);
+	});
+}
+
+/// Test withdrawing unbonded tokens after period ends
+#[test]
+fn withdraw_unbonded_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+
+		// Cannot withdraw before unbonding period ends
+		System::set_block_number(40);
+		assert_noop!(
+			TravelPoints::withdraw_unbonded(RuntimeOrigin::signed(10)),
+			Error::<Test>::UnbondingNotComplete
+		);
+
+		// Move past unbonding period
+		System::set_block_number(60);
+
+		// Now can withdraw
+		assert_ok!(TravelPoints::withdraw_unbonded(RuntimeOrigin::signed(10)));
+
+		// Check unbonding requests cleared
+		let requests = TravelPoints::get_unbonding_requests(&10);
+		assert_eq!(requests.len(), 0);
+	});
+}
+
+/// Test a recovery account can withdraw the staker's matured unbonding
+#[test]
+fn recover_unbonded_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11));
+		assert_eq!(TravelPoints::recovery_account(&10), Some(11));
+		System::assert_last_event(Event::StakingRecoverySet { staker: 10, recovery: 11 }.into());
+
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+
+		// Unbonding must still mature first
+		assert_noop!(
+			TravelPoints::recover_unbonded(RuntimeOrigin::signed(11), 10),
+			Error::<Test>::UnbondingNotComplete
+		);
+
+		System::set_block_number(60);
+		assert_ok!(TravelPoints::recover_unbonded(RuntimeOrigin::signed(11), 10));
+
+		assert_eq!(TravelPoints::get_unbonding_requests(&10).len(), 0);
+		System::assert_last_event(
+			Event::StakingRecovered { staker: 10, recovery: 11, amount: 500 }.into(),
+		);
+	});
+}
+
+/// Test unstaking keeps pending unbonding recoverable and closes the stake once it is withdrawn
+#[test]
+fn unstake_closes_stake_after_unbonding_withdrawn() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::Unstaked { staker: 10, amount: 500 }.into());
+
+		// The active stake is gone, but the unbonding request is still there
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 0);
+		assert!(!TravelPoints::get_all_stakers().contains(&10));
+		assert_eq!(TravelPoints::get_unbonding_requests(&10).len(), 1);
+		assert_eq!(TravelPoints::total_staked(), 0);
+
+		// Recovering the matured unbonding closes the stake and its recovery account
+		System::set_block_number(60);
+		assert_ok!(TravelPoints::recover_unbonded(RuntimeOrigin::signed(11), 10));
+		assert!(TravelPoints::get_stake_info(&10).is_none());
+		assert_eq!(TravelPoints::recovery_account(&10), None);
+		assert_eq!(TravelPoints::staker_count(), 0);
+
+		// Without pending unbonding, unstaking closes everything right away
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 1000));
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(20), 21));
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(20)));
+		assert!(TravelPoints::get_stake_info(&20).is_none());
+		assert_eq!(TravelPoints::recovery_account(&20), None);
+	});
+}
+
+/// Test only the designated recovery account can recover unbonding
+#[test]
+fn recover_unbonded_not_recovery_account_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+		System::set_block_number(60);
+
+		// No recovery account set yet
+		assert_noop!(
+			TravelPoints::recover_unbonded(RuntimeOrigin::signed(11), 10),
+			Error::<Test>::NotRecoveryAccount
+		);
+
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11));
+		assert_noop!(
+			TravelPoints::recover_unbonded(RuntimeOrigin::signed(12), 10),
+			Error::<Test>::NotRecoveryAccount
+		);
+	});
+}
+
+/// Test the recovery account cannot be set by non-stakers, to self, or after a slash
+#[test]
+fn set_staking_recovery_validation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11),
+			Error::<Test>::NotStaker
+		);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_noop!(
+			TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 10),
+			Error::<Test>::InvalidRecoveryAccount
+		);
+
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		assert_noop!(
+			TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11),
+			Error::<Test>::RecoveryAfterSlash
+		);
+	});
+}
+
+/// Test cancelling unbonding
+#[test]
+fn cancel_unbonding_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+
+		// Verify stake reduced
+		let stake_before = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_before.active, 500);
+
+		// Cancel unbonding
+		assert_ok!(TravelPoints::cancel_unbonding(RuntimeOrigin::signed(10)));
+
+		// Verify stake restored
+		let stake_after = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_after.active, 1000);
+
+		// Verify requests cleared
+		let requests = TravelPoints::get_unbonding_requests(&10);
+		assert_eq!(requests.len(), 0);
+	});
+}
+
+/// Test a request below the instant threshold is returned immediately
+#[test]
+fn request_unbond_below_threshold_is_instant() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Instant threshold is 50 in tests
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+
+		// Stake and total reduced straight away, with no queued request
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 960);
+		assert_eq!(TravelPoints::total_staked(), 960);
+		assert!(TravelPoints::get_unbonding_requests(&10).is_empty());
+		assert_eq!(TravelPoints::instant_unbonded(0, 10), 40);
+
+		System::assert_last_event(Event::InstantUnbonded { staker: 10, amount: 40 }.into());
+	});
+}
+
+/// Test instantly unbonding the last of the active stake closes the stake
+#[test]
+fn request_unbond_instant_full_exit_closes_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 100));
+		assert_ok!(TravelPoints::set_staking_recovery(RuntimeOrigin::signed(10), 11));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+
+		// The rest leaves in the next period, once the allowance has reset
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 20));
+
+		assert!(TravelPoints::get_stake_info(&10).is_none());
+		assert!(!TravelPoints::get_all_stakers().contains(&10));
+		assert_eq!(TravelPoints::recovery_account(&10), None);
+		assert_eq!(TravelPoints::staker_count(), 0);
+		assert_eq!(TravelPoints::total_staked(), 0);
+	});
+}
+
+/// Test a request at or above the instant threshold is queued as usual
+#[test]
+fn request_unbond_above_threshold_is_queued() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 50));
+
+		let requests = TravelPoints::get_unbonding_requests(&10);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].amount, 50);
+		assert_eq!(TravelPoints::instant_unbonded(0, 10), 0);
+	});
+}
+
+/// Test the per-period instant allowance falls back to the queue once used up
+#[test]
+fn request_unbond_instant_allowance_is_capped_per_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Allowance is 80 per period in tests: 40 + 40 fit, the third 40 is queued
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+
+		assert_eq!(TravelPoints::instant_unbonded(0, 10), 80);
+		let requests = TravelPoints::get_unbonding_requests(&10);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].amount, 40);
+
+		// A new period restores the allowance (100 blocks per period in tests)
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 40));
+		assert_eq!(TravelPoints::instant_unbonded(1, 10), 40);
+		assert_eq!(TravelPoints::get_unbonding_requests(&10).len(), 1);
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - DELEGATION AND POOLS
+// ============================================================================
+
+/// Test creating a staking pool
+#[test]
+fn create_pool_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Create pool with 1000 stake and 10% commission
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Check pool created
+		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
+		assert_eq!(pool.operator, 10);
+		assert_eq!(pool.total_stake, 1000);
+		assert_eq!(pool.operator_stake, 1000);
+		assert_eq!(pool.commission, 1000);
+		assert!(pool.is_active);
+		assert_eq!(pool.delegator_count, 0);
+
+		// Check next pool ID incremented
+		assert_eq!(TravelPoints::next_pool_id(), 1);
+
+		// Check total staked updated
+		assert_eq!(TravelPoints::total_staked(), 1000);
+	});
+}
+
+/// Test a lower commission pool reports a higher delegator APY
+#[test]
+fn pool_apy_reflects_commission() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// 10% and 50% commission pools
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(11), 2000, 5000));
+
+		// Nothing to pay out yet
+		assert_eq!(TravelPoints::pool_apy(0), 0);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(1), 1000));
+
+		// 1000 * 30% / 3000 staked per period, less commission, over 52_560 periods
+		assert_eq!(TravelPoints::pool_apy(0), 47_304_000);
+		assert_eq!(TravelPoints::pool_apy(1), 26_280_000);
+		assert!(TravelPoints::pool_apy(0) > TravelPoints::pool_apy(1));
+
+		// Unknown pool
+		assert_eq!(TravelPoints::pool_apy(7), 0);
+	});
+}
+
+/// Test pool health reflects the pool's state after a delegation
+#[test]
+fn pool_health_reports_pool_state() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 1500));
+
+		let pool = TravelPoints::get_pool(0).unwrap();
+		assert_eq!(
+			TravelPoints::pool_health(0),
+			Some(crate::PoolHealth {
+				commission: 1000,
+				// 1000 of 2500 staked by the operator
+				operator_stake_ratio: 4000,
+				delegator_count: pool.delegator_count,
+				total_stake: pool.total_stake,
+				is_active: true,
+				operator_slashed: false,
+			})
+		);
+		assert_eq!(pool.delegator_count, 1);
+		assert_eq!(pool.total_stake, 2500);
+
+		// Unknown pool
+		assert_eq!(TravelPoints::pool_health(7), None);
+	});
+}
+
+/// Test the full staking position combines flat stake, operated pools, delegation and unbonding
+#[test]
+fn full_staking_position_aggregates_all_modes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Account 10 stakes, operates pool 0 and delegates to pool 1
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 300));
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 600, 1000));
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(20), 800, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(10), 1, 400));
+
+		assert_eq!(
+			TravelPoints::full_staking_position(10),
+			crate::StakingPosition {
+				active: 700,
+				pools_operated: vec![(0, 600)],
+				delegation: Some((1, 400)),
+				unbonding: 300,
+			}
+		);
+
+		// An account with no position at all
+		assert_eq!(TravelPoints::full_staking_position(30), Default::default());
+	});
+}
+
+/// Test creating pool with insufficient stake fails
+#[test]
+fn create_pool_insufficient_stake_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Min pool operator stake is 500 in tests
+		assert_noop!(
+			TravelPoints::create_pool(RuntimeOrigin::signed(10), 100, 1000),
+			Error::<Test>::InsufficientOperatorStake
+		);
+	});
+}
+
+/// Test creating pool with excessive commission fails
+#[test]
+fn create_pool_excessive_commission_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Max commission is 5000 (50%) in tests
+		assert_noop!(
+			TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 6000),
+			Error::<Test>::CommissionTooHigh
+		);
+	});
+}
+
+/// Test anyone can create a pool while creation is unrestricted
+#[test]
+fn create_pool_unrestricted_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert!(!TravelPoints::pool_creation_restricted());
+		assert!(!TravelPoints::pool_creators(&10));
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+	});
+}
+
+/// Test only allow-listed accounts can create pools while creation is restricted
+#[test]
+fn create_pool_restricted_requires_allow_list() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::set_pool_creation_restricted(RuntimeOrigin::signed(1), true));
+		System::assert_last_event(Event::PoolCreationRestrictionSet { restricted: true }.into());
+
+		assert_noop!(
+			TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000),
+			Error::<Test>::NotAuthorizedPoolCreator
+		);
+
+		assert_ok!(TravelPoints::set_pool_creator(RuntimeOrigin::signed(1), 10, true));
+		System::assert_last_event(Event::PoolCreatorUpdated { account: 10, allowed: true }.into());
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Removing the account from the allow-list blocks it again
+		assert_ok!(TravelPoints::set_pool_creator(RuntimeOrigin::signed(1), 10, false));
+		assert_noop!(
+			TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000),
+			Error::<Test>::NotAuthorizedPoolCreator
+		);
+	});
+}
+
+/// Test only the admin can manage pool creation restrictions
+#[test]
+fn pool_creator_management_not_admin_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::set_pool_creation_restricted(RuntimeOrigin::signed(10), true),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			TravelPoints::set_pool_creator(RuntimeOrigin::signed(10), 10, true),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+/// Test delegating to a pool
+#[test]
+fn delegate_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Create pool first
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Delegate to pool
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+
+		// Check delegation recorded
+		let delegation = TravelPoints::get_delegation(&20).expect("Delegation should exist");
+		assert_eq!(delegation.pool_id, 0);
+		assert_eq!(delegation.amount, 500);
+
+		// Check pool updated
+		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
+		assert_eq!(pool.total_stake, 1500);
+		assert_eq!(pool.delegator_count, 1);
+
+		// Check delegator list
+		let delegators = TravelPoints::get_pool_delegators(0);
+		assert!(delegators.contains(&20));
+	});
+}
+
+/// Test cannot delegate below minimum
+#[test]
+fn delegate_below_minimum_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Min stake is 100 in tests
+		assert_noop!(
+			TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 50),
+			Error::<Test>::DelegationBelowMinimum
+		);
+	});
+}
+
+/// Test delegations are capped per account and pool
+#[test]
+fn delegate_above_cap_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// MaxDelegationPerPoolPerAccount is 2000 in tests
+		assert_noop!(
+			TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 2001),
+			Error::<Test>::DelegationCapExceeded
+		);
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 2000));
+		assert_eq!(TravelPoints::get_pool(0).unwrap().total_stake, 3000);
+	});
+}
+
+/// Test undelegating from a pool
+#[test]
+fn undelegate_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+
+		// Undelegate
+		assert_ok!(TravelPoints::undelegate(RuntimeOrigin::signed(20)));
+
+		// Check delegation removed
+		assert!(TravelPoints::get_delegation(&20).is_none());
+
+		// Check pool updated
+		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
+		assert_eq!(pool.total_stake, 1000);
+		assert_eq!(pool.delegator_count, 0);
+	});
+}
+
+/// Test updating pool commission
+#[test]
+fn set_pool_commission_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Update commission
+		assert_ok!(TravelPoints::set_pool_commission(RuntimeOrigin::signed(10), 0, 2000));
+
+		let pool = TravelPoints::get_pool(0).expect("Pool should exist");
+		assert_eq!(pool.commission, 2000);
+	});
+}
+
+/// Test non-operator cannot update commission
+#[test]
+fn set_pool_commission_not_operator_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		assert_noop!(
+			TravelPoints::set_pool_commission(RuntimeOrigin::signed(20), 0, 2000),
+			Error::<Test>::NotPoolOperator
+		);
+	});
+}
+
+/// Test closing a pool
+#[test]
+fn close_pool_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Close pool
+		assert_ok!(TravelPoints::close_pool(RuntimeOrigin::signed(10), 0));
+
+		// Check pool removed
+		assert!(TravelPoints::get_pool(0).is_none());
+
+		// Check total staked reduced
+		assert_eq!(TravelPoints::total_staked(), 0);
+	});
+}
+
+/// Test a pool cannot be closed before it reaches `MinPoolLifetime`
+#[test]
+fn close_pool_respects_min_lifetime() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinPoolLifetime::set(50);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		System::set_block_number(50);
+		assert_noop!(
+			TravelPoints::close_pool(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::PoolTooYoung
+		);
+
+		System::set_block_number(51);
+		assert_ok!(TravelPoints::close_pool(RuntimeOrigin::signed(10), 0));
+		assert!(TravelPoints::get_pool(0).is_none());
+	});
+}
+
+/// Test cannot close pool with delegators
+#[test]
+fn close_pool_with_delegators_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+
+		assert_noop!(
+			TravelPoints::close_pool(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::PoolHasDelegators
+		);
+	});
+}
+
+/// Test no delegator index is left behind after delegating, undelegating and closing
+#[test]
+fn close_pool_leaves_no_delegator_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 500));
+		assert!(crate::PoolDelegators::<Test>::contains_key(0));
+
+		assert_ok!(TravelPoints::undelegate(RuntimeOrigin::signed(20)));
+		assert_ok!(TravelPoints::close_pool(RuntimeOrigin::signed(10), 0));
+
+		assert!(!crate::PoolDelegators::<Test>::contains_key(0));
+	});
+}
+
+/// Test cleaning up a leftover delegator index for a removed pool
+#[test]
+fn cleanup_pool_index_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+
+		// Index cannot be cleaned while the pool exists
+		assert_noop!(
+			TravelPoints::cleanup_pool_index(RuntimeOrigin::signed(99), 0),
+			Error::<Test>::PoolStillExists
+		);
+
+		// Simulate an orphaned index left behind by a removed pool
+		assert_ok!(TravelPoints::close_pool(RuntimeOrigin::signed(10), 0));
+		crate::PoolDelegators::<Test>::mutate(0, |delegators| {
+			let _ = delegators.try_push(20);
+		});
+
+		assert_ok!(TravelPoints::cleanup_pool_index(RuntimeOrigin::signed(99), 0));
+		assert!(!crate::PoolDelegators::<Test>::contains_key(0));
+		System::assert_last_event(Event::PoolIndexCleaned { pool_id: 0 }.into());
+
+		// Nothing left to clean
+		assert_noop!(
+			TravelPoints::cleanup_pool_index(RuntimeOrigin::signed(99), 0),
+			Error::<Test>::PoolIndexNotFound
+		);
+	});
+}
+
+/// Test the admin can release delegations stranded by a pool removed with delegators
+#[test]
+fn sweep_closed_pool_residue_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::create_pool(RuntimeOrigin::signed(10), 1000, 1000));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(20), 0, 600));
+		assert_ok!(TravelPoints::delegate(RuntimeOrigin::signed(21), 0, 400));
+
+		assert_noop!(
+			TravelPoints::sweep_closed_pool_residue(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::PoolStillExists
+		);
+
+		// Force the pool closed, bypassing the delegator check
+		crate::Pools::<Test>::remove(0);
+		crate::TotalStaked::<Test>::mutate(|total| *total -= 1000);
+		assert_eq!(TravelPoints::total_staked(), 1000);
+
+		assert_noop!(
+			TravelPoints::sweep_closed_pool_residue(RuntimeOrigin::signed(99), 0),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(TravelPoints::sweep_closed_pool_residue(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(
+			Event::Undelegated { delegator: 20, pool_id: 0, amount: 600 }.into(),
+		);
+		System::assert_has_event(Event::ClosedPoolResidueSwept { pool_id: 0, amount: 1000 }.into());
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::SweepClosedPoolResidue,
+				target: None,
+			}
+			.into(),
+		);
+
+		assert_eq!(TravelPoints::total_staked(), 0);
+		assert!(TravelPoints::get_delegation(&20).is_none());
+		assert!(TravelPoints::get_delegation(&21).is_none());
+		assert!(!crate::PoolDelegators::<Test>::contains_key(0));
+
+		// Nothing left to sweep
+		assert_noop!(
+			TravelPoints::sweep_closed_pool_residue(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::PoolIndexNotFound
+		);
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - ERA ROTATION AND VERIFIERS
+// ============================================================================
+
+/// Test era rotation and verifier selection
+#[test]
+fn rotate_era_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Create multiple stakers with different stakes
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 500));
+
+		// Move past blocks per era (200 in tests)
+		System::set_block_number(201);
+
+		// Rotate era
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+
+		// Check era incremented
+		assert_eq!(TravelPoints::current_era(), 1);
+
+		// Check verifiers selected (should select by highest stake)
+		let verifiers = TravelPoints::get_current_verifiers();
+		assert!(!verifiers.is_empty());
+
+		// Account 20 should be a verifier (highest stake)
+		assert!(TravelPoints::is_current_verifier(&20));
+	});
+}
+
+/// Test era rotation is charged for the stakers it actually scans
+#[test]
+fn rotate_era_weight_scales_with_stakers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for staker in [10, 20, 30] {
+			assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(staker), 1000));
+		}
+
+		System::set_block_number(201);
+		let rotated =
+			TravelPoints::rotate_era(RuntimeOrigin::signed(99)).expect("rotation should succeed");
+		assert_eq!(rotated.actual_weight, Some(<() as WeightInfo>::rotate_era(3)));
+
+		// The up-front charge covers a full staker list (MaxStakers is 100 in tests)
+		assert!(
+			<() as WeightInfo>::rotate_era(100).ref_time()
+				> rotated.actual_weight.unwrap().ref_time()
+		);
+	});
+}
+
+/// Test rewards are only released once a quorum of verifiers has reported
+#[test]
+fn distribute_rewards_requires_verification_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 500));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert_eq!(TravelPoints::get_current_verifiers().len(), 3);
+
+		// Quorum is 50% in tests: 2 of the 3 verifiers must report
+		let period = TravelPoints::current_period();
+		assert_noop!(
+			TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period),
+			Error::<Test>::VerificationQuorumNotMet
+		);
+
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(10), period));
+		System::assert_last_event(Event::VerificationReported { verifier: 10, period }.into());
+		assert_noop!(
+			TravelPoints::report_verification(RuntimeOrigin::signed(10), period),
+			Error::<Test>::AlreadyReported
+		);
+		assert_noop!(
+			TravelPoints::report_verification(RuntimeOrigin::signed(40), period),
+			Error::<Test>::NotVerifier
+		);
+		assert_noop!(
+			TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period),
+			Error::<Test>::VerificationQuorumNotMet
+		);
+
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(20), period));
+		assert_eq!(TravelPoints::verification_report_count(period), 2);
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+		assert_eq!(TravelPoints::reward_pool(), 0);
+	});
+}
+
+/// Test a period's quorum is measured against the era its reports started in, and its
+/// reports are removed once it is distributed
+#[test]
+fn verification_quorum_uses_era_of_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 500));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		let period = TravelPoints::current_period();
+
+		// Periods that have not started cannot be attested
+		assert_noop!(
+			TravelPoints::report_verification(RuntimeOrigin::signed(10), period + 1),
+			Error::<Test>::PeriodInFuture
+		);
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(10), period));
+		assert_eq!(TravelPoints::period_verification_era(period), Some(1));
+
+		// The next era has only two verifiers, but the period still needs 2 of era 1's 3
+		assert_ok!(TravelPoints::unstake(RuntimeOrigin::signed(30)));
+		System::set_block_number(401);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert_eq!(TravelPoints::get_current_verifiers().len(), 2);
+		assert_noop!(
+			TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period),
+			Error::<Test>::VerificationQuorumNotMet
+		);
+
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(20), period));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		assert_eq!(TravelPoints::verification_report_count(period), 0);
+		assert_eq!(TravelPoints::period_verification_era(period), None);
+		assert!(crate::VerificationReports::<Test>::get(period, 10).is_none());
+	});
+}
+
+/// Test the admin can remove the reports of a period that is never distributed
+#[test]
+fn prune_verification_reports_clears_undistributed_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(10), period));
+
+		assert_noop!(
+			TravelPoints::prune_verification_reports(RuntimeOrigin::signed(1), period),
+			Error::<Test>::PeriodNotEnded
+		);
+
+		System::set_block_number(301);
+		assert_noop!(
+			TravelPoints::prune_verification_reports(RuntimeOrigin::signed(10), period),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(TravelPoints::prune_verification_reports(RuntimeOrigin::signed(1), period));
+		System::assert_last_event(Event::VerificationReportsPruned { period, removed: 1 }.into());
+		assert_eq!(TravelPoints::verification_report_count(period), 0);
+		assert_eq!(TravelPoints::period_verification_era(period), None);
+	});
+}
+
+/// Test era rotation not due yet
+#[test]
+fn rotate_era_not_due_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(100); // Less than 200 blocks per era
+
+		assert_noop!(
+			TravelPoints::rotate_era(RuntimeOrigin::signed(99)),
+			Error::<Test>::EraRotationNotDue
+		);
+	});
+}
+
+/// Test era progress counts from the last rotation and saturates once rotation is overdue
+#[test]
+fn era_progress_reports_position_in_era() {
+	new_test_ext().execute_with(|| {
+		// 200 blocks per era in tests, first era starting at block 0
+		System::set_block_number(50);
+		assert_eq!(TravelPoints::era_progress(), (50, 200));
+
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert_eq!(TravelPoints::era_progress(), (0, 200));
+
+		System::set_block_number(351);
+		assert_eq!(TravelPoints::era_progress(), (150, 200));
+
+		// Rotation overdue
+		System::set_block_number(700);
+		assert_eq!(TravelPoints::era_progress(), (200, 200));
+	});
+}
+
+/// Test the verifier set of each era stays queryable after later rotations
+#[test]
+fn get_era_verifiers_returns_past_eras() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+
+		// Account 10 is slashed out of the next selection and account 20 joins
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Malicious,
+			None
+		));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+
+		System::set_block_number(401);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+
+		assert_eq!(TravelPoints::get_era_verifiers(1), vec![10]);
+		assert_eq!(TravelPoints::get_era_verifiers(2), vec![20]);
+		assert_eq!(TravelPoints::get_era_verifiers(2), TravelPoints::get_current_verifiers());
+		// No verifiers were selected for eras that have not started
+		assert!(TravelPoints::get_era_verifiers(3).is_empty());
+	});
+}
+
+/// Test an active staker with sufficient stake is eligible to be a verifier
+#[test]
+fn is_verifier_eligible_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Not staking at all
+		assert!(!TravelPoints::is_verifier_eligible(10));
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert!(TravelPoints::is_verifier_eligible(10));
+	});
+}
+
+/// Test slashed or zero-stake stakers are not eligible to be verifiers
+#[test]
+fn is_verifier_eligible_false_when_penalized() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 1000));
+
+		// Slashed during the current era
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		assert!(!TravelPoints::is_verifier_eligible(10));
+
+		// Fully slashed stake leaves nothing at stake
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			20,
+			crate::SlashReason::Malicious,
+			None
+		));
+		assert_eq!(TravelPoints::get_stake_info(&20).unwrap().active, 0);
+
+		// Penalized stakers are skipped by verifier selection
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert!(!TravelPoints::is_current_verifier(&10));
+		assert!(!TravelPoints::is_current_verifier(&20));
+
+		// The slash penalty lasts for the era in which it happened
+		assert!(TravelPoints::is_verifier_eligible(10));
+		assert!(!TravelPoints::is_verifier_eligible(20));
+	});
+}
+
+/// Test stakers below `MinVerifierStake` are left out of verifier selection
+#[test]
+fn verifier_selection_skips_stakes_below_minimum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinVerifierStake::set(1000);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 999));
+		assert!(TravelPoints::is_verifier_eligible(10));
+		assert!(!TravelPoints::is_verifier_eligible(30));
+
+		// Fewer stakers than VerifiersPerEra, yet the one below the minimum is not picked
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		assert_eq!(TravelPoints::get_era_verifiers(1), vec![20, 10]);
+		assert!(!TravelPoints::is_current_verifier(&30));
+		assert!(!TravelPoints::get_stake_info(&30).unwrap().is_verifier);
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - REWARDS
+// ============================================================================
+
+/// Test distributing rewards
+#[test]
+fn distribute_rewards_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Setup: Add staker and issuer spending
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Add to reward pool
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		// Award and spend points to create issuer tracking
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			crate::TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+
+		let period = TravelPoints::current_period();
+
+		// Distribute rewards
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// Check reward pool emptied
+		assert_eq!(TravelPoints::reward_pool(), 0);
+
+		// Check pending rewards created (staker gets 80%, issuer gets 20%)
+		let staker_rewards = TravelPoints::pending_staker_rewards(&10);
+		assert!(staker_rewards > 0);
+	});
+}
+
+/// Test issuer rewards blend transaction count with points spent
+#[test]
+fn distribute_rewards_blends_transaction_count() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		IssuerRewardSpendWeight::set(1);
+		IssuerRewardTransactionWeight::set(1);
+		assert_ok!(TravelPoints::authorize_issuer(RuntimeOrigin::signed(1), 3));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		for user in [30, 31] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				user,
+				1000,
+				crate::TravelType::Airline,
+				None
+			));
+		}
+
+		// Both issuers redeem 1000 points: one large spend versus ten small ones
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 1000, 2));
+		for _ in 0..10 {
+			assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(31), 100, 3));
+		}
+
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// Scores: 1000 + 1 * 100 = 1100 and 1000 + 10 * 100 = 2000 of a 2000 issuer share
+		assert_eq!(TravelPoints::pending_issuer_rewards(&2), 709);
+		assert_eq!(TravelPoints::pending_issuer_rewards(&3), 1290);
+	});
+}
+
+/// Test reward calls refuse to run with a zero-length reward period
+#[test]
+fn zero_reward_period_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BlocksPerRewardPeriod::set(0);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		assert_noop!(
+			TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::RewardPeriodMisconfigured
+		);
+		assert_noop!(
+			TravelPoints::report_verification(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::RewardPeriodMisconfigured
+		);
+	});
+}
+
+/// Test the integrity test rejects a zero-length reward period
+#[test]
+#[should_panic(expected = "`BlocksPerRewardPeriod` must not be zero")]
+fn zero_reward_period_fails_integrity_test() {
+	BlocksPerRewardPeriod::set(0);
+	<TravelPoints as frame_support::traits::Hooks<u64>>::integrity_test();
+}
+
+/// Test stakers mid-unbond only earn rewards on their active stake
+#[test]
+fn distribute_rewards_excludes_unbonding_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 400));
+
+		// Move 600 of account 10's stake into unbonding, leaving 400 active
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 600));
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().active, 400);
+		assert_eq!(TravelPoints::unbonding_requests(&10)[0].amount, 600);
+		assert_eq!(TravelPoints::total_staked(), 800);
+
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// No issuer spending, so stakers split 80% of the pool (8000) by active stake:
+		// 8000 * 400 / 800 each, rather than 8000 * 1000 / 1400 for account 10
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 4000);
+		assert_eq!(TravelPoints::pending_staker_rewards(&20), 4000);
+	});
+}
+
+/// Test stakes younger than `StakeRewardDelay` are left out of a distribution
+#[test]
+fn distribute_rewards_skips_recent_stakes() {
+	new_test_ext().execute_with(|| {
+		StakeRewardDelay::set(50);
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+
+		// Account 20 stakes just before the distribution
+		System::set_block_number(80);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 1000));
+
+		System::set_block_number(100);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// Only account 10's stake is old enough, so it takes the whole staker share (8000)
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 8000);
+		assert_eq!(TravelPoints::pending_staker_rewards(&20), 0);
+	});
+}
+
+/// Test stake added shortly before a distribution moves `staked_at` forward, so a small old
+/// stake cannot be topped up to dodge `StakeRewardDelay`
+#[test]
+fn topped_up_stake_waits_out_reward_delay() {
+	new_test_ext().execute_with(|| {
+		StakeRewardDelay::set(50);
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 100));
+
+		// 900 joins 100 staked 89 blocks ago: 1 + 89 * 900 / 1000, rounded up
+		System::set_block_number(90);
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(20), 900));
+		assert_eq!(TravelPoints::get_stake_info(&20).unwrap().staked_at, 82);
+
+		System::set_block_number(100);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_eq!(TravelPoints::projected_reward(20), 0);
+
+		// Rebonding cancelled unbonding moves it forward the same way
+		assert_ok!(TravelPoints::request_unbond(RuntimeOrigin::signed(10), 500));
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::cancel_unbonding(RuntimeOrigin::signed(10)));
+		assert_eq!(TravelPoints::get_stake_info(&10).unwrap().staked_at, 51);
+	});
+}
+
+/// Test the projected reward matches what a distribution then credits
+#[test]
+fn projected_reward_matches_distribution() {
+	new_test_ext().execute_with(|| {
+		StakeRewardDelay::set(50);
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 3000));
+		System::set_block_number(80);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 1000));
+
+		System::set_block_number(100);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		let projected_10 = TravelPoints::projected_reward(10);
+		let projected_20 = TravelPoints::projected_reward(20);
+		assert_eq!(projected_10, 2000);
+		assert_eq!(projected_20, 6000);
+		// Too recent to earn, and not a staker at all
+		assert_eq!(TravelPoints::projected_reward(30), 0);
+		assert_eq!(TravelPoints::projected_reward(40), 0);
+
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), projected_10);
+		assert_eq!(TravelPoints::pending_staker_rewards(&20), projected_20);
+		assert_eq!(TravelPoints::pending_staker_rewards(&30), 0);
+		// The pool is empty after the distribution
+		assert_eq!(TravelPoints::projected_reward(10), 0);
+	});
+}
+
+/// Test only reporting verifiers earn when verification is required for rewards
+#[test]
+fn distribute_rewards_requires_verification_when_configured() {
+	new_test_ext().execute_with(|| {
+		RequireVerificationForReward::set(true);
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 3000));
+
+		System::set_block_number(201);
+		assert_ok!(TravelPoints::rotate_era(RuntimeOrigin::signed(99)));
+		// Staked after the rotation, so not a verifier this era
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		// One of the two verifiers reports, which meets the 50% quorum
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::report_verification(RuntimeOrigin::signed(10), period));
+
+		assert_eq!(TravelPoints::projected_reward(10), 8000);
+		assert_eq!(TravelPoints::projected_reward(20), 0);
+		assert_eq!(TravelPoints::projected_reward(30), 0);
+
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// The reporting verifier takes the whole staker share
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 8000);
+		assert_eq!(TravelPoints::pending_staker_rewards(&20), 0);
+		assert_eq!(TravelPoints::pending_staker_rewards(&30), 0);
+	});
+}
+
+/// Test the issuer dashboard aggregates authorization, spend and pending reward
+#[test]
+fn issuer_dashboard_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			crate::TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 300, 2));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 200, 2));
+
+		// Issuer share is 20% of the pool, all of it to the only issuer with spending
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		let dashboard = TravelPoints::issuer_dashboard(2);
+		assert_eq!(
+			dashboard,
+			crate::IssuerDashboard {
+				authorized: true,
+				period,
+				points_spent: 500,
+				transaction_count: 2,
+				pending_reward: 2000,
+			}
+		);
+
+		// Unknown accounts get an empty dashboard
+		assert_eq!(TravelPoints::issuer_dashboard(5), Default::default());
+	});
+}
+
+/// Test period numbers map to block ranges and back
+#[test]
+fn period_block_range_round_trips() {
+	new_test_ext().execute_with(|| {
+		// BlocksPerRewardPeriod is 100 in tests
+		assert_eq!(TravelPoints::period_for_block(0), 0);
+		assert_eq!(TravelPoints::period_for_block(99), 0);
+		assert_eq!(TravelPoints::period_for_block(100), 1);
+		assert_eq!(TravelPoints::period_start_block(0), 0);
+		assert_eq!(TravelPoints::period_start_block(3), 300);
+
+		for block in [0u64, 1, 99, 100, 101, 250, 999, 1000] {
+			let period = TravelPoints::period_for_block(block);
+			let start = TravelPoints::period_start_block(period);
+			let end = TravelPoints::period_start_block(period + 1) - 1;
+			assert!(start <= block && block <= end);
+			assert_eq!(TravelPoints::period_for_block(start), period);
+			assert_eq!(TravelPoints::period_for_block(end), period);
+		}
+
+		// current_period agrees with period_for_block
+		System::set_block_number(250);
+		assert_eq!(TravelPoints::current_period(), TravelPoints::period_for_block(250));
+	});
+}
+
+/// Test claiming rewards
+#[test]
+fn claim_rewards_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+
+		// Award and spend points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			crate::TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+
+		let period = TravelPoints::current_period();
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), period));
+
+		// Claim rewards
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		// Check pending rewards cleared
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 0);
+	});
+}
+
+/// Test rewards left unclaimed past the claim window are recycled instead of paid
+#[test]
+fn claim_rewards_after_window_recycles_to_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		// No issuer spending, so the staker gets the 80% staker share
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 8000);
+		assert_eq!(TravelPoints::pending_rewards_since(&10), Some(1));
+
+		// Claim window is 500 blocks in tests
+		System::set_block_number(501);
+		assert!(TravelPoints::rewards_expired(&10));
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		// Nothing paid: the reward went back to the pool
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 0);
+		assert_eq!(TravelPoints::pending_rewards_since(&10), None);
+		assert_eq!(TravelPoints::reward_pool(), 8000);
+		System::assert_last_event(Event::RewardExpired { account: 10, amount: 8000 }.into());
+	});
+}
+
+/// Test a claim on the last block of the window is still paid
+#[test]
+fn claim_rewards_within_window_pays() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		System::set_block_number(500);
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		assert_eq!(TravelPoints::reward_pool(), 0);
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 8000 }.into());
+	});
+}
+
+/// Test a fresh accrual restarts the claim window instead of expiring with older rewards
+#[test]
+fn new_accrual_restarts_claim_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		System::set_block_number(400);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 3));
+		assert_eq!(TravelPoints::pending_rewards_since(&10), Some(400));
+
+		// Past the first accrual's window, but within the latest one's
+		System::set_block_number(600);
+		assert!(!TravelPoints::rewards_expired(&10));
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 16000 }.into());
+	});
+}
+
+/// Test rewards already stale when an account accrues again are recycled, not carried over
+#[test]
+fn stale_rewards_recycled_on_next_accrual() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		System::set_block_number(600);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 5));
+		System::assert_has_event(Event::RewardExpired { account: 10, amount: 8000 }.into());
+
+		// Only the new reward is pending, in a window starting now
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 8000);
+		assert_eq!(TravelPoints::pending_rewards_since(&10), Some(600));
+		assert_eq!(TravelPoints::reward_pool(), 8000);
+
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 8000 }.into());
+	});
+}
+
+/// Test what is left after a partial claim gets a fresh claim window
+#[test]
+fn partial_claim_restarts_claim_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxClaimPerCall::set(5000);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		System::set_block_number(400);
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		assert_eq!(TravelPoints::pending_rewards_since(&10), Some(400));
+
+		System::set_block_number(800);
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 3000 }.into());
+	});
+}
+
+/// Test a reward above `MaxClaimPerCall` is paid over several claims
+#[test]
+fn claim_rewards_capped_per_call() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxClaimPerCall::set(5000);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 8000);
+
+		// The first claim pays the cap and leaves the rest pending
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_last_event(
+			Event::PartialRewardClaimed { account: 10, amount: 5000, remaining: 3000 }.into(),
+		);
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 3000);
+		assert_eq!(TravelPoints::pending_rewards_since(&10), Some(1));
+
+		// The second claim drains it
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 3000 }.into());
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 0);
+		assert_eq!(TravelPoints::pending_rewards_since(&10), None);
+
+		assert_noop!(
+			TravelPoints::claim_rewards(RuntimeOrigin::signed(10)),
+			Error::<Test>::NoRewardsToClaim
+		);
+	});
+}
+
+/// Test claim rewards with no pending fails
+#[test]
+fn claim_rewards_none_pending_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::claim_rewards(RuntimeOrigin::signed(10)),
+			Error::<Test>::NoRewardsToClaim
+		);
+	});
+}
+
+/// Test a keeper can claim rewards on behalf of several accounts
+#[test]
+fn claim_rewards_for_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 2000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(30), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		// Account 40 has nothing pending and is skipped
+		assert_ok!(TravelPoints::claim_rewards_for(
+			RuntimeOrigin::signed(99),
+			vec![10, 20, 30, 40]
+		));
+
+		for who in [10, 20, 30] {
+			assert_eq!(TravelPoints::get_pending_rewards(&who), 0);
+		}
+		System::assert_has_event(Event::RewardClaimed { account: 10, amount: 2000 }.into());
+		System::assert_has_event(Event::RewardClaimed { account: 20, amount: 4000 }.into());
+		System::assert_has_event(Event::RewardClaimed { account: 30, amount: 2000 }.into());
+
+		// The keeper receives nothing
+		assert_eq!(TravelPoints::get_pending_rewards(&99), 0);
+	});
+}
+
+/// Test a failing account is skipped without rolling back the rest of a batch claim
+#[test]
+fn claim_rewards_for_skips_failing_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(20), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		// Account 10's rewards are stale, but recycling them would overflow the pool
+		System::set_block_number(600);
+		crate::PendingRewardsSince::<Test>::insert(20, 500);
+		assert_ok!(TravelPoints::set_reward_pool(RuntimeOrigin::signed(1), u128::MAX));
+
+		assert_ok!(TravelPoints::claim_rewards_for(RuntimeOrigin::signed(99), vec![10, 20]));
+
+		// Account 10 keeps its rewards pending, account 20 is paid
+		assert_eq!(TravelPoints::get_pending_rewards(&10), 4000);
+		assert_eq!(TravelPoints::get_pending_rewards(&20), 0);
+		System::assert_has_event(Event::RewardClaimed { account: 20, amount: 4000 }.into());
+	});
+}
+
+/// Test an account that is both staker and issuer can claim each reward bucket on its own
+#[test]
+fn claim_staker_and_issuer_rewards_separately() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Issuer 2 also stakes, and earns issuer rewards for a spend made with it
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(2), 1000));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		let (staker_reward, issuer_reward) = TravelPoints::pending_rewards_breakdown(2);
+		assert!(staker_reward > 0 && issuer_reward > 0);
+
+		// Claiming the staker bucket leaves the issuer bucket and its claim window alone
+		assert_ok!(TravelPoints::claim_staker_rewards_only(RuntimeOrigin::signed(2)));
+		System::assert_last_event(
+			Event::RewardClaimed { account: 2, amount: staker_reward }.into(),
+		);
+		assert_eq!(TravelPoints::pending_rewards_breakdown(2), (0, issuer_reward));
+		assert_eq!(TravelPoints::pending_rewards_since(&2), Some(1));
+		assert_noop!(
+			TravelPoints::claim_staker_rewards_only(RuntimeOrigin::signed(2)),
+			Error::<Test>::NoRewardsToClaim
+		);
+
+		assert_ok!(TravelPoints::claim_issuer_rewards_only(RuntimeOrigin::signed(2)));
+		System::assert_last_event(
+			Event::RewardClaimed { account: 2, amount: issuer_reward }.into(),
+		);
+		assert_eq!(TravelPoints::pending_rewards_breakdown(2), (0, 0));
+		assert_eq!(TravelPoints::pending_rewards_since(&2), None);
+		assert_noop!(
+			TravelPoints::claim_issuer_rewards_only(RuntimeOrigin::signed(2)),
+			Error::<Test>::NoRewardsToClaim
+		);
+	});
+}
+
+/// Test staker rewards are minted as points when `StakerRewardKind` is `Points`
+#[test]
+fn claim_rewards_as_points_mints_batch() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StakerRewardKind::set(crate::RewardKind::Points);
+		IssuerRewardKind::set(crate::RewardKind::Currency);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		// The 8000 staker reward arrives as a batch with the default expiry
+		assert_eq!(TotalPoints::<Test>::get(10), 8000);
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 8000);
+		assert_eq!(batches[0].expires_at_block, 1001);
+		assert_eq!(batches[0].travel_type, TravelType::Other);
+		System::assert_has_event(
+			Event::PointsEarned {
+				recipient: 10,
+				amount: 8000,
+				expires_at_block: 1001,
+				travel_type: TravelType::Other,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::RewardClaimed { account: 10, amount: 8000 }.into());
+	});
+}
+
+/// Test claimed currency rewards vest linearly when `RewardVestingPeriod` is set
+#[test]
+fn claimed_rewards_vest_linearly() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RewardVestingPeriod::set(100);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		System::assert_has_event(
+			Event::RewardVestingStarted { account: 10, amount: 8000, ends_at: 101 }.into(),
+		);
+
+		// Nothing is available right after the claim
+		assert_eq!(TravelPoints::get_pending_rewards(&10), 0);
+		assert_eq!(TravelPoints::withdrawable_vested_reward(&10), 0);
+		assert_noop!(
+			TravelPoints::withdraw_vested_reward(RuntimeOrigin::signed(10)),
+			Error::<Test>::NothingVested
+		);
+
+		// Half way through, half of the rewards can be withdrawn
+		System::set_block_number(51);
+		assert_eq!(TravelPoints::withdrawable_vested_reward(&10), 4000);
+		assert_ok!(TravelPoints::withdraw_vested_reward(RuntimeOrigin::signed(10)));
+		System::assert_last_event(
+			Event::VestedRewardWithdrawn { account: 10, amount: 4000 }.into(),
+		);
+		assert_eq!(TravelPoints::withdrawable_vested_reward(&10), 0);
+
+		// The rest once the period has ended, after which the schedule is gone
+		System::set_block_number(101);
+		assert_ok!(TravelPoints::withdraw_vested_reward(RuntimeOrigin::signed(10)));
+		System::assert_last_event(
+			Event::VestedRewardWithdrawn { account: 10, amount: 4000 }.into(),
+		);
+		assert!(TravelPoints::reward_vesting(10).is_none());
+		assert_noop!(
+			TravelPoints::withdraw_vested_reward(RuntimeOrigin::signed(10)),
+			Error::<Test>::NoVestingRewards
+		);
+	});
+}
+
+/// Test a new claim vests together with a running schedule by its original end block
+#[test]
+fn new_claim_keeps_running_vesting_end() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RewardVestingPeriod::set(100);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		// Half way through, claim more rewards
+		System::set_block_number(51);
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+		let pending = TravelPoints::get_pending_rewards(&10);
+		assert!(pending > 0);
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+
+		// The vested half was paid out and the rest keeps the original end block
+		System::assert_has_event(Event::VestedRewardWithdrawn { account: 10, amount: 4000 }.into());
+		System::assert_has_event(
+			Event::RewardVestingStarted { account: 10, amount: 4000 + pending, ends_at: 101 }
+				.into(),
+		);
+
+		System::set_block_number(101);
+		assert_eq!(TravelPoints::withdrawable_vested_reward(&10), 4000 + pending);
+	});
+}
+
+/// Test issuer rewards use their own reward kind, independent of staker rewards
+#[test]
+fn issuer_reward_kind_is_independent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StakerRewardKind::set(crate::RewardKind::Currency);
+		IssuerRewardKind::set(crate::RewardKind::Points);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::add_to_reward_pool(RuntimeOrigin::signed(99), 10000));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			30,
+			1000,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(30), 500, 2));
+		assert_ok!(TravelPoints::distribute_rewards(RuntimeOrigin::signed(1), 0));
+
+		let issuer_reward = TravelPoints::pending_issuer_rewards(&2);
+		assert!(issuer_reward > 0);
+
+		// The issuer's reward is minted as points
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(2)));
+		assert_eq!(TotalPoints::<Test>::get(2), issuer_reward);
+
+		// The staker's reward is paid as currency and leaves its point balance untouched
+		assert_ok!(TravelPoints::claim_rewards(RuntimeOrigin::signed(10)));
+		assert_eq!(TravelPoints::pending_staker_rewards(&10), 0);
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+	});
+}
+
+/// Test batch reward claims enforce the batch size limits
+#[test]
+fn claim_rewards_for_batch_size_limits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::claim_rewards_for(RuntimeOrigin::signed(99), vec![]),
+			Error::<Test>::EmptyClaimBatch
+		);
+
+		// MaxClaimsPerBatch is 5 in tests
+		assert_noop!(
+			TravelPoints::claim_rewards_for(
+				RuntimeOrigin::signed(99),
+				vec![10, 11, 12, 13, 14, 15]
+			),
+			Error::<Test>::TooManyClaimsInBatch
+		);
+	});
+}
+
+// ============================================================================
+// ADVANCED STAKING TESTS - INCREASE STAKE
+// ============================================================================
+
+/// Test increasing stake
+#[test]
+fn increase_stake_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 500));
+		assert_eq!(TravelPoints::total_staked(), 500);
+
+		// Increase stake
+		assert_ok!(TravelPoints::increase_stake(RuntimeOrigin::signed(10), 300));
+
+		let stake_info = TravelPoints::get_stake_info(&10).expect("Stake should exist");
+		assert_eq!(stake_info.active, 800);
+		assert_eq!(TravelPoints::total_staked(), 800);
+	});
+}
+
+/// Test a stake or `TotalStaked` that would overflow fails with its own error
+#[test]
+fn stake_overflow_errors_are_distinct() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), u128::MAX - 100));
+		assert_noop!(
+			TravelPoints::increase_stake(RuntimeOrigin::signed(10), 101),
+			Error::<Test>::StakeOverflow
+		);
+
+		// The new stake fits on its own, but not in the total
+		assert_noop!(
+			TravelPoints::stake(RuntimeOrigin::signed(20), 101),
+			Error::<Test>::TotalStakeOverflow
+		);
+	});
+}
+
+/// Test increasing stake without existing stake fails
+#[test]
+fn increase_stake_not_staker_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::increase_stake(RuntimeOrigin::signed(10), 300),
+			Error::<Test>::NotStaker
+		);
+	});
+}
+
+// ============================================================================
+// TICKET UNMINT (BURN) TESTS
+// ============================================================================
+
+/// Test unminting a ticket by owner
+#[test]
+fn unmint_ticket_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket for user 10
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			0,
+			None,
+			b"Test User".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"Business Class".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Verify ticket exists
+		assert!(TravelPoints::get_ticket(0).is_some());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+
+		// Unmint the ticket
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+
+		// Verify ticket was removed
+		assert!(TravelPoints::get_ticket(0).is_none());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+
+		// Check event
+		System::assert_last_event(Event::TicketUnminted { ticket_id: 0, owner: 10 }.into());
+	});
+}
+
+/// Test unminting a redeemed ticket works
+#[test]
+fn unmint_redeemed_ticket_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint and redeem a ticket
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::TrainTicket,
+			0,
+			None,
+			b"Test User".to_vec(),
+			b"TR456".to_vec(),
+			b"".to_vec(),
+			b"22B".to_vec(),
+			b"Chicago".to_vec(),
+			b"Detroit".to_vec(),
+			b"2024-04-01 14:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		assert_ok!(TravelPoints::redeem_ticket(RuntimeOrigin::signed(10), 0));
+
+		// Can still unmint the redeemed ticket
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+
+		assert!(TravelPoints::get_ticket(0).is_none());
+	});
+}
+
+/// Test unminting non-existent ticket fails
+#[test]
+fn unmint_ticket_not_found_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 999),
+			Error::<Test>::TicketNotFound
+		);
+	});
+}
+
+/// Test unminting ticket by non-owner fails
+#[test]
+fn unmint_ticket_not_owner_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket for user 10
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::BusTicket,
+			0,
+			None,
+			b"Test".to_vec(),
+			b"BUS001".to_vec(),
+			b"".to_vec(),
+			b"5".to_vec(),
+			b"City A".to_vec(),
+			b"City B".to_vec(),
+			b"2024-05-01 09:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// User 20 tries to unmint (not owner)
+		assert_noop!(
+			TravelPoints::unmint_ticket(RuntimeOrigin::signed(20), 0),
+			Error::<Test>::NotTicketOwner
+		);
+	});
+}
+
+/// Test double unmint fails
+#[test]
+fn unmint_ticket_double_unmint_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::Bonus,
+			0,
+			None,
+			b"Test".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"Lounge Access".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// First unmint succeeds
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+
+		// Second unmint fails (ticket already removed)
+		assert_noop!(
+			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::TicketNotFound
+		);
+	});
+}
+
+// ============================================================================
+// FORCE UNMINT (ADMIN) TESTS
+// ============================================================================
+
+/// Test force unminting by admin
+#[test]
+fn force_unmint_ticket_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket for user 10
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			0,
+			None,
+			b"Test User".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Admin (account 1) force unmints the ticket
+		assert_ok!(TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(1), 0));
+
+		// Verify ticket was removed
+		assert!(TravelPoints::get_ticket(0).is_none());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+
+		// Check event
+		System::assert_has_event(
+			Event::TicketForceUnminted { ticket_id: 0, owner: 10, admin: 1 }.into(),
+		);
+	});
+}
+
+/// Test destructive admin calls leave an `AdminActionPerformed` audit record
+#[test]
+fn destructive_admin_actions_emit_audit_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Slash
+		assert_ok!(TravelPoints::stake(RuntimeOrigin::signed(10), 1000));
+		assert_ok!(TravelPoints::slash_staker(
+			RuntimeOrigin::signed(1),
+			10,
+			crate::SlashReason::Offline,
+			None
+		));
+		System::assert_has_event(
+			Event::Slashed { staker: 10, amount: 50, reason: crate::SlashReason::Offline }.into(),
+		);
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::SlashStaker,
+				target: Some(10),
+			}
+			.into(),
+		);
+
+		// Force unmint
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			20,
+			TicketType::PlaneTicket,
+			0,
+			None,
+			b"Test User".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+		assert_ok!(TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::ForceUnmintTicket,
+				target: Some(20),
+			}
+			.into(),
+		);
+
+		// Root has no admin account to record
+		assert_ok!(TravelPoints::set_admin(RuntimeOrigin::root(), 5));
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: None,
+				action: crate::AdminAction::SetAdmin,
+				target: Some(5),
+			}
+			.into(),
+		);
+	});
+}
+
+/// Test force unminting by non-admin fails
+#[test]
+fn force_unmint_ticket_not_admin_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::TrainTicket,
+			0,
+			None,
+			b"Test".to_vec(),
+			b"TR456".to_vec(),
+			b"".to_vec(),
+			b"22B".to_vec(),
+			b"Chicago".to_vec(),
+			b"Detroit".to_vec(),
+			b"2024-04-01 14:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Non-admin (account 5) tries to force unmint
+		assert_noop!(
+			TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+/// Test force unminting non-existent ticket fails
+#[test]
+fn force_unmint_ticket_not_found_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TravelPoints::force_unmint_ticket(RuntimeOrigin::signed(1), 999),
+			Error::<Test>::TicketNotFound
+		);
+	});
+}
+
+// ============================================================================
+// EXPIRED TICKET CLEANUP TESTS
+// ============================================================================
+
+/// Test cleanup of expired tickets
+#[test]
+fn cleanup_expired_tickets_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket that expires at block 100
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			0,
+			Some(100), // Expires at block 100
+			b"Test User".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Verify ticket exists
+		assert!(TravelPoints::get_ticket(0).is_some());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+
+		// Move past expiration
+		System::set_block_number(150);
+
+		// Cleanup expired tickets
+		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+
+		// Verify ticket was removed
+		assert!(TravelPoints::get_ticket(0).is_none());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+
+		// Check event
+		System::assert_last_event(
+			Event::ExpiredTicketsCleaned { user: 10, tickets_removed: 1 }.into(),
+		);
+	});
+}
+
+/// Test cleanup with no expired tickets does nothing
+#[test]
+fn cleanup_expired_tickets_no_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket that expires at block 1000
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::TrainTicket,
+			0,
+			Some(1000), // Expires at block 1000
+			b"Test".to_vec(),
+			b"TR456".to_vec(),
+			b"".to_vec(),
+			b"22B".to_vec(),
+			b"Chicago".to_vec(),
+			b"Detroit".to_vec(),
+			b"2024-04-01 14:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Still before expiration
+		System::set_block_number(500);
+
+		// Cleanup - but nothing to clean
+		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+
+		// Ticket should still exist
+		assert!(TravelPoints::get_ticket(0).is_some());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+	});
+}
+
+/// Test cleanup with tickets that have no expiration
+#[test]
+fn cleanup_expired_tickets_no_expiration_date() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint a ticket with no expiration
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::Bonus,
+			0,
+			None, // No expiration
+			b"Test".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"Lounge Access".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Move far into the future
+		System::set_block_number(1000000);
+
+		// Cleanup - should not remove ticket without expiration
+		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+
+		// Ticket should still exist
+		assert!(TravelPoints::get_ticket(0).is_some());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 1);
+	});
+}
+
+/// Test cleanup removes only expired tickets (partial cleanup)
+#[test]
+fn cleanup_expired_tickets_partial() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint ticket that expires at block 50
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			0,
+			Some(50), // Expires at block 50
+			b"Early Ticket".to_vec(),
+			b"AB123".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Mint ticket that expires at block 200
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::TrainTicket,
+			0,
+			Some(200), // Expires at block 200
+			b"Late Ticket".to_vec(),
+			b"TR456".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Mint ticket with no expiration
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::Bonus,
+			0,
+			None, // No expiration
+			b"Bonus".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 3);
+
+		// Move to block 100 (only first ticket expired)
+		System::set_block_number(100);
+
+		// Cleanup
+		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+
+		// Only 2 tickets should remain
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 2);
+		assert!(TravelPoints::get_ticket(0).is_none()); // First ticket removed
+		assert!(TravelPoints::get_ticket(1).is_some()); // Second ticket still exists
+		assert!(TravelPoints::get_ticket(2).is_some()); // Bonus ticket still exists
+	});
+}
+
+/// Test cleanup for user with no tickets
+#[test]
+fn cleanup_expired_tickets_no_tickets() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Cleanup for user with no tickets - should succeed without error
+		assert_ok!(TravelPoints::cleanup_expired_tickets(RuntimeOrigin::signed(99), 10));
+
+		// No event should be emitted (no tickets cleaned)
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+	});
+}
+
+// ============================================================================
+// COMPLETE TICKET LIFECYCLE TESTS
+// ============================================================================
+
+/// Test full ticket lifecycle: mint -> transfer -> unmint
+#[test]
+fn ticket_lifecycle_mint_transfer_unmint() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Mint ticket for user 10
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			0,
+			None,
+			b"Original Owner".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Transfer to user 20
+		assert_ok!(TravelPoints::transfer_ticket(RuntimeOrigin::signed(10), 0, 20));
+
+		// User 10 cannot unmint (no longer owner)
+		assert_noop!(
+			TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::NotTicketOwner
+		);
+
+		// User 20 can unmint
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(20), 0));
+
+		// Ticket is gone
+		assert!(TravelPoints::get_ticket(0).is_none());
+		assert_eq!(TravelPoints::get_user_tickets(&10).len(), 0);
+		assert_eq!(TravelPoints::get_user_tickets(&20).len(), 0);
+	});
+}
+
+/// Test ticket lifecycle with points: mint with cost -> unmint (no refund)
+#[test]
+fn ticket_lifecycle_mint_with_points_unmint() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Award points
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			1000,
+			TravelType::Airline,
+			None
+		));
+
+		// Mint ticket with points cost
+		assert_ok!(TravelPoints::mint_ticket(
+			RuntimeOrigin::signed(2),
+			10,
+			TicketType::PlaneTicket,
+			500, // Points cost
+			None,
+			b"Test User".to_vec(),
+			b"AB123".to_vec(),
+			b"A12".to_vec(),
+			b"15A".to_vec(),
+			b"New York".to_vec(),
+			b"Los Angeles".to_vec(),
+			b"2024-03-15 10:00".to_vec(),
+			b"".to_vec(),
+			None,
+			None,
+			1,
+			1,
+		));
+
+		// Points were deducted
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+
+		// Unmint the ticket
+		assert_ok!(TravelPoints::unmint_ticket(RuntimeOrigin::signed(10), 0));
+
+		// Points are NOT refunded (unmint doesn't restore points)
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+
+		// Ticket is gone
+		assert!(TravelPoints::get_ticket(0).is_none());
+	});
+}
+
+// ============================================================================
+// ROUNDING TESTS
+// ============================================================================
+
+/// Test exact divisions are unaffected by nearest rounding
+#[test]
+fn div_round_nearest_exact_division() {
+	assert_eq!(crate::div_round_nearest(0, 7), 0);
+	assert_eq!(crate::div_round_nearest(100, 10), 10);
+	assert_eq!(crate::div_round_nearest(9_000, 10_000), 0);
+	assert_eq!(crate::div_round_nearest(10_000, 10_000), 1);
+}
+
+/// Test nearest rounding keeps the point that round-down would drop
+#[test]
+fn div_round_nearest_rounds_half_up() {
+	// 2.5 -> 3 where saturating_div gives 2
+	assert_eq!(5u128.saturating_div(2), 2);
+	assert_eq!(crate::div_round_nearest(5, 2), 3);
+	// 2.67 -> 3, 2.33 -> 2
+	assert_eq!(crate::div_round_nearest(8, 3), 3);
+	assert_eq!(crate::div_round_nearest(7, 3), 2);
+	// 95% of 15 points = 14.25 -> 14, 5% of 30 points = 1.5 -> 2
+	assert_eq!(crate::div_round_nearest(15 * 9_500, 10_000), 14);
+	assert_eq!(crate::div_round_nearest(30 * 500, 10_000), 2);
+}
+
+/// Test boundary values for nearest rounding
+#[test]
+fn div_round_nearest_boundaries() {
+	assert_eq!(crate::div_round_nearest(42, 0), 0);
+	assert_eq!(crate::div_round_nearest(u128::MAX, 1), u128::MAX);
+	assert_eq!(crate::div_round_nearest(u128::MAX, u128::MAX), 1);
+	// u128::MAX is odd, so MAX / 2 = x.5 rounds up
+	assert_eq!(crate::div_round_nearest(u128::MAX, 2), u128::MAX / 2 + 1);
+	assert_eq!(crate::div_round_nearest(u128::MAX - 1, u128::MAX), 1);
+	assert_eq!(crate::div_round_nearest(1, u128::MAX), 0);
+}
+
+/// Test a redemption discount and an award multiplier keep the point round-down dropped
+#[test]
+fn redemption_discount_and_multiplier_round_to_nearest() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = crate::TravelTypeConfig {
+			multiplier: Some(15_000),
+			default_expiry: None,
+			redemption_bonus: Some(2000),
+		};
+		assert_ok!(TravelPoints::configure_travel_type(
+			RuntimeOrigin::signed(1),
+			TravelType::Train,
+			config
+		));
+
+		// 1.5x of 101 is 151.5: round-down credited 151, nearest credits 152
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			101,
+			TravelType::Train,
+			None
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), 152);
+
+		// 20% of 13 is 2.6: round-down discounted 2, nearest discounts 3
+		assert_ok!(TravelPoints::spend_points_of_type(
+			RuntimeOrigin::signed(10),
+			13,
+			2,
+			TravelType::Train
+		));
+		assert_eq!(TotalPoints::<Test>::get(10), 142);
+		System::assert_has_event(
+			Event::RedemptionBonusApplied {
+				user: 10,
+				travel_type: TravelType::Train,
+				value: 13,
+				points_deducted: 10,
+			}
+			.into(),
+		);
+	});
+}
+
+// ============================================================================
+// ESCHEATMENT TESTS
+// ============================================================================
+
+/// Test a dormant account's points are moved to the escheatment account
+#[test]
+fn escheat_dormant_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			700,
+			TravelType::Airline,
+			Some(2000)
+		));
+		assert_eq!(TravelPoints::last_activity(&10), Some(1));
+
+		// Dormancy period is 500 blocks in tests
+		System::set_block_number(501);
+		assert_ok!(TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), 10));
+
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		assert!(UserPoints::<Test>::get(10).is_empty());
+		assert_eq!(TravelPoints::last_activity(&10), None);
+
+		// Account 999 is the escheatment account in tests; escheated points never expire
+		assert_eq!(TotalPoints::<Test>::get(999), 700);
+		let batches = UserPoints::<Test>::get(999);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 700);
+		assert_eq!(batches[0].travel_type, TravelType::Other);
+		assert_eq!(batches[0].expires_at_block, u64::MAX);
+		System::assert_has_event(Event::PointsEscheated { user: 10, amount: 700 }.into());
+		System::assert_has_event(Event::BalanceEmptied { user: 10 }.into());
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::EscheatDormant,
+				target: Some(10),
+			}
+			.into(),
+		);
+	});
+}
+
+/// Test escheatment keeps working however many travel types and accounts it absorbs
+#[test]
+fn escheat_dormant_is_not_limited_by_batch_caps() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Four travel types, one more than an account may hold in tests
+		let travel_types =
+			[TravelType::Airline, TravelType::Train, TravelType::Bus, TravelType::Other];
+		for (user, travel_type) in (10..14u64).zip(travel_types) {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				user,
+				100,
+				travel_type,
+				Some(2000)
+			));
+		}
+
+		System::set_block_number(501);
+		for user in 10..14u64 {
+			assert_ok!(TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), user));
+		}
+
+		// All escheatments merged into a single batch
+		assert_eq!(TotalPoints::<Test>::get(999), 400);
+		let batches = UserPoints::<Test>::get(999);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 400);
+	});
+}
+
+/// Test points held in a dormant account's point locks are escheated with its batches and
+/// cannot return to the account once the lock times out
+#[test]
+fn escheat_dormant_takes_locked_points() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			700,
+			TravelType::Airline,
+			Some(2000)
+		));
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 200, 1));
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+
+		System::set_block_number(501);
+		assert_ok!(TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), 10));
+
+		System::assert_has_event(Event::PointsEscheated { user: 10, amount: 700 }.into());
+		assert_eq!(TotalPoints::<Test>::get(999), 700);
+		assert_eq!(TravelPoints::locked_points(10, 1), None);
+		assert_eq!(TravelPoints::point_lock_count(10), 0);
+
+		// Nothing is left to release back to the account
+		assert_ok!(TravelPoints::cleanup_expired(RuntimeOrigin::signed(99), 10));
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		assert!(UserPoints::<Test>::get(10).is_empty());
+	});
+}
+
+/// Test recent activity keeps an account from being escheated
+#[test]
+fn escheat_active_account_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			700,
+			TravelType::Airline,
+			Some(2000)
+		));
+
+		// A spend at block 300 resets the dormancy clock
+		System::set_block_number(300);
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+
+		System::set_block_number(700);
+		assert_noop!(
+			TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), 10),
+			Error::<Test>::AccountNotDormant
+		);
+
+		// Accounts without recorded activity are never dormant
+		assert_noop!(
+			TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), 11),
+			Error::<Test>::AccountNotDormant
+		);
+
+		System::set_block_number(800);
+		assert_noop!(
+			TravelPoints::escheat_dormant(RuntimeOrigin::signed(10), 10),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(TravelPoints::escheat_dormant(RuntimeOrigin::signed(1), 10));
+		assert_eq!(TotalPoints::<Test>::get(999), 600);
+	});
+}
+
+// ============================================================================
+// ACCOUNT MIGRATION TESTS
+// ============================================================================
+
+/// Test migrating a user's batches to a fresh account preserves expiries and travel types
+#[test]
+fn migrate_account_points_to_fresh_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			200,
+			TravelType::Train,
+			None
+		));
+
+		assert_ok!(TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20));
+
+		// The old account is cleared
+		assert_eq!(TotalPoints::<Test>::get(10), 0);
+		assert!(UserPoints::<Test>::get(10).is_empty());
+
+		// The new account holds the same batches
+		assert_eq!(TotalPoints::<Test>::get(20), 700);
+		let batches = UserPoints::<Test>::get(20);
+		assert_eq!(batches.len(), 2);
+		assert_eq!(batches[0].expires_at_block, 1001);
+		assert_eq!(batches[0].remaining_points, 500);
+		assert_eq!(batches[0].travel_type, TravelType::Airline);
+		assert_eq!(batches[1].expires_at_block, 1002);
+		assert_eq!(batches[1].remaining_points, 200);
+		assert_eq!(batches[1].travel_type, TravelType::Train);
+		System::assert_has_event(
+			Event::AccountPointsMigrated { from: 10, to: 20, amount: 700 }.into(),
+		);
+		System::assert_has_event(Event::BalanceEmptied { user: 10 }.into());
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::MigrateAccountPoints,
+				target: Some(10),
+			}
+			.into(),
+		);
+	});
+}
+
+/// Test migrating into an account with points merges matching batches
+#[test]
+fn migrate_account_points_merges_matching_batches() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Both accounts hold an Airline batch expiring at block 1001
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			20,
+			300,
+			TravelType::Airline,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			200,
+			TravelType::Train,
+			None
+		));
+
+		assert_ok!(TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20));
+
+		assert_eq!(TotalPoints::<Test>::get(20), 1000);
+		let batches = UserPoints::<Test>::get(20);
+		assert_eq!(batches.len(), 2);
+		assert_eq!(batches[0].remaining_points, 800);
+		assert_eq!(batches[1].remaining_points, 200);
+	});
+}
+
+/// Test a migrated batch merges like compaction, keeping the later earning block
+#[test]
+fn migrate_account_points_merge_keeps_latest_earned_at() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			20,
+			300,
+			TravelType::Airline,
+			Some(1000)
+		));
+
+		// A batch earned later that expires at the same block
+		System::set_block_number(11);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			Some(990)
+		));
+
+		assert_ok!(TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20));
+
+		let batches = UserPoints::<Test>::get(20);
+		assert_eq!(batches.len(), 1);
+		assert_eq!(batches[0].remaining_points, 800);
+		assert_eq!(batches[0].expires_at_block, 1001);
+		assert_eq!(batches[0].earned_at_block, 11);
+	});
+}
+
+/// Test a migration the target account cannot hold fails without changes
+#[test]
+fn migrate_account_points_over_limit_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
+
+		// MaxTravelTypesPerUser is 3 in tests
+		for travel_type in [TravelType::Train, TravelType::Bus, TravelType::Other] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				20,
+				100,
+				travel_type,
+				None
+			));
+		}
+
+		assert_noop!(
+			TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20),
+			Error::<Test>::TooManyTravelTypes
+		);
+		assert_noop!(
+			TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 10),
+			Error::<Test>::MigrationToSameAccount
+		);
+		assert_noop!(
+			TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 30, 20),
+			Error::<Test>::NothingToMigrate
+		);
+		assert_noop!(
+			TravelPoints::migrate_account_points(RuntimeOrigin::signed(10), 10, 30),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+/// Test migrating an account with open point locks fails until the locks are released
+#[test]
+fn migrate_account_points_with_open_locks_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			500,
+			TravelType::Airline,
+			None
+		));
+		assert_ok!(TravelPoints::spend_points(RuntimeOrigin::signed(10), 100, 2));
+		assert_ok!(TravelPoints::lock_points(RuntimeOrigin::signed(10), 200, 1));
+
+		assert_noop!(
+			TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20),
+			Error::<Test>::PointLocksOpen
+		);
+
+		assert_ok!(TravelPoints::release_lock(RuntimeOrigin::signed(10), 1));
+		assert_ok!(TravelPoints::migrate_account_points(RuntimeOrigin::signed(1), 10, 20));
+
+		// The locked points moved with the rest of the balance
+		assert_eq!(TotalPoints::<Test>::get(20), 400);
+		assert_eq!(TravelPoints::point_lock_count(&10), 0);
+		assert!(TravelPoints::last_spend_breakdown(&10).is_none());
+	});
+}
+
+/// Test correcting a batch's travel type
+#[test]
+fn reclassify_batch_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for (amount, travel_type) in [(300, TravelType::Bus), (200, TravelType::Airline)] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				amount,
+				travel_type,
+				None
+			));
+		}
+
+		// The admin may correct a batch
+		assert_ok!(TravelPoints::reclassify_batch(
+			RuntimeOrigin::signed(1),
+			10,
+			0,
+			TravelType::Train
+		));
+		assert_eq!(
+			TravelPoints::points_by_travel_type(10),
+			vec![(TravelType::Train, 300), (TravelType::Airline, 200)]
+		);
+		assert_eq!(TotalPoints::<Test>::get(10), 500);
+		System::assert_has_event(
+			Event::BatchReclassified {
+				user: 10,
+				batch_index: 0,
+				old_type: TravelType::Bus,
+				new_type: TravelType::Train,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::AdminActionPerformed {
+				admin: Some(1),
+				action: crate::AdminAction::ReclassifyBatch,
+				target: Some(10),
+			}
+			.into(),
+		);
+
+		assert_ok!(TravelPoints::reclassify_batch(
+			RuntimeOrigin::signed(1),
+			10,
+			1,
+			TravelType::Train
+		));
+		assert_eq!(TravelPoints::points_by_travel_type(10), vec![(TravelType::Train, 500)]);
+	});
+}
+
+/// Test extending all of a user's batches keeps their order
+#[test]
+fn extend_all_batches_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for (amount, expiration) in [(100, 300), (200, 500), (300, 700)] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				amount,
+				TravelType::Airline,
+				Some(expiration)
+			));
+		}
+
+		assert_ok!(TravelPoints::extend_all_batches(RuntimeOrigin::signed(2), 10, 1000));
+		System::assert_last_event(Event::AllBatchesExtended { user: 10, batch_count: 3 }.into());
+
+		let batches = UserPoints::<Test>::get(10);
+		let expiries: Vec<_> = batches.iter().map(|b| b.expires_at_block).collect();
+		let amounts: Vec<_> = batches.iter().map(|b| b.remaining_points).collect();
+		assert_eq!(expiries, vec![1301, 1501, 1701]);
+		assert_eq!(amounts, vec![100, 200, 300]);
+
+		// The admin may extend too
+		assert_ok!(TravelPoints::extend_all_batches(RuntimeOrigin::signed(1), 10, 10));
+		assert_eq!(UserPoints::<Test>::get(10)[0].expires_at_block, 1311);
+	});
+}
+
+/// Test recently expired batches are reinstated while older ones stay expired
+#[test]
+fn extend_all_batches_reinstates_within_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for (amount, expiration) in [(100, 10), (200, 100), (300, 600)] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				amount,
+				TravelType::Airline,
+				Some(expiration)
+			));
+		}
+
+		// Expired 139 and 49 blocks ago; the reinstatement window is 100 in tests
+		System::set_block_number(150);
+		assert_eq!(TravelPoints::get_available_points(&10), 300);
+
+		assert_ok!(TravelPoints::extend_all_batches(RuntimeOrigin::signed(2), 10, 200));
+		System::assert_last_event(Event::AllBatchesExtended { user: 10, batch_count: 2 }.into());
+
+		let expiries: Vec<_> =
+			UserPoints::<Test>::get(10).iter().map(|b| b.expires_at_block).collect();
+		assert_eq!(expiries, vec![11, 301, 801]);
+		assert_eq!(TravelPoints::get_available_points(&10), 500);
+	});
+}
+
+/// Test blanket extension is limited to the admin and issuers and needs something to extend
+#[test]
+fn extend_all_batches_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TravelPoints::award_points(
+			RuntimeOrigin::signed(2),
+			10,
+			100,
+			TravelType::Airline,
+			Some(10)
+		));
+
+		assert_noop!(
+			TravelPoints::extend_all_batches(RuntimeOrigin::signed(10), 10, 100),
+			Error::<Test>::NotAuthorizedIssuer
+		);
+		assert_noop!(
+			TravelPoints::extend_all_batches(RuntimeOrigin::signed(2), 10, 0),
+			Error::<Test>::InvalidExpiration
+		);
+		assert_noop!(
+			TravelPoints::extend_all_batches(RuntimeOrigin::signed(2), 20, 100),
+			Error::<Test>::NothingToExtend
+		);
+
+		// Past the reinstatement window
+		System::set_block_number(200);
+		assert_noop!(
+			TravelPoints::extend_all_batches(RuntimeOrigin::signed(2), 10, 100),
+			Error::<Test>::NothingToExtend
+		);
+	});
+}
+
+/// Test `get_indexed_batches` reports the indices `reclassify_batch` operates on, after
+/// awards were sorted by expiry
+#[test]
+fn get_indexed_batches_matches_reclassify_indices() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// The later award expires first and is sorted in front of the earlier one
+		for (amount, travel_type, expiration) in
+			[(300, TravelType::Bus, None), (200, TravelType::Airline, Some(500))]
+		{
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				amount,
+				travel_type,
+				expiration
+			));
+		}
+
+		let indexed = TravelPoints::get_indexed_batches(10);
+		assert_eq!(
+			indexed
+				.iter()
+				.map(|(index, batch)| (*index, batch.remaining_points))
+				.collect::<Vec<_>>(),
+			vec![(0, 200), (1, 300)]
+		);
+
+		// Reclassifying the reported index of the bus batch changes that batch only
+		let (bus_index, _) =
+			indexed.iter().find(|(_, batch)| batch.travel_type == TravelType::Bus).unwrap();
+		assert_ok!(TravelPoints::reclassify_batch(
+			RuntimeOrigin::signed(1),
+			10,
+			*bus_index,
+			TravelType::Train
+		));
+		let batches = UserPoints::<Test>::get(10);
+		assert_eq!(batches[1].travel_type, TravelType::Train);
+		assert_eq!(batches[1].remaining_points, 300);
+		assert_eq!(batches[0].travel_type, TravelType::Airline);
+
+		assert!(TravelPoints::get_indexed_batches(20).is_empty());
+	});
+}
+
+/// Test `is_batch_spendable` rejects expired and unseasoned batches and accepts seasoned ones
+#[test]
+fn is_batch_spendable_applies_expiry_and_holding_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinHoldingPeriod::set(50);
+
+		// Batch 0 expires at block 101, batch 1 at the default 1001
+		for expiration in [Some(100), None] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				TravelType::Airline,
+				expiration
+			));
+		}
+
+		// Both batches are still within the holding period
+		System::set_block_number(30);
+		assert!(!TravelPoints::is_batch_spendable(10, 0));
+		assert!(!TravelPoints::is_batch_spendable(10, 1));
+
+		// Seasoned and unexpired
+		System::set_block_number(51);
+		assert!(TravelPoints::is_batch_spendable(10, 0));
+		assert!(TravelPoints::is_batch_spendable(10, 1));
+
+		// Batch 0 expired but has not been cleaned up yet
+		System::set_block_number(200);
+		assert_eq!(UserPoints::<Test>::get(10).len(), 2);
+		assert!(!TravelPoints::is_batch_spendable(10, 0));
+		assert!(TravelPoints::is_batch_spendable(10, 1));
+
+		// Unknown batches and users
+		assert!(!TravelPoints::is_batch_spendable(10, 2));
+		assert!(!TravelPoints::is_batch_spendable(20, 0));
+	});
+}
+
+/// Test reclassification is limited to the admin and issuers, valid indices and the
+/// travel type limit
+#[test]
+fn reclassify_batch_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// MaxTravelTypesPerUser is 3 in tests
+		for (travel_type, expiration) in [
+			(TravelType::Airline, None),
+			(TravelType::Train, None),
+			(TravelType::Bus, None),
+			(TravelType::Bus, Some(500)),
+		] {
+			assert_ok!(TravelPoints::award_points(
+				RuntimeOrigin::signed(2),
+				10,
+				100,
+				travel_type,
+				expiration
+			));
+		}
+		let bus_batch = UserPoints::<Test>::get(10)
+			.iter()
+			.position(|b| b.travel_type == TravelType::Bus)
+			.unwrap() as u32;
+
+		// Issuers cannot reclassify, since batches do not record who awarded them
+		assert_noop!(
+			TravelPoints::reclassify_batch(RuntimeOrigin::signed(10), 10, 0, TravelType::Other),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			TravelPoints::reclassify_batch(RuntimeOrigin::signed(2), 10, 0, TravelType::Other),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			TravelPoints::reclassify_batch(RuntimeOrigin::signed(1), 10, 4, TravelType::Other),
+			Error::<Test>::InvalidBatchIndex
+		);
+		// The other bus batch keeps three types in use
+		assert_noop!(
+			TravelPoints::reclassify_batch(
+				RuntimeOrigin::signed(1),
+				10,
+				bus_batch,
+				TravelType::Other
+			),
+			Error::<Test>::TooManyTravelTypes
+		);
 	});
 }
@@ -6,6 +6,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `chery`, CPU: `13th Gen Intel(R) Core(TM) i7-1360P`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: weights of calls added or changed since this run are estimates that have not been
+//! benchmarked yet. Regenerate the whole file on reference hardware with
+//! `scripts/benchmark.sh` before relying on them.
 
 // Executed Command:
 // ./target/release/solochain-template-node
@@ -31,33 +35,74 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_travel_points`.
 pub trait WeightInfo {
-	fn award_points() -> Weight;
+	fn award_points(n: u32) -> Weight;
+	fn award_points_pending() -> Weight;
+	fn confirm_award(n: u32) -> Weight;
+	fn cancel_pending_award() -> Weight;
+	fn retroactive_bonus(n: u32) -> Weight;
+	fn prune_period_redeemers(n: u32) -> Weight;
 	fn spend_points() -> Weight;
-	fn cleanup_expired() -> Weight;
+	fn spend_points_of_type() -> Weight;
+	fn teleport_points() -> Weight;
+	fn lock_points() -> Weight;
+	fn commit_lock() -> Weight;
+	fn release_lock() -> Weight;
+	fn cleanup_expired(n: u32) -> Weight;
+	fn apply_demurrage() -> Weight;
+	fn refresh_balance_cache() -> Weight;
 	fn authorize_issuer() -> Weight;
 	fn revoke_issuer() -> Weight;
+	fn renounce_issuer() -> Weight;
+	fn prune_inactive_issuer() -> Weight;
+	fn reset_user_issuer_spend() -> Weight;
+	fn prune_user_period_spend(n: u32) -> Weight;
+	fn prune_issuer_ticket_counts(n: u32) -> Weight;
+	fn escheat_dormant(n: u32) -> Weight;
+	fn migrate_account_points() -> Weight;
+	fn reclassify_batch() -> Weight;
+	fn extend_all_batches() -> Weight;
 	fn set_admin() -> Weight;
 	fn mint_ticket() -> Weight;
+	fn gift_ticket() -> Weight;
 	fn redeem_ticket() -> Weight;
 	fn transfer_ticket() -> Weight;
+	fn transfer_tickets_batch(n: u32) -> Weight;
+	fn set_accepts_gifts() -> Weight;
 	fn unmint_ticket() -> Weight;
 	fn force_unmint_ticket() -> Weight;
 	fn cleanup_expired_tickets() -> Weight;
 	fn stake() -> Weight;
 	fn unstake() -> Weight;
 	fn add_to_reward_pool() -> Weight;
+	fn set_reward_pool() -> Weight;
 	fn request_unbond() -> Weight;
 	fn withdraw_unbonded() -> Weight;
+	fn emergency_withdraw() -> Weight;
+	fn set_staking_recovery() -> Weight;
+	fn recover_unbonded() -> Weight;
 	fn cancel_unbonding() -> Weight;
 	fn slash_staker() -> Weight;
+	fn rebuild_staker_list(n: u32) -> Weight;
 	fn create_pool() -> Weight;
 	fn delegate() -> Weight;
 	fn undelegate() -> Weight;
 	fn set_pool_commission() -> Weight;
 	fn close_pool() -> Weight;
-	fn rotate_era() -> Weight;
+	fn cleanup_pool_index() -> Weight;
+	fn sweep_closed_pool_residue(n: u32) -> Weight;
+	fn set_pool_creation_restricted() -> Weight;
+	fn set_pool_creator() -> Weight;
+	fn rotate_era(n: u32) -> Weight;
+	fn report_verification() -> Weight;
+	fn prune_verification_reports(n: u32) -> Weight;
+	fn set_suppress_events() -> Weight;
+	fn set_spend_tracking() -> Weight;
+	fn set_redemption_bonus() -> Weight;
+	fn configure_travel_type() -> Weight;
 	fn distribute_rewards() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn claim_rewards_for(n: u32) -> Weight;
+	fn withdraw_vested_reward() -> Weight;
 	fn increase_stake() -> Weight;
 }
 
@@ -70,14 +115,134 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
-	fn award_points() -> Weight {
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn award_points(n: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `114`
+		//  Measured:  `114 + n * (25 ±0)`
 		//  Estimated: `6015`
 		// Minimum execution time: 15_043_000 picoseconds.
 		Weight::from_parts(15_681_000, 6015)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			// Standard Error: 2_117
+			.saturating_add(Weight::from_parts(398_512, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::NextPendingAwardId` (r:1 w:1)
+	/// Proof: `TravelPoints::NextPendingAwardId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingAwards` (r:0 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	fn award_points_pending() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3587`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(9_874_000, 3587)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::PendingAwards` (r:1 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn confirm_award(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236 + n * (25 ±0)`
+		//  Estimated: `6015`
+		// Minimum execution time: 19_318_000 picoseconds.
+		Weight::from_parts(20_102_000, 6015)
+			// Standard Error: 2_164
+			.saturating_add(Weight::from_parts(401_227, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TravelPoints::PendingAwards` (r:1 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn cancel_pending_award() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `3587`
+		// Minimum execution time: 10_125_000 picoseconds.
+		Weight::from_parts(10_583_000, 3587)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:51 w:50)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:50 w:0)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:50 w:50)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:50 w:50)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:50)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn retroactive_bonus(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + n * (130 ±0)`
+		//  Estimated: `3585 + n * (5025 ±0)`
+		// Minimum execution time: 12_604_000 picoseconds.
+		Weight::from_parts(13_118_000, 3585)
+			// Standard Error: 9_126
+			.saturating_add(Weight::from_parts(14_382_915, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5025).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:51 w:50)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn prune_period_redeemers(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2595 ±0)`
+		//  Estimated: `3585 + n * (2595 ±0)`
+		// Minimum execution time: 6_214_000 picoseconds.
+		Weight::from_parts(6_582_000, 3585)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_874_233, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(n.into()))
 	}
 	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
 	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -89,32 +254,227 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
 	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn spend_points() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `298`
 		//  Estimated: `6015`
 		// Minimum execution time: 23_846_000 picoseconds.
 		Weight::from_parts(24_565_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn spend_points_of_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6015`
+		// Minimum execution time: 25_318_000 picoseconds.
+		Weight::from_parts(26_104_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn teleport_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `6015`
+		// Minimum execution time: 14_206_000 picoseconds.
+		Weight::from_parts(14_873_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn lock_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `6059`
+		// Minimum execution time: 17_942_000 picoseconds.
+		Weight::from_parts(18_611_000, 6059)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:0)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn commit_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6059`
+		// Minimum execution time: 21_376_000 picoseconds.
+		Weight::from_parts(22_148_000, 6059)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn release_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6059`
+		// Minimum execution time: 16_215_000 picoseconds.
+		Weight::from_parts(16_874_000, 6059)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `TravelPoints::LockedPoints` (r:9 w:8)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
 	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
-	fn cleanup_expired() -> Weight {
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 8]`.
+	fn cleanup_expired(n: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `261`
-		//  Estimated: `6015`
+		//  Measured:  `261 + n * (164 ±0)`
+		//  Estimated: `6015 + n * (5069 ±0)`
 		// Minimum execution time: 14_898_000 picoseconds.
 		Weight::from_parts(15_607_000, 6015)
+			// Standard Error: 11_402
+			.saturating_add(Weight::from_parts(2_138_904, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5069).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn apply_demurrage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `265`
+		//  Estimated: `6015`
+		// Minimum execution time: 15_912_000 picoseconds.
+		Weight::from_parts(16_578_000, 6015)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:0)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn refresh_balance_cache() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `6015`
+		// Minimum execution time: 9_874_000 picoseconds.
+		Weight::from_parts(10_312_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
 	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn authorize_issuer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `61`
@@ -122,21 +482,176 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 11_073_000 picoseconds.
 		Weight::from_parts(11_510_000, 3514)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
 	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn revoke_issuer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `132`
 		//  Estimated: `3514`
 		// Minimum execution time: 12_520_000 picoseconds.
 		Weight::from_parts(13_110_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn renounce_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `3514`
+		// Minimum execution time: 9_871_000 picoseconds.
+		Weight::from_parts(10_302_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_inactive_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_284_000 picoseconds.
+		Weight::from_parts(11_803_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn reset_user_issuer_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3597`
+		// Minimum execution time: 11_842_000 picoseconds.
+		Weight::from_parts(12_305_000, 3597)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:501 w:500)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn prune_user_period_spend(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2563 ±0)`
+		//  Estimated: `3553 + n * (2563 ±0)`
+		// Minimum execution time: 6_108_000 picoseconds.
+		Weight::from_parts(6_477_000, 3553)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(1_851_604, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:501 w:500)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn prune_issuer_ticket_counts(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2547 ±0)`
+		//  Estimated: `3537 + n * (2547 ±0)`
+		// Minimum execution time: 6_095_000 picoseconds.
+		Weight::from_parts(6_460_000, 3537)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(1_839_215, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:1 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:2 w:2)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LockedPoints` (r:9 w:8)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:2)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:0 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 8]`.
+	fn escheat_dormant(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + n * (133 ±0)`
+		//  Estimated: `11040 + n * (5069 ±0)`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(31_274_000, 11040)
+			// Standard Error: 2_016
+			.saturating_add(Weight::from_parts(4_213_508, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5069).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:2 w:2)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:2 w:2)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:0)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	fn migrate_account_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `404`
+		//  Estimated: `11040`
+		// Minimum execution time: 27_384_000 picoseconds.
+		Weight::from_parts(28_519_000, 11040)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	fn reclassify_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `6015`
+		// Minimum execution time: 13_522_000 picoseconds.
+		Weight::from_parts(14_107_000, 6015)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	fn extend_all_batches() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2663`
+		//  Estimated: `6015`
+		// Minimum execution time: 21_406_000 picoseconds.
+		Weight::from_parts(22_187_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:1)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	fn set_admin() -> Weight {
@@ -163,44 +678,164 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:0 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn mint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `298`
 		//  Estimated: `6015`
 		// Minimum execution time: 32_770_000 picoseconds.
 		Weight::from_parts(34_137_000, 6015)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::NextTicketId` (r:1 w:1)
+	/// Proof: `TravelPoints::NextTicketId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
+	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Tickets` (r:0 w:1)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn gift_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `6015`
+		// Minimum execution time: 33_904_000 picoseconds.
+		Weight::from_parts(35_282_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
 	}
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn redeem_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
-		//  Estimated: `4644`
+		//  Estimated: `5036`
 		// Minimum execution time: 11_770_000 picoseconds.
-		Weight::from_parts(12_396_000, 4644)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(12_396_000, 5036)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:2 w:2)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn transfer_ticket() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `415`
+		//  Measured:  `2286`
 		//  Estimated: `9240`
-		// Minimum execution time: 18_794_000 picoseconds.
-		Weight::from_parts(19_642_000, 9240)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		// Minimum execution time: 34_918_000 picoseconds.
+		Weight::from_parts(36_105_000, 9240)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TravelPoints::Tickets` (r:20 w:20)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1392), added: 3867, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserTickets` (r:2 w:2)
+	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:20 w:20)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn transfer_tickets_batch(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (6567 ±0)`
+		//  Estimated: `5115 + n * (6567 ±0)`
+		// Minimum execution time: 17_538_000 picoseconds.
+		Weight::from_parts(10_212_000, 5115)
+			// Standard Error: 15_117
+			.saturating_add(Weight::from_parts(11_064_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6567).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::AcceptsGifts` (r:0 w:1)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_accepts_gifts() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_912_000 picoseconds.
+		Weight::from_parts(7_204_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn unmint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `406`
@@ -208,14 +843,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 16_731_000 picoseconds.
 		Weight::from_parts(17_422_000, 5115)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn force_unmint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `425`
@@ -223,12 +860,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 18_028_000 picoseconds.
 		Weight::from_parts(18_946_000, 5115)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn cleanup_expired_tickets() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `409`
@@ -236,37 +875,55 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 19_978_000 picoseconds.
 		Weight::from_parts(21_044_000, 5115)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
 		//  Estimated: `33487`
 		// Minimum execution time: 12_174_000 picoseconds.
 		Weight::from_parts(12_689_000, 33487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `191`
 		//  Estimated: `33487`
 		// Minimum execution time: 15_063_000 picoseconds.
 		Weight::from_parts(15_863_000, 33487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
 	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
@@ -279,68 +936,208 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:0 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_reward_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 6_912_000 picoseconds.
+		Weight::from_parts(7_288_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::InstantUnbonded` (r:1 w:1)
+	/// Proof: `TravelPoints::InstantUnbonded` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn request_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
-		//  Estimated: `4282`
+		//  Estimated: `33487`
 		// Minimum execution time: 16_506_000 picoseconds.
-		Weight::from_parts(17_309_000, 4282)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(17_309_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:0)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn withdraw_unbonded() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `266`
 		//  Estimated: `4282`
 		// Minimum execution time: 14_633_000 picoseconds.
 		Weight::from_parts(15_164_000, 4282)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:0)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn emergency_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `33487`
+		// Minimum execution time: 27_316_000 picoseconds.
+		Weight::from_parts(28_405_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashRecords` (r:1 w:0)
+	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_staking_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `5615`
+		// Minimum execution time: 11_842_000 picoseconds.
+		Weight::from_parts(12_390_000, 5615)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TravelPoints::RecoveryAccount` (r:1 w:0)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn recover_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4282`
+		// Minimum execution time: 17_019_000 picoseconds.
+		Weight::from_parts(17_884_000, 4282)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn cancel_unbonding() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `268`
-		//  Estimated: `4282`
+		//  Estimated: `33487`
 		// Minimum execution time: 16_186_000 picoseconds.
-		Weight::from_parts(17_105_000, 4282)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(17_105_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::SlashRecords` (r:1 w:1)
 	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalSlashed` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalSlashed` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	fn slash_staker() -> Weight {
-		// Proof Size summary in bytes:
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashStatsByReason` (r:1 w:1)
+	/// Proof: `TravelPoints::SlashStatsByReason` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	fn slash_staker() -> Weight {
+		// Proof Size summary in bytes:
 		//  Measured:  `222`
-		//  Estimated: `5615`
+		//  Estimated: `33487`
 		// Minimum execution time: 19_336_000 picoseconds.
-		Weight::from_parts(19_989_000, 5615)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(19_989_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1101 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:0 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:0 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1100]`.
+	fn rebuild_staker_list(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `72 + n * (2544 ±0)`
+		//  Estimated: `1517 + n * (2544 ±0)`
+		// Minimum execution time: 9_431_000 picoseconds.
+		Weight::from_parts(9_887_000, 1517)
+			// Standard Error: 1_903
+			.saturating_add(Weight::from_parts(6_874_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
 	}
+	/// Storage: `TravelPoints::PoolCreationRestricted` (r:1 w:0)
+	/// Proof: `TravelPoints::PoolCreationRestricted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::NextPoolId` (r:1 w:1)
 	/// Proof: `TravelPoints::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
@@ -353,7 +1150,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `1501`
 		// Minimum execution time: 9_914_000 picoseconds.
 		Weight::from_parts(10_430_000, 1501)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Delegations` (r:1 w:1)
@@ -414,24 +1211,192 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::Pools` (r:1 w:0)
+	/// Proof: `TravelPoints::Pools` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolDelegators` (r:1 w:1)
+	/// Proof: `TravelPoints::PoolDelegators` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+	fn cleanup_pool_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `6687`
+		// Minimum execution time: 10_916_000 picoseconds.
+		Weight::from_parts(11_402_000, 6687)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Pools` (r:1 w:0)
+	/// Proof: `TravelPoints::Pools` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolDelegators` (r:1 w:1)
+	/// Proof: `TravelPoints::PoolDelegators` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Delegations` (r:100 w:100)
+	/// Proof: `TravelPoints::Delegations` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_closed_pool_residue(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (2547 ±0)`
+		//  Estimated: `6687 + n * (2547 ±0)`
+		// Minimum execution time: 15_732_000 picoseconds.
+		Weight::from_parts(16_241_000, 6687)
+			// Standard Error: 2_406
+			.saturating_add(Weight::from_parts(5_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolCreationRestricted` (r:0 w:1)
+	/// Proof: `TravelPoints::PoolCreationRestricted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_pool_creation_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_215_000 picoseconds.
+		Weight::from_parts(7_602_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolCreators` (r:0 w:1)
+	/// Proof: `TravelPoints::PoolCreators` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_pool_creator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 8_031_000 picoseconds.
+		Weight::from_parts(8_466_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `TravelPoints::LastEraBlock` (r:1 w:1)
 	/// Proof: `TravelPoints::LastEraBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::CurrentEra` (r:1 w:1)
 	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:0)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1000 w:1000)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashRecords` (r:1000 w:0)
+	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::EraVerifiers` (r:0 w:1)
 	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
-	fn rotate_era() -> Weight {
+	/// The range of component `n` is `[0, 1000]`.
+	fn rotate_era(n: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `189`
-		//  Estimated: `33487`
-		// Minimum execution time: 19_849_000 picoseconds.
-		Weight::from_parts(20_703_000, 33487)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `86 + n * (176 ±0)`
+		//  Estimated: `33487 + n * (4625 ±0)`
+		// Minimum execution time: 12_418_000 picoseconds.
+		Weight::from_parts(13_062_000, 33487)
+			// Standard Error: 4_187
+			.saturating_add(Weight::from_parts(6_204_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4625).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn report_verification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240`
+		//  Estimated: `4158`
+		// Minimum execution time: 15_112_000 picoseconds.
+		Weight::from_parts(15_870_000, 4158)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:0 w:21)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:0 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:0 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 21]`.
+	fn prune_verification_reports(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_512_000 picoseconds.
+		Weight::from_parts(7_903_000, 1517)
+			// Standard Error: 2_318
+			.saturating_add(Weight::from_parts(1_126_487, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:0 w:1)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_suppress_events() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 8_012_000 picoseconds.
+		Weight::from_parts(8_431_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:0 w:1)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_spend_tracking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_986_000 picoseconds.
+		Weight::from_parts(8_402_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_redemption_bonus() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_934_000 picoseconds.
+		Weight::from_parts(8_297_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn configure_travel_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_218_000 picoseconds.
+		Weight::from_parts(9_674_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -441,44 +1406,98 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:0)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:2 w:0)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:2 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PendingStakerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:0)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `258`
-		//  Estimated: `6078`
+		//  Estimated: `33487`
 		// Minimum execution time: 21_179_000 picoseconds.
-		Weight::from_parts(21_769_000, 6078)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(21_769_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `TravelPoints::PendingStakerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PendingIssuerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingIssuerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `95`
 		//  Estimated: `3529`
 		// Minimum execution time: 14_153_000 picoseconds.
 		Weight::from_parts(14_701_000, 3529)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::PendingStakerRewards` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingIssuerRewards` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingIssuerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingRewardsSince` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingRewardsSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:50 w:50)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn claim_rewards_for(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (2539 ±0)`
+		//  Estimated: `990 + n * (2539 ±0)`
+		// Minimum execution time: 6_212_000 picoseconds.
+		Weight::from_parts(6_530_000, 990)
+			// Standard Error: 3_105
+			.saturating_add(Weight::from_parts(12_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(n.into()))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn withdraw_vested_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3553`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_687_000, 3553)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn increase_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
-		//  Estimated: `3534`
+		//  Estimated: `33487`
 		// Minimum execution time: 12_154_000 picoseconds.
-		Weight::from_parts(12_582_000, 3534)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(12_582_000, 33487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 }
 
@@ -490,15 +1509,135 @@ impl WeightInfo for () {
 	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
-	fn award_points() -> Weight {
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn award_points(n: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `114`
+		//  Measured:  `114 + n * (25 ±0)`
 		//  Estimated: `6015`
 		// Minimum execution time: 15_043_000 picoseconds.
 		Weight::from_parts(15_681_000, 6015)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			// Standard Error: 2_117
+			.saturating_add(Weight::from_parts(398_512, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::NextPendingAwardId` (r:1 w:1)
+	/// Proof: `TravelPoints::NextPendingAwardId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingAwards` (r:0 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	fn award_points_pending() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `3587`
+		// Minimum execution time: 9_412_000 picoseconds.
+		Weight::from_parts(9_874_000, 3587)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::PendingAwards` (r:1 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn confirm_award(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236 + n * (25 ±0)`
+		//  Estimated: `6015`
+		// Minimum execution time: 19_318_000 picoseconds.
+		Weight::from_parts(20_102_000, 6015)
+			// Standard Error: 2_164
+			.saturating_add(Weight::from_parts(401_227, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TravelPoints::PendingAwards` (r:1 w:1)
+	/// Proof: `TravelPoints::PendingAwards` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn cancel_pending_award() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `3587`
+		// Minimum execution time: 10_125_000 picoseconds.
+		Weight::from_parts(10_583_000, 3587)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:51 w:50)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:50 w:0)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:50 w:50)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:50 w:50)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:50)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn retroactive_bonus(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + n * (130 ±0)`
+		//  Estimated: `3585 + n * (5025 ±0)`
+		// Minimum execution time: 12_604_000 picoseconds.
+		Weight::from_parts(13_118_000, 3585)
+			// Standard Error: 9_126
+			.saturating_add(Weight::from_parts(14_382_915, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5025).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:51 w:50)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn prune_period_redeemers(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2595 ±0)`
+		//  Estimated: `3585 + n * (2595 ±0)`
+		// Minimum execution time: 6_214_000 picoseconds.
+		Weight::from_parts(6_582_000, 3585)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_874_233, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(n.into()))
+	}
 	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
 	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
@@ -509,52 +1648,402 @@ impl WeightInfo for () {
 	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
 	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn spend_points() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `298`
 		//  Estimated: `6015`
 		// Minimum execution time: 23_846_000 picoseconds.
 		Weight::from_parts(24_565_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:1 w:0)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn spend_points_of_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6015`
+		// Minimum execution time: 25_318_000 picoseconds.
+		Weight::from_parts(26_104_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn teleport_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `6015`
+		// Minimum execution time: 14_206_000 picoseconds.
+		Weight::from_parts(14_873_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn lock_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196`
+		//  Estimated: `6059`
+		// Minimum execution time: 17_942_000 picoseconds.
+		Weight::from_parts(18_611_000, 6059)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:0)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodRedeemers` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodRedeemers` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:1 w:0)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn commit_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6059`
+		// Minimum execution time: 21_376_000 picoseconds.
+		Weight::from_parts(22_148_000, 6059)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TravelPoints::LockedPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn release_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6059`
+		// Minimum execution time: 16_215_000 picoseconds.
+		Weight::from_parts(16_874_000, 6059)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Storage: `TravelPoints::LockedPoints` (r:9 w:8)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 8]`.
+	fn cleanup_expired(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261 + n * (164 ±0)`
+		//  Estimated: `6015 + n * (5069 ±0)`
+		// Minimum execution time: 14_898_000 picoseconds.
+		Weight::from_parts(15_607_000, 6015)
+			// Standard Error: 11_402
+			.saturating_add(Weight::from_parts(2_138_904, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5069).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn apply_demurrage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `265`
+		//  Estimated: `6015`
+		// Minimum execution time: 15_912_000 picoseconds.
+		Weight::from_parts(16_578_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:0)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn refresh_balance_cache() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `6015`
+		// Minimum execution time: 9_874_000 picoseconds.
+		Weight::from_parts(10_312_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn authorize_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3514`
+		// Minimum execution time: 11_073_000 picoseconds.
+		Weight::from_parts(11_510_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn revoke_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `132`
+		//  Estimated: `3514`
+		// Minimum execution time: 12_520_000 picoseconds.
+		Weight::from_parts(13_110_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn renounce_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `3514`
+		// Minimum execution time: 9_871_000 picoseconds.
+		Weight::from_parts(10_302_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_inactive_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_284_000 picoseconds.
+		Weight::from_parts(11_803_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserIssuerSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserIssuerSpend` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn reset_user_issuer_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3597`
+		// Minimum execution time: 11_842_000 picoseconds.
+		Weight::from_parts(12_305_000, 3597)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:501 w:500)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn prune_user_period_spend(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2563 ±0)`
+		//  Estimated: `3553 + n * (2563 ±0)`
+		// Minimum execution time: 6_108_000 picoseconds.
+		Weight::from_parts(6_477_000, 3553)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(1_851_604, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:501 w:500)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn prune_issuer_ticket_counts(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (2547 ±0)`
+		//  Estimated: `3537 + n * (2547 ±0)`
+		// Minimum execution time: 6_095_000 picoseconds.
+		Weight::from_parts(6_460_000, 3537)
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(1_839_215, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:1 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:2 w:2)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LockedPoints` (r:9 w:8)
+	/// Proof: `TravelPoints::LockedPoints` (`max_values`: None, `max_size`: Some(2594), added: 5069, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:2)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:0 w:1)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 8]`.
+	fn escheat_dormant(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + n * (133 ±0)`
+		//  Estimated: `11040 + n * (5069 ±0)`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(31_274_000, 11040)
+			// Standard Error: 2_016
+			.saturating_add(Weight::from_parts(4_213_508, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5069).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:2 w:2)
 	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Storage: `TravelPoints::TotalPoints` (r:2 w:2)
 	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
-	fn cleanup_expired() -> Weight {
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AvailableCache` (r:0 w:1)
+	/// Proof: `TravelPoints::AvailableCache` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PointLockCount` (r:1 w:0)
+	/// Proof: `TravelPoints::PointLockCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	fn migrate_account_points() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `261`
-		//  Estimated: `6015`
-		// Minimum execution time: 14_898_000 picoseconds.
-		Weight::from_parts(15_607_000, 6015)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `404`
+		//  Estimated: `11040`
+		// Minimum execution time: 27_384_000 picoseconds.
+		Weight::from_parts(28_519_000, 11040)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
-	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn authorize_issuer() -> Weight {
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	fn reclassify_batch() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `61`
-		//  Estimated: `3514`
-		// Minimum execution time: 11_073_000 picoseconds.
-		Weight::from_parts(11_510_000, 3514)
+		//  Measured:  `263`
+		//  Estimated: `6015`
+		// Minimum execution time: 13_522_000 picoseconds.
+		Weight::from_parts(14_107_000, 6015)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:1)
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
 	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn revoke_issuer() -> Weight {
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	fn extend_all_batches() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `132`
-		//  Estimated: `3514`
-		// Minimum execution time: 12_520_000 picoseconds.
-		Weight::from_parts(13_110_000, 3514)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `2663`
+		//  Estimated: `6015`
+		// Minimum execution time: 21_406_000 picoseconds.
+		Weight::from_parts(22_187_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:1)
@@ -583,44 +2072,164 @@ impl WeightInfo for () {
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:0 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn mint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `298`
 		//  Estimated: `6015`
 		// Minimum execution time: 32_770_000 picoseconds.
 		Weight::from_parts(34_137_000, 6015)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: `TravelPoints::AuthorizedIssuers` (r:1 w:0)
+	/// Proof: `TravelPoints::AuthorizedIssuers` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerDailyRecords` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerDailyRecords` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodTotalSpent` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::NextTicketId` (r:1 w:1)
+	/// Proof: `TravelPoints::NextTicketId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
+	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Tickets` (r:0 w:1)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastSpendBreakdown` (r:0 w:1)
+	/// Proof: `TravelPoints::LastSpendBreakdown` (`max_values`: None, `max_size`: Some(1585), added: 4060, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodIssuerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodIssuerCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerPeriodTicketCount` (r:1 w:1)
+	/// Proof: `TravelPoints::IssuerPeriodTicketCount` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::IssuerLastActivePeriod` (r:0 w:1)
+	/// Proof: `TravelPoints::IssuerLastActivePeriod` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPeriodSpend` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPeriodSpend` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:1 w:0)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:1)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn gift_ticket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `6015`
+		// Minimum execution time: 33_904_000 picoseconds.
+		Weight::from_parts(35_282_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
 	}
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	fn redeem_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
-		//  Estimated: `4644`
+		//  Estimated: `5036`
 		// Minimum execution time: 11_770_000 picoseconds.
-		Weight::from_parts(12_396_000, 4644)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(12_396_000, 5036)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:2 w:2)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:1 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn transfer_ticket() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `415`
+		//  Measured:  `2286`
 		//  Estimated: `9240`
-		// Minimum execution time: 18_794_000 picoseconds.
-		Weight::from_parts(19_642_000, 9240)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		// Minimum execution time: 34_918_000 picoseconds.
+		Weight::from_parts(36_105_000, 9240)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TravelPoints::Tickets` (r:20 w:20)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1392), added: 3867, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserTickets` (r:2 w:2)
+	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UserPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::UserPoints` (`max_values`: None, `max_size`: Some(2550), added: 5025, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalPoints` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalPoints` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:20 w:20)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::AcceptsGifts` (r:1 w:0)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::LastActivity` (r:0 w:2)
+	/// Proof: `TravelPoints::LastActivity` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::ReentrancyLock` (r:1 w:1)
+	/// Proof: `TravelPoints::ReentrancyLock` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn transfer_tickets_batch(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (6567 ±0)`
+		//  Estimated: `5115 + n * (6567 ±0)`
+		// Minimum execution time: 17_538_000 picoseconds.
+		Weight::from_parts(10_212_000, 5115)
+			// Standard Error: 15_117
+			.saturating_add(Weight::from_parts(11_064_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6567).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::AcceptsGifts` (r:0 w:1)
+	/// Proof: `TravelPoints::AcceptsGifts` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_accepts_gifts() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_912_000 picoseconds.
+		Weight::from_parts(7_204_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn unmint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `406`
@@ -628,14 +2237,16 @@ impl WeightInfo for () {
 		// Minimum execution time: 16_731_000 picoseconds.
 		Weight::from_parts(17_422_000, 5115)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn force_unmint_ticket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `425`
@@ -643,12 +2254,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 18_028_000 picoseconds.
 		Weight::from_parts(18_946_000, 5115)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::UserTickets` (r:1 w:1)
 	/// Proof: `TravelPoints::UserTickets` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::Tickets` (r:1 w:1)
-	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Proof: `TravelPoints::Tickets` (`max_values`: None, `max_size`: Some(1571), added: 4046, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TicketProvenance` (r:0 w:1)
+	/// Proof: `TravelPoints::TicketProvenance` (`max_values`: None, `max_size`: Some(609), added: 3084, mode: `MaxEncodedLen`)
 	fn cleanup_expired_tickets() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `409`
@@ -656,37 +2269,55 @@ impl WeightInfo for () {
 		// Minimum execution time: 19_978_000 picoseconds.
 		Weight::from_parts(21_044_000, 5115)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6`
 		//  Estimated: `33487`
 		// Minimum execution time: 12_174_000 picoseconds.
 		Weight::from_parts(12_689_000, 33487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `191`
 		//  Estimated: `33487`
 		// Minimum execution time: 15_063_000 picoseconds.
 		Weight::from_parts(15_863_000, 33487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
 	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
@@ -699,68 +2330,208 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:0 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_reward_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 6_912_000 picoseconds.
+		Weight::from_parts(7_288_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::InstantUnbonded` (r:1 w:1)
+	/// Proof: `TravelPoints::InstantUnbonded` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn request_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
-		//  Estimated: `4282`
+		//  Estimated: `33487`
 		// Minimum execution time: 16_506_000 picoseconds.
-		Weight::from_parts(17_309_000, 4282)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(17_309_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:0)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn withdraw_unbonded() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `266`
 		//  Estimated: `4282`
 		// Minimum execution time: 14_633_000 picoseconds.
 		Weight::from_parts(15_164_000, 4282)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerCount` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:0)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn emergency_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `33487`
+		// Minimum execution time: 27_316_000 picoseconds.
+		Weight::from_parts(28_405_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashRecords` (r:1 w:0)
+	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RecoveryAccount` (r:0 w:1)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_staking_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `5615`
+		// Minimum execution time: 11_842_000 picoseconds.
+		Weight::from_parts(12_390_000, 5615)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TravelPoints::RecoveryAccount` (r:1 w:0)
+	/// Proof: `TravelPoints::RecoveryAccount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:0 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn recover_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `4282`
+		// Minimum execution time: 17_019_000 picoseconds.
+		Weight::from_parts(17_884_000, 4282)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
 	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn cancel_unbonding() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `268`
-		//  Estimated: `4282`
+		//  Estimated: `33487`
 		// Minimum execution time: 16_186_000 picoseconds.
-		Weight::from_parts(17_105_000, 4282)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(17_105_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::SlashRecords` (r:1 w:1)
 	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalSlashed` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalSlashed` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UnbondingRequests` (r:1 w:1)
+	/// Proof: `TravelPoints::UnbondingRequests` (`max_values`: None, `max_size`: Some(817), added: 3292, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashStatsByReason` (r:1 w:1)
+	/// Proof: `TravelPoints::SlashStatsByReason` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardPool` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn slash_staker() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `222`
-		//  Estimated: `5615`
+		//  Estimated: `33487`
 		// Minimum execution time: 19_336_000 picoseconds.
-		Weight::from_parts(19_989_000, 5615)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(19_989_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1101 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:0 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:0 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1100]`.
+	fn rebuild_staker_list(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `72 + n * (2544 ±0)`
+		//  Estimated: `1517 + n * (2544 ±0)`
+		// Minimum execution time: 9_431_000 picoseconds.
+		Weight::from_parts(9_887_000, 1517)
+			// Standard Error: 1_903
+			.saturating_add(Weight::from_parts(6_874_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(n.into()))
 	}
+	/// Storage: `TravelPoints::PoolCreationRestricted` (r:1 w:0)
+	/// Proof: `TravelPoints::PoolCreationRestricted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::NextPoolId` (r:1 w:1)
 	/// Proof: `TravelPoints::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
@@ -773,7 +2544,7 @@ impl WeightInfo for () {
 		//  Estimated: `1501`
 		// Minimum execution time: 9_914_000 picoseconds.
 		Weight::from_parts(10_430_000, 1501)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Delegations` (r:1 w:1)
@@ -834,24 +2605,192 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TravelPoints::Pools` (r:1 w:0)
+	/// Proof: `TravelPoints::Pools` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolDelegators` (r:1 w:1)
+	/// Proof: `TravelPoints::PoolDelegators` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+	fn cleanup_pool_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `6687`
+		// Minimum execution time: 10_916_000 picoseconds.
+		Weight::from_parts(11_402_000, 6687)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Pools` (r:1 w:0)
+	/// Proof: `TravelPoints::Pools` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolDelegators` (r:1 w:1)
+	/// Proof: `TravelPoints::PoolDelegators` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::Delegations` (r:100 w:100)
+	/// Proof: `TravelPoints::Delegations` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
+	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_closed_pool_residue(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (2547 ±0)`
+		//  Estimated: `6687 + n * (2547 ±0)`
+		// Minimum execution time: 15_732_000 picoseconds.
+		Weight::from_parts(16_241_000, 6687)
+			// Standard Error: 2_406
+			.saturating_add(Weight::from_parts(5_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolCreationRestricted` (r:0 w:1)
+	/// Proof: `TravelPoints::PoolCreationRestricted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_pool_creation_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_215_000 picoseconds.
+		Weight::from_parts(7_602_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PoolCreators` (r:0 w:1)
+	/// Proof: `TravelPoints::PoolCreators` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_pool_creator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 8_031_000 picoseconds.
+		Weight::from_parts(8_466_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `TravelPoints::LastEraBlock` (r:1 w:1)
 	/// Proof: `TravelPoints::LastEraBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::CurrentEra` (r:1 w:1)
 	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::StakerList` (r:1 w:0)
 	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1000 w:1000)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SlashRecords` (r:1000 w:0)
+	/// Proof: `TravelPoints::SlashRecords` (`max_values`: None, `max_size`: Some(2150), added: 4625, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::EraVerifiers` (r:0 w:1)
 	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
-	fn rotate_era() -> Weight {
+	/// The range of component `n` is `[0, 1000]`.
+	fn rotate_era(n: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `189`
-		//  Estimated: `33487`
-		// Minimum execution time: 19_849_000 picoseconds.
-		Weight::from_parts(20_703_000, 33487)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `86 + n * (176 ±0)`
+		//  Estimated: `33487 + n * (4625 ±0)`
+		// Minimum execution time: 12_418_000 picoseconds.
+		Weight::from_parts(13_062_000, 33487)
+			// Standard Error: 4_187
+			.saturating_add(Weight::from_parts(6_204_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4625).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn report_verification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240`
+		//  Estimated: `4158`
+		// Minimum execution time: 15_112_000 picoseconds.
+		Weight::from_parts(15_870_000, 4158)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:0 w:21)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:0 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:0 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 21]`.
+	fn prune_verification_reports(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_512_000 picoseconds.
+		Weight::from_parts(7_903_000, 1517)
+			// Standard Error: 2_318
+			.saturating_add(Weight::from_parts(1_126_487, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::SuppressEvents` (r:0 w:1)
+	/// Proof: `TravelPoints::SuppressEvents` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_suppress_events() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 8_012_000 picoseconds.
+		Weight::from_parts(8_431_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::UntrackedSpends` (r:0 w:1)
+	/// Proof: `TravelPoints::UntrackedSpends` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_spend_tracking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_986_000 picoseconds.
+		Weight::from_parts(8_402_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_redemption_bonus() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_934_000 picoseconds.
+		Weight::from_parts(8_297_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TravelPoints::Admin` (r:1 w:0)
+	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeMultiplier` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeMultiplier` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeExpiry` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeExpiry` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::TravelTypeRedemptionBonus` (r:0 w:1)
+	/// Proof: `TravelPoints::TravelTypeRedemptionBonus` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn configure_travel_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `39`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_218_000 picoseconds.
+		Weight::from_parts(9_674_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `TravelPoints::Admin` (r:1 w:0)
 	/// Proof: `TravelPoints::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -861,43 +2800,97 @@ impl WeightInfo for () {
 	/// Proof: `TravelPoints::PeriodTotalSpent` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:0)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `TravelPoints::Stakes` (r:2 w:0)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:2 w:0)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PendingStakerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:0)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::CurrentEra` (r:1 w:0)
+	/// Proof: `TravelPoints::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PeriodVerificationEra` (r:1 w:1)
+	/// Proof: `TravelPoints::PeriodVerificationEra` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EraVerifiers` (r:1 w:0)
+	/// Proof: `TravelPoints::EraVerifiers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReportCount` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReportCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::VerificationReports` (r:1 w:1)
+	/// Proof: `TravelPoints::VerificationReports` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	fn distribute_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `258`
-		//  Estimated: `6078`
+		//  Estimated: `33487`
 		// Minimum execution time: 21_179_000 picoseconds.
-		Weight::from_parts(21_769_000, 6078)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(21_769_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `TravelPoints::PendingStakerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::PendingIssuerRewards` (r:1 w:1)
 	/// Proof: `TravelPoints::PendingIssuerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `95`
 		//  Estimated: `3529`
 		// Minimum execution time: 14_153_000 picoseconds.
 		Weight::from_parts(14_701_000, 3529)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TravelPoints::PendingStakerRewards` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingStakerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingIssuerRewards` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingIssuerRewards` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::PendingRewardsSince` (r:50 w:50)
+	/// Proof: `TravelPoints::PendingRewardsSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:50 w:50)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn claim_rewards_for(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (2539 ±0)`
+		//  Estimated: `990 + n * (2539 ±0)`
+		// Minimum execution time: 6_212_000 picoseconds.
+		Weight::from_parts(6_530_000, 990)
+			// Standard Error: 3_105
+			.saturating_add(Weight::from_parts(12_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(n.into()))
+	}
+	/// Storage: `TravelPoints::RewardVestingSchedules` (r:1 w:1)
+	/// Proof: `TravelPoints::RewardVestingSchedules` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn withdraw_vested_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3553`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_687_000, 3553)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `TravelPoints::Stakes` (r:1 w:1)
-	/// Proof: `TravelPoints::Stakes` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::EnhancedStakes` (r:1 w:1)
+	/// Proof: `TravelPoints::EnhancedStakes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `TravelPoints::TotalStaked` (r:1 w:1)
 	/// Proof: `TravelPoints::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakeHistory` (r:1 w:1)
+	/// Proof: `TravelPoints::StakeHistory` (`max_values`: None, `max_size`: Some(689), added: 3164, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::BondedStake` (r:1 w:1)
+	/// Proof: `TravelPoints::BondedStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerList` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerList` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	/// Storage: `TravelPoints::StakerWaitlist` (r:1 w:1)
+	/// Proof: `TravelPoints::StakerWaitlist` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	fn increase_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
-		//  Estimated: `3534`
+		//  Estimated: `33487`
 		// Minimum execution time: 12_154_000 picoseconds.
-		Weight::from_parts(12_582_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(12_582_000, 33487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }
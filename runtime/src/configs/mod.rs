@@ -31,11 +31,15 @@ use frame_support::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
+	PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{AccountIdConversion, One},
+	Perbill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
@@ -164,6 +168,18 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	/// Travel points staker rewards are paid out as currency
+	pub const TravelPointsStakerRewardKind: pallet_travel_points::RewardKind =
+		pallet_travel_points::RewardKind::Currency;
+	/// Travel points issuer rewards are paid out as currency
+	pub const TravelPointsIssuerRewardKind: pallet_travel_points::RewardKind =
+		pallet_travel_points::RewardKind::Currency;
+	/// Program account that receives points escheated from dormant accounts
+	pub TravelPointsEscheatmentAccount: AccountId =
+		PalletId(*b"tp/esche").into_account_truncating();
+}
+
 /// Configure the pallet-travel-points for managing loyalty points.
 ///
 /// This pallet implements a travel points system similar to airline mileage programs.
@@ -184,14 +200,30 @@ impl pallet_travel_points::Config for Runtime {
 	type WeightInfo = pallet_travel_points::weights::SubstrateWeight<Runtime>;
 	/// Maximum number of point batches a user can have (100 is reasonable for most use cases)
 	type MaxPointBatches = ConstU32<100>;
+	/// Merge same-type, same-expiry point batches once 90% of the batch limit is used
+	type BatchCompactionThreshold = ConstU32<9000>;
+	/// Minimum points per award, to deter batch-fragmentation griefing
+	type MinAwardAmount = ConstU128<10>;
+	/// Maximum distinct travel types per user (high enough to disable the limit)
+	type MaxTravelTypesPerUser = ConstU32<16>;
 	/// Default expiration period for points: approximately 1 year.
 	/// Calculation: 365 days * 24 hours * 60 minutes * 10 blocks/minute = 5,256,000 blocks
 	/// (Assumes 6-second block time, which is standard for Substrate chains)
 	type DefaultExpirationPeriod = ConstU32<{ 365 * 24 * 60 * 10 }>;
+	/// Whole batches expire at once (no partial expiry)
+	type PartialExpiryPercent = ConstU32<0>;
+	/// Partial expiry steps would be ~90 days (1296000 blocks) apart
+	type PartialExpiryInterval = ConstU32<{ 90 * 24 * 60 * 10 }>;
 	/// Maximum number of tickets a user can own (100 tickets)
 	type MaxTicketsPerUser = ConstU32<100>;
+	/// Maximum number of past owners kept per ticket (16 entries)
+	type MaxProvenanceEntries = ConstU32<16>;
+	/// Each issuer may mint up to 10000 tickets per reward period
+	type MaxTicketsPerIssuerPerPeriod = ConstU32<10_000>;
 	/// Maximum number of stakers (1000 stakers)
 	type MaxStakers = ConstU32<1000>;
+	/// Maximum number of stakers waiting for an active slot
+	type MaxStakerWaitlist = ConstU32<100>;
 	/// Minimum stake amount: 1000 units
 	type MinStakeAmount = ConstU128<1000>;
 	/// Staker reward percentage: 30% (3000 basis points)
@@ -211,21 +243,106 @@ impl pallet_travel_points::Config for Runtime {
 	type InvalidVerificationSlashPercent = ConstU32<1000>;
 	/// Slash percentage for malicious behavior: 100% (10000 basis points)
 	type MaliciousSlashPercent = ConstU32<10000>;
+	/// Half of every slash is added to the reward pool, the rest is burned
+	type SlashToRewardPoolBps = ConstU32<5000>;
 	/// Maximum number of staking pools
 	type MaxPools = ConstU32<100>;
 	/// Maximum delegators per pool
 	type MaxDelegatorsPerPool = ConstU32<100>;
+	/// Maximum delegation by one account to a single pool: 1000000 units
+	type MaxDelegationPerPoolPerAccount = ConstU128<1000000>;
 	/// Minimum pool operator stake: 10000 units
 	type MinPoolOperatorStake = ConstU128<10000>;
+	/// Pools must exist ~7 days (100800 blocks with 6s block time) before they can be closed
+	type MinPoolLifetime = ConstU32<{ 7 * 24 * 60 * 10 }>;
 	/// Maximum pool commission: 30% (3000 basis points)
 	type MaxPoolCommission = ConstU32<3000>;
 	/// Number of verifiers selected per era
 	type VerifiersPerEra = ConstU32<21>;
+	/// Only stakers with at least 10000 staked are considered for verifier selection
+	type MinVerifierStake = ConstU128<10000>;
+	/// Two thirds of an era's verifiers must attest before a period's rewards are released
+	type VerifierQuorumPercent = ConstU32<6667>;
 	/// Blocks per era: ~1 day worth of blocks (14400 blocks with 6s block time)
 	type BlocksPerEra = ConstU32<{ 24 * 60 * 10 }>;
 	/// Percentage of rewards going to issuers: 20% (2000 basis points)
 	/// This incentivizes issuers to participate in the network
 	type IssuerRewardPercent = ConstU32<2000>;
+	/// Points spent and transaction count weigh equally in the issuer reward score
+	type IssuerRewardSpendWeight = ConstU32<1>;
+	type IssuerRewardTransactionWeight = ConstU32<1>;
+	/// Each transaction counts as 10 points in the issuer reward score
+	type IssuerRewardTransactionScale = ConstU128<10>;
 	/// Maximum unbonding requests per account
 	type MaxUnbondingRequests = ConstU32<32>;
+	/// Stake changes remembered per staker for slashing at the offense block
+	type MaxStakeHistory = ConstU32<32>;
+	/// Unbonding requests below 100 units skip the unbonding period
+	type InstantUnbondThreshold = ConstU128<100>;
+	/// At most 500 units may be unbonded instantly per account per reward period
+	type MaxInstantUnbondPerPeriod = ConstU128<500>;
+	/// Emergency withdrawals skip unbonding but forfeit 20% to the reward pool
+	type EmergencyWithdrawPenaltyBps = ConstU32<2000>;
+	/// Minimum points left after a spend unless spending the whole balance
+	type MinRetainedPoints = ConstU128<10>;
+	/// Awarded points can be spent after ~1 hour
+	type MinHoldingPeriod = ConstU32<{ 60 * 10 }>;
+	/// Batches expired within the last ~30 days can be reinstated by a blanket extension
+	type ReinstatementWindow = ConstU32<{ 30 * 24 * 60 * 10 }>;
+	/// Awards and spends leave the expiry of a user's other batches unchanged
+	type ResetExpiryOnActivity = ConstBool<false>;
+	/// Point locks can be committed for ~30 minutes
+	type LockTimeout = ConstU32<{ 30 * 10 }>;
+	/// A user may hold up to 8 open point locks
+	type MaxPointLocks = ConstU32<8>;
+	/// Spend breakdown events list up to 32 consumed batches
+	type MaxSpendBreakdownEntries = ConstU32<32>;
+	/// A user may spend with at most 10 distinct issuers per reward period
+	type MaxIssuersPerUserPerPeriod = ConstU32<10>;
+	/// A user may spend at most 1000000 points per reward period across all issuers
+	type MaxUserSpendPerPeriod = ConstU128<1000000>;
+	/// Pruning calls remove up to 500 entries of a past reward period at a time
+	type MaxPeriodEntriesPruned = ConstU32<500>;
+	/// A retroactive bonus credits at most 50 users per call
+	type MaxRetroactiveRecipients = ConstU32<50>;
+	/// Periods can receive a retroactive bonus for 30 reward periods (~30 days) after they end
+	type RetroactiveBonusWindow = ConstU32<30>;
+	/// Accounts become dormant after ~180 days without activity
+	type DormancyPeriod = ConstU32<{ 180 * 24 * 60 * 10 }>;
+	/// Issuers can be pruned after 90 reward periods (~90 days) without activity
+	type IssuerInactivityPeriod = ConstU32<90>;
+	/// Escheated points go to the travel points program account
+	type EscheatmentAccount = TravelPointsEscheatmentAccount;
+	/// Demurrage is disabled; set a rate to make held points lose value over time
+	type DemurrageRate = ConstU32<0>;
+	/// The demurrage rate applies per ~30 days (432000 blocks with 6s block time)
+	type DemurragePeriod = ConstU32<{ 30 * 24 * 60 * 10 }>;
+	/// A single demurrage call removes at most 10% of a batch
+	type MaxDemurragePerCall = ConstU32<1000>;
+	/// Pending rewards must be claimed within ~30 days (432000 blocks with 6s block time)
+	type RewardClaimWindow = ConstU32<{ 30 * 24 * 60 * 10 }>;
+	/// A single claim pays at most 1,000,000 of an account's pending rewards
+	type MaxClaimPerCall = ConstU128<1000000>;
+	/// Stakes must be held ~1 day (14400 blocks) before they share in reward distributions
+	type StakeRewardDelay = ConstU32<{ 24 * 60 * 10 }>;
+	/// Passive stakers keep earning; only verification quorum gates distributions
+	type RequireVerificationForReward = ConstBool<false>;
+	/// Maximum tickets moved by a single batch transfer
+	type MaxTicketsPerBatchTransfer = ConstU32<20>;
+	/// Points burned per ticket transfer (transfers are free)
+	type TicketTransferFee = ConstU128<0>;
+	/// Maximum accounts a keeper can claim rewards for in one call
+	type MaxClaimsPerBatch = ConstU32<50>;
+	/// Maximum tickets checked by one ownership verification query
+	type MaxOwnershipChecks = ConstU32<100>;
+	/// Maximum tickets looked up by one ticket status query
+	type MaxTicketStatusQueries = ConstU32<100>;
+	/// Staker rewards are paid as currency
+	type StakerRewardKind = TravelPointsStakerRewardKind;
+	/// Issuer rewards are paid as currency
+	type IssuerRewardKind = TravelPointsIssuerRewardKind;
+	/// Claimed rewards are paid out instantly (no vesting)
+	type RewardVestingPeriod = ConstU32<0>;
+	/// No other pallet reacts to ticket redemptions
+	type OnTicketRedeemed = ();
 }
@@ -168,6 +168,12 @@ pub type UncheckedExtrinsic =
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (
+	pallet_travel_points::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_travel_points::migrations::v2::MigrateV1ToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -175,6 +181,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
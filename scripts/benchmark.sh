@@ -28,12 +28,12 @@ fi
 # run the benchmarks
 ./target/release/solochain-template-node benchmark pallet \
 	--chain=dev \
-	--pallet=pallet_template \
+	--pallet=pallet_travel_points \
 	--extrinsic='*' \
 	--steps=50 \
 	--repeat=200 \
 	--wasm-execution=compiled \
-	--output='pallets/template/src/weights.rs' \
+	--output='pallets/travel-points/src/weights.rs' \
 	--template="${template_file}" || die 'Benchmark failed'
 
 # done